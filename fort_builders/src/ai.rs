@@ -0,0 +1,351 @@
+//! # ai module
+//!
+//! Holds the computer opponent that picks the moves of a [`Player`] whose [`PlayerKind`] is
+//! [`PlayerKind::Computer`]. Every legal move from the [`GameRules`] gets a score and the move with
+//! the highest score is played. The same game always gets the same move.
+//!
+//! The attacker plays towards the fort in the middle of the board. It rolls the dice as soon as a
+//! piece is on the opposite side, captures with the knights whenever it can and advances the pawns
+//! towards the fort while keeping the pieces off the squares the other players can take.
+//!
+//! The defender weighs the threat in each [`Quadrant`] by how close the attackers are to the fort.
+//! It keeps the fort entry squares of the quadrants under threat within reach of its pieces, takes
+//! the attackers that reach them first and pushes its pieces to the border of the attackers where
+//! the quadrant is calm.
+//!
+//! [`PlayerKind`]: crate::player::PlayerKind
+//! [`PlayerKind::Computer`]: crate::player::PlayerKind::Computer
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    board::{
+        border_distance, check_in_opposite_defender, check_in_opposite_enemy, fort_distance,
+        Quadrant,
+    },
+    game::{Game, GameAction},
+    pieces::{Move, MoveKind, Piece, PieceType, Position},
+    player::{Player, PlayerAction},
+    rules::{possible_piece_paths, GameRules},
+};
+
+/// The score of rolling the dice. Higher than any other move.
+const DICE_ROLL_SCORE: i32 = 10_000_i32;
+/// The score of a skip. Lower than any other move.
+const SKIP_SCORE: i32 = -10_000_i32;
+/// The score of each square a piece gets closer to the fort.
+const ADVANCE_SCORE: i32 = 10_i32;
+/// The score of a piece reaching the opposite side, where it can roll the dice.
+const OPPOSITE_SIDE_SCORE: i32 = 500_i32;
+/// The score of each point of value of a piece that is captured or put at risk.
+const PIECE_VALUE_SCORE: i32 = 100_i32;
+/// The extra score of a capture made with a knight.
+const KNIGHT_CAPTURE_SCORE: i32 = 50_i32;
+/// The extra score of taking an attacker that stands on a fort entry square.
+const ENTRY_CAPTURE_SCORE: i32 = 2_000_i32;
+/// The score of each point of threat in the quadrant for every fort entry square guarded.
+const GUARD_SCORE: i32 = 5_i32;
+/// The threat of an attacker piece that stands on a fort entry square.
+const ENTRY_THREAT: i32 = 10_i32;
+/// The distance from the fort from which an attacker piece starts to be a threat.
+const THREAT_RANGE: i32 = 6_i32;
+/// The threat under which a quadrant is calm enough for the defender to push to the border.
+const CALM_THREAT: i32 = 8_i32;
+
+/// The squares and the quadrants the player whose turn it is has to look out for.
+struct Threats {
+    /// The squares the other players can take on their turns.
+    squares: Vec<Position>,
+    /// The threat the attackers in each quadrant pose to the fort, in the order of the quadrants.
+    quadrants: [i32; 3_usize],
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// To pick the move of the player whose turn it is.
+///
+/// Returns a [`MoveKind::Skip`] if the player has no other move.
+pub fn choose_move(game: &Game) -> Move {
+    let player = game.current_player();
+    let threats = Threats::from(game);
+    game.all_legal_moves()
+        .into_iter()
+        .fold((i32::MIN, Move::skip()), |best, step| {
+            let score = match player.is_defender {
+                true => defender_score(game, player, &step, &threats),
+                false => attacker_score(game, player, &step, &threats),
+            } + promotion_score(&step);
+            match score > best.0 {
+                true => (score, step),
+                false => best,
+            }
+        })
+        .1
+}
+
+/// To get the value of a piece type, the way it is counted in chess.
+#[inline]
+fn piece_value(piece_type: PieceType) -> i32 {
+    match piece_type {
+        PieceType::Pawn => 1_i32,
+        PieceType::Knight | PieceType::Minister => 3_i32,
+        PieceType::Rook => 5_i32,
+        PieceType::Queen => 9_i32,
+    }
+}
+
+/// To get the score of the piece a pawn is promoted to, over the pawn it was.
+#[inline]
+fn promotion_score(step: &Move) -> i32 {
+    step.promotion.map_or(0_i32, |piece_type| {
+        (piece_value(piece_type) - piece_value(PieceType::Pawn)) * PIECE_VALUE_SCORE
+    })
+}
+
+/// To get the piece at the position, whoever it belongs to.
+#[inline]
+fn piece_at(game: &Game, position: Position) -> Option<Piece> {
+    game.players.iter().find_map(|player| {
+        player
+            .piece_index_from_xy_i32(position.x, position.y)
+            .ok()
+            .map(|index| player.pieces[index])
+    })
+}
+
+/// To get the index of the quadrant the position is in.
+#[inline]
+fn quadrant_index(position: Position) -> Option<usize> {
+    match Quadrant::from_xy(position.x, position.y) {
+        Ok(Quadrant::Q1) => Some(0_usize),
+        Ok(Quadrant::Q2) => Some(1_usize),
+        Ok(Quadrant::Q3) => Some(2_usize),
+        _ => None,
+    }
+}
+
+/// To get the number of fort entry squares the piece guards, that is the entry squares it can move
+/// to or take on.
+///
+/// A pawn only guards the squares it can take on. A piece whose paths cannot be analysed guards
+/// none.
+fn guarded_entries(game: &Game, player: &Player, piece: &Piece) -> i32 {
+    possible_piece_paths(piece, player, game)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|to| check_in_opposite_enemy(to.x, to.y))
+        .filter(|to| piece.piece_type != PieceType::Pawn || game.check_piece_in_pos(to.x, to.y))
+        .count() as i32
+}
+
+/*████Threats████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Threats {
+    /// To find the threats to the player whose turn it is.
+    fn from(game: &Game) -> Self {
+        Threats {
+            squares: Threats::squares(game),
+            quadrants: Threats::quadrants(game),
+        }
+    }
+    /// To get the squares the other players can take on their turns.
+    ///
+    /// A pawn only takes on its diagonals, so the squares in front of it are left out.
+    fn squares(game: &Game) -> Vec<Position> {
+        let mut squares = Vec::new();
+        for (index, player) in game.players.iter().enumerate() {
+            if index == game.turn {
+                continue;
+            }
+            for (piece_index, piece) in player.pieces.iter().enumerate() {
+                squares.extend(
+                    game.legal_moves(index, piece_index)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|step| match step.kind {
+                            MoveKind::Capture => true,
+                            MoveKind::Step => piece.piece_type != PieceType::Pawn,
+                            MoveKind::FortDiceRoll | MoveKind::Skip => false,
+                        })
+                        .map(|step| step.to),
+                );
+            }
+        }
+        squares
+    }
+    /// To get the threat the attackers in each quadrant pose to the fort.
+    ///
+    /// An attacker piece on a fort entry square can roll the dice on its next turn, so it counts
+    /// the most. The other pieces count more the closer they are to the fort.
+    fn quadrants(game: &Game) -> [i32; 3_usize] {
+        let mut quadrants = [0_i32; 3_usize];
+        game.players
+            .iter()
+            .filter(|player| !player.is_defender)
+            .flat_map(|player| player.pieces.iter())
+            .for_each(|piece| {
+                if let Some(index) = quadrant_index(piece.position) {
+                    quadrants[index] +=
+                        match check_in_opposite_enemy(piece.position.x, piece.position.y) {
+                            true => ENTRY_THREAT,
+                            false => (THREAT_RANGE
+                                - fort_distance(piece.position.x, piece.position.y))
+                            .max(0_i32),
+                        };
+                }
+            });
+        quadrants
+    }
+    /// To get the threat in the quadrant the position is in.
+    #[inline]
+    fn in_quadrant(&self, position: Position) -> i32 {
+        quadrant_index(position)
+            .map(|index| self.quadrants[index])
+            .unwrap_or_default()
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/// To get the score of the material won and put at risk by a step or a capture.
+fn material_score(game: &Game, piece: &Piece, step: &Move, threats: &Threats) -> i32 {
+    let mut score = 0_i32;
+    if let (MoveKind::Capture, Some(captured)) = (step.kind, piece_at(game, step.to)) {
+        score += piece_value(captured.piece_type) * PIECE_VALUE_SCORE;
+    }
+    if threats.squares.contains(&step.to) {
+        score -= piece_value(piece.piece_type) * PIECE_VALUE_SCORE / 2_i32;
+    }
+    score
+}
+
+/// To get the moved piece of the player.
+#[inline]
+fn moved_piece(player: &Player, step: &Move) -> Option<Piece> {
+    player
+        .piece_index_from_xy_i32(step.from.x, step.from.y)
+        .ok()
+        .map(|index| player.pieces[index])
+}
+
+/// To score a move of an attacker.
+///
+/// A dice roll beats every other move. Captures are scored by the value of the captured piece with
+/// an extra score for the knights. The pawns score twice as much for every square they get closer
+/// to the fort.
+fn attacker_score(game: &Game, player: &Player, step: &Move, threats: &Threats) -> i32 {
+    let piece = match (step.kind, moved_piece(player, step)) {
+        (MoveKind::FortDiceRoll, _) => return DICE_ROLL_SCORE,
+        (MoveKind::Skip, _) | (_, None) => return SKIP_SCORE,
+        (_, Some(piece)) => piece,
+    };
+    let mut score = material_score(game, &piece, step, threats);
+    let advance = fort_distance(step.from.x, step.from.y) - fort_distance(step.to.x, step.to.y);
+    score += match piece.piece_type {
+        PieceType::Pawn => advance * ADVANCE_SCORE * 2_i32,
+        _ => advance * ADVANCE_SCORE,
+    };
+    if step.kind == MoveKind::Capture && piece.piece_type == PieceType::Knight {
+        score += KNIGHT_CAPTURE_SCORE;
+    }
+    if check_in_opposite_enemy(step.to.x, step.to.y) {
+        score += OPPOSITE_SIDE_SCORE;
+    }
+    score
+}
+
+/// To score a move of the defender.
+///
+/// A dice roll beats every other move and taking an attacker on a fort entry square comes next.
+/// Every fort entry square the piece guards after the move, more than it did before, scores by the
+/// threat in its quadrant. The pieces are pushed to the border of the attackers only where the
+/// quadrant is calm.
+fn defender_score(game: &Game, player: &Player, step: &Move, threats: &Threats) -> i32 {
+    let piece = match (step.kind, moved_piece(player, step)) {
+        (MoveKind::FortDiceRoll, _) => return DICE_ROLL_SCORE,
+        (MoveKind::Skip, _) | (_, None) => return SKIP_SCORE,
+        (_, Some(piece)) => piece,
+    };
+    let threat = threats.in_quadrant(step.from);
+    let mut score = material_score(game, &piece, step, threats);
+    if step.kind == MoveKind::Capture && check_in_opposite_enemy(step.to.x, step.to.y) {
+        score += ENTRY_CAPTURE_SCORE;
+    }
+    let moved = Piece {
+        position: step.to,
+        ..piece
+    };
+    score += (guarded_entries(game, player, &moved) - guarded_entries(game, player, &piece))
+        * threat
+        * GUARD_SCORE;
+    if threat < CALM_THREAT {
+        score += (border_distance(step.from.x, step.from.y)
+            - border_distance(step.to.x, step.to.y))
+            * ADVANCE_SCORE;
+    }
+    if check_in_opposite_defender(step.to.x, step.to.y) {
+        score += OPPOSITE_SIDE_SCORE;
+    }
+    score
+}
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{at, game, KNIGHT_AND_PAWN};
+
+    /// The blue attacker has a knight that can take the red rook.
+    const KNIGHT_TAKES_ROOK: &str = "RD=alice:R1c3/B1=bobby:N1b1 B 2";
+
+    /// To pick the move and check that the game allows it.
+    fn legal_choice(game: &Game) -> Move {
+        let step = choose_move(game);
+        assert!(game.all_legal_moves().contains(&step));
+        step
+    }
+
+    #[test]
+    fn test_attacker_move_is_legal() {
+        let step = legal_choice(&game(KNIGHT_AND_PAWN));
+        assert_ne!(step.kind, MoveKind::Skip);
+    }
+
+    #[test]
+    fn test_attacker_prefers_capture() {
+        let step = legal_choice(&game(KNIGHT_TAKES_ROOK));
+        assert_eq!(step, Move::new(at("1b1"), at("1c3"), MoveKind::Capture));
+    }
+
+    #[test]
+    fn test_attacker_rolls_over_capture() {
+        let step = legal_choice(&game("RD=alice:R1c3/B1=bobby:N1b1,P1f2 B 2"));
+        assert_eq!(step, Move::dice_roll(at("1f2")));
+    }
+
+    #[test]
+    fn test_defender_move_is_legal() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        game.apply_move(Move::new(at("1e2"), at("1f2"), MoveKind::Step))
+            .unwrap();
+        let step = legal_choice(&game);
+        assert_ne!(step.kind, MoveKind::Skip);
+    }
+
+    #[test]
+    fn test_defender_takes_attacker_on_fort_entry() {
+        let step = legal_choice(&game("RD=alice:R1c1/B1=bobby:P1f1,Q1c4 R 2"));
+        assert_eq!(step, Move::new(at("1c1"), at("1f1"), MoveKind::Capture));
+    }
+
+    #[test]
+    fn test_defender_keeps_fort_entry_guarded() {
+        let game = game("RD=alice:N1d3/B1=bobby:P1f1 R 2");
+        let step = legal_choice(&game);
+        let player = game.current_player();
+        let moved = Piece {
+            position: step.to,
+            ..moved_piece(player, &step).unwrap()
+        };
+        assert!(guarded_entries(&game, player, &moved) > 0_i32);
+    }
+}
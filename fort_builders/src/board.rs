@@ -1,18 +1,19 @@
 //! # board module
 //!
-//! module to hold board specific values like dimensions etc.
+//! module to hold board specific values like dimensions etc. The shape of the board is described
+//! by the [`BOARD_LAYOUT`], which the bounds and the sides below are looked up in.
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
-use crate::{Error, BREADTH};
+//--------------//
+pub mod layout;
+//--------------//
 
-/// Board's right most `x axis` length.
-pub const X_MAX: i32 = 8_i32;
-/// Board's left most `x axis` length.
-pub const X_MIN: i32 = -8_i32;
-/// Board's top most `y axis` length.
-pub const Y_MAX: i32 = 8_i32;
-/// Board's down most `y axis` length.
-pub const Y_MIN: i32 = -2_i32;
+use crate::Error;
+use layout::BoardLayout;
+use serde::{Deserialize, Serialize};
+
+/// The layout of the board the game is played on.
+pub const BOARD_LAYOUT: BoardLayout = BoardLayout::CLASSIC;
 
 /// Board's right most length in view.
 pub const RGT: i32 = 12_i32;
@@ -23,24 +24,8 @@ pub const TOP: i32 = 10_i32;
 /// Board's bottom most length in view.
 pub const BTM: i32 = -4_i32;
 
-/*████Local Constants████*/
-/*-----------------------------------------------------------------------------------------------*/
-/// Holds the [`X_MIN`]  value as `f32`.
-pub const XMINF: f32 = X_MIN as f32;
-/// Holds the [`X_MAX`]  value as `f32`.
-pub const XMAXF: f32 = X_MAX as f32;
-/// Holds the [`Y_MIN`]  value as `f32`.
-pub const YMINF: f32 = Y_MIN as f32;
-/// Holds the[`Y_MAX`]  value as `f32`.
-pub const YMAXF: f32 = Y_MAX as f32;
-/// Holds the empty value that needs to be deleted from each size to form the board shape.
-const EMPTY: f32 = 6_f32;
-/// to get the actual board bounds excluding the border.
-const BOARD: f32 = EMPTY - 1_f32;
-/*-----------------------------------------------------------------------------------------------*/
-
 /// Quadrants inside the game. Each value corresponds to a side of the board.
-#[derive(Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Quadrant {
     /// Block 1.
     Q1,
@@ -59,19 +44,19 @@ pub enum Quadrant {
 /// Gets the outer bound of the board q1.
 #[inline]
 pub fn q1_outer_bound_pos() -> (i32, i32) {
-    (X_MIN - 4_i32, 0_i32)
+    BOARD_LAYOUT.name_position(Quadrant::Q1)
 }
 
 /// Gets the outer bound of the board q2.
 #[inline]
 pub fn q2_outer_bound_pos() -> (i32, i32) {
-    (-1_i32, Y_MAX + 1_i32)
+    BOARD_LAYOUT.name_position(Quadrant::Q2)
 }
 
 /// Gets the outer bound of the board q3.
 #[inline]
 pub fn q3_outer_bound_pos() -> (i32, i32) {
-    (X_MAX + 1_i32, 0_i32)
+    BOARD_LAYOUT.name_position(Quadrant::Q3)
 }
 /*-----------------------------------------------------------------------------------------------*/
 
@@ -88,59 +73,65 @@ impl Quadrant {
             _ => Err(Error::InvalidQuadrantIndex(index)),
         }
     }
-    /// To get a [`Quadrant`] value from x and y values of `f32` type.
+    /// To get a [`Quadrant`] value from x and y values of `i32` type.
     #[inline]
-    pub fn from_xy(x: f32, y: f32) -> Result<Self, Error> {
-        if position_in_q1_bounds(x, y) {
-            return Ok(Quadrant::Q1);
-        }
-        if position_in_q2_bounds(x, y) {
-            return Ok(Quadrant::Q2);
-        }
-        if position_in_q3_bounds(x, y) {
-            return Ok(Quadrant::Q3);
-        }
-        Err(Error::PositionNotInQuadrant(x as i32, y as i32))
+    pub fn from_xy(x: i32, y: i32) -> Result<Self, Error> {
+        BOARD_LAYOUT
+            .quadrant_at(x, y)
+            .map(|layout| layout.quadrant)
+            .ok_or(Error::PositionNotInQuadrant(x, y))
+    }
+    /// To get the bottom left position of the [`Quadrant`] as `i32` x and y values.
+    ///
+    /// The defender quadrant does not have a block of its own, so it has no origin.
+    #[inline]
+    pub(crate) fn origin(&self) -> Option<(i32, i32)> {
+        BOARD_LAYOUT
+            .quadrant(*self)
+            .map(|layout| (layout.area.x_min, layout.area.y_min))
     }
 }
 /*-----------------------------------------------------------------------------------------------*/
 
 /*████Board Bounds Logic████*/
 /*-----------------------------------------------------------------------------------------------*/
+/// Returns the bool if the x and y values are inside the bounds of the quadrant.
+#[inline]
+fn position_in_quadrant_bounds(quadrant: Quadrant, x: i32, y: i32) -> bool {
+    BOARD_LAYOUT
+        .quadrant(quadrant)
+        .map_or(false, |layout| layout.area.contains(x, y))
+}
+
 /// Returns the bool if the x and y values are inside [`Q1`] bounds.
 ///
 /// [`Q1`]: Quadrant::Q1
 #[inline]
-pub fn position_in_q1_bounds(x: f32, y: f32) -> bool {
-    (x < XMAXF - (BOARD * 2_f32) && x >= XMINF) && (y <= (YMAXF - 1_f32) - EMPTY && y >= YMINF)
+pub fn position_in_q1_bounds(x: i32, y: i32) -> bool {
+    position_in_quadrant_bounds(Quadrant::Q1, x, y)
 }
 
 /// Returns the bool if the x and y values are inside [`Q2`] bounds.
 ///
 /// [`Q2`]: Quadrant::Q2
 #[inline]
-pub fn position_in_q2_bounds(x: f32, y: f32) -> bool {
-    (x <= (XMAXF - 1_f32) - EMPTY && x >= XMAXF - (BOARD * 2_f32))
-        && (y < YMAXF && y >= (YMINF + 4_f32))
+pub fn position_in_q2_bounds(x: i32, y: i32) -> bool {
+    position_in_quadrant_bounds(Quadrant::Q2, x, y)
 }
 
 /// Returns the bool if the x and y values are inside [`Q3`] bounds.
 ///
 /// [`Q3`]: Quadrant::Q3
 #[inline]
-pub fn position_in_q3_bounds(x: f32, y: f32) -> bool {
-    (x >= XMAXF - EMPTY && x < XMAXF) && (y <= (YMAXF - 1_f32) - EMPTY && y >= YMINF)
+pub fn position_in_q3_bounds(x: i32, y: i32) -> bool {
+    position_in_quadrant_bounds(Quadrant::Q3, x, y)
 }
 
-/// Checks if position inside [`Q1`], [`Q2`] or [`Q3`]. Returns false
-/// if the piece not inside these three bounds.
-///
-/// [`Q1`]: Quadrant::Q1
-/// [`Q2`]: Quadrant::Q2
-/// [`Q3`]: Quadrant::Q3
+/// Checks if position inside any of the quadrants of the [`BOARD_LAYOUT`]. Returns false if the
+/// piece is not inside them.
 #[inline]
-pub fn position_in_board_bounds(x: f32, y: f32) -> bool {
-    position_in_q1_bounds(x, y) || position_in_q2_bounds(x, y) || position_in_q3_bounds(x, y)
+pub fn position_in_board_bounds(x: i32, y: i32) -> bool {
+    BOARD_LAYOUT.contains(x, y)
 }
 
 /*████Cursor Position Logic████*/
@@ -158,26 +149,50 @@ fn full_height() -> f32 {
 }
 
 /// To get the cursor position relative to the camera screen.
+///
+/// The cursor position is the only place where the `f32` screen values are turned into the `i32`
+/// board positions.
 #[inline]
-pub fn cursor_in_window(c_x: f32, c_y: f32, height: f32, width: f32) -> (f32, f32) {
+pub fn cursor_in_window(c_x: f32, c_y: f32, height: f32, width: f32) -> (i32, i32) {
     (
-        (((c_x / width) * full_width()) + (LFT as f32)).round(),
-        (((c_y / height) * full_height()) + (BTM as f32)).round(),
+        (((c_x / width) * full_width()) + (LFT as f32)).round() as i32,
+        (((c_y / height) * full_height()) + (BTM as f32)).round() as i32,
     )
 }
 /*-----------------------------------------------------------------------------------------------*/
 
 /*████Position in Opposite Side Logic████*/
 /*-----------------------------------------------------------------------------------------------*/
-/// To check wether the defender piece is in the opposite side.
+/// To check wether the defender piece is in the opposite side, which is the back rank of any of
+/// the quadrants.
 #[inline]
 pub(crate) fn check_in_opposite_defender(x: i32, y: i32) -> bool {
-    x <= X_MIN || x >= X_MAX - 1_i32 || y >= Y_MAX - 1_i32
+    BOARD_LAYOUT
+        .quadrants
+        .iter()
+        .any(|layout| layout.back_rank().contains(x, y))
 }
 
-/// To check wether the non-defender piece is in the opposite side.
+/// To check wether the non-defender piece is in the opposite side, which is the fort wall.
 #[inline]
 pub(crate) fn check_in_opposite_enemy(x: i32, y: i32) -> bool {
-    (x >= -BREADTH - 1_i32 && x < BREADTH + 1_i32) && y.abs() <= BREADTH
+    BOARD_LAYOUT.fort_wall().contains(x, y)
+}
+
+/// To get the number of squares between the position and the opposite side of the attackers.
+#[inline]
+pub fn fort_distance(x: i32, y: i32) -> i32 {
+    BOARD_LAYOUT.fort_wall().distance(x, y)
+}
+
+/// To get the number of squares between the position and the opposite side of the defender.
+#[inline]
+pub fn border_distance(x: i32, y: i32) -> i32 {
+    BOARD_LAYOUT
+        .quadrants
+        .iter()
+        .map(|layout| layout.back_rank().distance(x, y))
+        .min()
+        .unwrap_or_default()
 }
 /*-----------------------------------------------------------------------------------------------*/
@@ -0,0 +1,327 @@
+//! # layout module
+//!
+//! Holds the [`BoardLayout`] that describes the shape of the board as data. The quadrants, the
+//! fort, the borders and the start squares are kept here instead of being worked out from the
+//! board dimensions, so that a board with larger arms or more of them only needs a new layout.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{board::Quadrant, pieces::ENEMY_COUNT};
+
+/// Type to abstract the start squares of the pieces placed in a quadrant.
+pub type StartSquares = [(i32, i32); ENEMY_COUNT];
+
+/// A rectangle of squares on the board. The bounds are part of the area.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Area {
+    /// The left most `x axis` value.
+    pub x_min: i32,
+    /// The right most `x axis` value.
+    pub x_max: i32,
+    /// The down most `y axis` value.
+    pub y_min: i32,
+    /// The top most `y axis` value.
+    pub y_max: i32,
+}
+
+/// The layout of one of the quadrants the attackers start from.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct QuadrantLayout {
+    /// The quadrant the layout belongs to.
+    pub quadrant: Quadrant,
+    /// The squares of the quadrant.
+    pub area: Area,
+    /// The step an attacker pawn takes towards the fort. A defender pawn steps the other way.
+    pub forward: (i32, i32),
+    /// The position the name of the attacker is shown at, outside the board.
+    pub name_position: (i32, i32),
+    /// The squares the pieces of the attacker start on, in the order of its army.
+    pub attacker_start: StartSquares,
+    /// The squares the pieces the defender places in front of the attacker start on, in the order
+    /// of its army.
+    pub defender_start: StartSquares,
+}
+
+/// The shape of the board.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BoardLayout {
+    /// The quadrants in the order the attackers join the game.
+    pub quadrants: &'static [QuadrantLayout],
+    /// The squares of the fort in the middle of the board. The fort is not part of the board.
+    pub fort: Area,
+    /// The position the name of the defender is shown at.
+    pub fort_name_position: (i32, i32),
+}
+
+/// The quadrants of the classic cross-shaped board.
+const CLASSIC_QUADRANTS: [QuadrantLayout; 3_usize] = [
+    QuadrantLayout {
+        quadrant: Quadrant::Q1,
+        area: Area::new(-8_i32, -3_i32, -2_i32, 1_i32),
+        forward: (1_i32, 0_i32),
+        name_position: (-12_i32, 0_i32),
+        attacker_start: [
+            (-7_i32, -2_i32),
+            (-7_i32, -1_i32),
+            (-7_i32, 0_i32),
+            (-7_i32, 1_i32),
+            (-8_i32, -2_i32),
+            (-8_i32, -1_i32),
+            (-8_i32, 0_i32),
+            (-8_i32, 1_i32),
+        ],
+        defender_start: [
+            (-3_i32, -2_i32),
+            (-3_i32, -1_i32),
+            (-3_i32, 0_i32),
+            (-3_i32, 1_i32),
+            (-4_i32, -2_i32),
+            (-4_i32, -1_i32),
+            (-4_i32, 0_i32),
+            (-4_i32, 1_i32),
+        ],
+    },
+    QuadrantLayout {
+        quadrant: Quadrant::Q2,
+        area: Area::new(-2_i32, 1_i32, 2_i32, 7_i32),
+        forward: (0_i32, -1_i32),
+        name_position: (-1_i32, 9_i32),
+        attacker_start: [
+            (-2_i32, 6_i32),
+            (-1_i32, 6_i32),
+            (0_i32, 6_i32),
+            (1_i32, 6_i32),
+            (-2_i32, 7_i32),
+            (-1_i32, 7_i32),
+            (0_i32, 7_i32),
+            (1_i32, 7_i32),
+        ],
+        defender_start: [
+            (-2_i32, 2_i32),
+            (-1_i32, 2_i32),
+            (0_i32, 2_i32),
+            (1_i32, 2_i32),
+            (-2_i32, 3_i32),
+            (-1_i32, 3_i32),
+            (0_i32, 3_i32),
+            (1_i32, 3_i32),
+        ],
+    },
+    QuadrantLayout {
+        quadrant: Quadrant::Q3,
+        area: Area::new(2_i32, 7_i32, -2_i32, 1_i32),
+        forward: (-1_i32, 0_i32),
+        name_position: (9_i32, 0_i32),
+        attacker_start: [
+            (6_i32, 1_i32),
+            (6_i32, 0_i32),
+            (6_i32, -1_i32),
+            (6_i32, -2_i32),
+            (7_i32, 1_i32),
+            (7_i32, 0_i32),
+            (7_i32, -1_i32),
+            (7_i32, -2_i32),
+        ],
+        defender_start: [
+            (2_i32, 1_i32),
+            (2_i32, 0_i32),
+            (2_i32, -1_i32),
+            (2_i32, -2_i32),
+            (3_i32, 1_i32),
+            (3_i32, 0_i32),
+            (3_i32, -1_i32),
+            (3_i32, -2_i32),
+        ],
+    },
+];
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Area████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Area {
+    /// To create an [`Area`] from its bounds.
+    #[inline]
+    pub const fn new(x_min: i32, x_max: i32, y_min: i32, y_max: i32) -> Self {
+        Area {
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        }
+    }
+    /// To check if the x and y values are inside the area.
+    #[inline]
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        (self.x_min..=self.x_max).contains(&x) && (self.y_min..=self.y_max).contains(&y)
+    }
+    /// To get the area grown by the given number of squares on each side. A negative number
+    /// shrinks it.
+    #[inline]
+    pub fn grow(&self, by: i32) -> Area {
+        Area::new(
+            self.x_min - by,
+            self.x_max + by,
+            self.y_min - by,
+            self.y_max + by,
+        )
+    }
+    /// To get the number of squares between the position and the area, counted along each axis.
+    /// A position inside the area is at `0`.
+    #[inline]
+    pub fn distance(&self, x: i32, y: i32) -> i32 {
+        let outside = |value: i32, min: i32, max: i32| (min - value).max(value - max).max(0_i32);
+        outside(x, self.x_min, self.x_max) + outside(y, self.y_min, self.y_max)
+    }
+    /// To get the smallest area holding both areas.
+    #[inline]
+    pub fn union(&self, other: &Area) -> Area {
+        Area::new(
+            self.x_min.min(other.x_min),
+            self.x_max.max(other.x_max),
+            self.y_min.min(other.y_min),
+            self.y_max.max(other.y_max),
+        )
+    }
+    /// To get the x and y values of each square of the area, row by row from the bottom.
+    #[inline]
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> {
+        let (x_min, x_max) = (self.x_min, self.x_max);
+        (self.y_min..=self.y_max).flat_map(move |y| (x_min..=x_max).map(move |x| (x, y)))
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████QuadrantLayout████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl QuadrantLayout {
+    /// To get the row of the quadrant furthest from the fort, which is the opposite side of the
+    /// defender.
+    #[inline]
+    pub fn back_rank(&self) -> Area {
+        let area = self.area;
+        match self.forward {
+            (x, _) if x > 0_i32 => Area::new(area.x_min, area.x_min, area.y_min, area.y_max),
+            (x, _) if x < 0_i32 => Area::new(area.x_max, area.x_max, area.y_min, area.y_max),
+            (_, y) if y > 0_i32 => Area::new(area.x_min, area.x_max, area.y_min, area.y_min),
+            _ => Area::new(area.x_min, area.x_max, area.y_max, area.y_max),
+        }
+    }
+    /// To get the squares the pieces start on, depending on if they belong to the defender.
+    #[inline]
+    pub fn start_squares(&self, is_defender: bool) -> &StartSquares {
+        match is_defender {
+            true => &self.defender_start,
+            false => &self.attacker_start,
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████BoardLayout████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl BoardLayout {
+    /// The classic cross-shaped board with three quadrants around a fort.
+    pub const CLASSIC: BoardLayout = BoardLayout {
+        quadrants: &CLASSIC_QUADRANTS,
+        fort: Area::new(-2_i32, 1_i32, -2_i32, 1_i32),
+        fort_name_position: (-1_i32, 0_i32),
+    };
+
+    /// To get the layout of the quadrant, if the board has it.
+    #[inline]
+    pub fn quadrant(&self, quadrant: Quadrant) -> Option<&QuadrantLayout> {
+        self.quadrants
+            .iter()
+            .find(|layout| layout.quadrant == quadrant)
+    }
+    /// To get the layout of the quadrant the x and y values are in, if any.
+    #[inline]
+    pub fn quadrant_at(&self, x: i32, y: i32) -> Option<&QuadrantLayout> {
+        self.quadrants
+            .iter()
+            .find(|layout| layout.area.contains(x, y))
+    }
+    /// To check if the x and y values are on the board, that is inside one of the quadrants.
+    #[inline]
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        self.quadrant_at(x, y).is_some()
+    }
+    /// To get the squares around the fort, which are the opposite side of the attackers.
+    #[inline]
+    pub fn fort_wall(&self) -> Area {
+        self.fort.grow(1_i32)
+    }
+    /// To get the smallest area holding the whole board along with the fort.
+    #[inline]
+    pub fn bounds(&self) -> Area {
+        self.quadrants
+            .iter()
+            .fold(self.fort, |bounds, layout| bounds.union(&layout.area))
+    }
+    /// To get the position the name of the player starting from the quadrant is shown at.
+    #[inline]
+    pub fn name_position(&self, quadrant: Quadrant) -> (i32, i32) {
+        self.quadrant(quadrant)
+            .map_or(self.fort_name_position, |layout| layout.name_position)
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        board::{position_in_board_bounds, BOARD_LAYOUT},
+        pieces::Piece,
+    };
+
+    #[test]
+    fn test_start_squares_are_inside_their_quadrant() {
+        for layout in BOARD_LAYOUT.quadrants.iter() {
+            let starts = layout.start_squares(false).iter();
+            for &(x, y) in starts.chain(layout.start_squares(true).iter()) {
+                assert_eq!(Quadrant::from_xy(x, y).unwrap(), layout.quadrant);
+            }
+        }
+    }
+
+    #[test]
+    fn test_back_rank_holds_attacker_start_squares() {
+        for layout in BOARD_LAYOUT.quadrants.iter() {
+            assert!(layout
+                .back_rank()
+                .positions()
+                .all(|position| layout.start_squares(false).contains(&position)));
+        }
+    }
+
+    #[test]
+    fn test_fort_is_not_part_of_board() {
+        assert!(BOARD_LAYOUT
+            .fort
+            .positions()
+            .all(|(x, y)| !position_in_board_bounds(x, y)));
+    }
+
+    #[test]
+    fn test_classic_bounds() {
+        assert_eq!(
+            BOARD_LAYOUT.bounds(),
+            Area::new(-8_i32, 7_i32, -2_i32, 7_i32)
+        );
+    }
+
+    #[test]
+    fn test_board_range_follows_quadrants() {
+        let bounds = BOARD_LAYOUT.bounds();
+        for (x, y) in bounds.grow(1_i32).positions() {
+            assert_eq!(
+                Piece::in_board_range(x, y).is_ok(),
+                BOARD_LAYOUT.quadrant_at(x, y).is_some()
+            );
+        }
+        assert!(Piece::in_board_range(BOARD_LAYOUT.fort.x_min, BOARD_LAYOUT.fort.y_min).is_err());
+    }
+}
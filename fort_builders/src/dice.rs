@@ -0,0 +1,242 @@
+//! # dice module
+//!
+//! Holds the dice used in the [`Game`]. The dice is a [`DiceRoller`] owned by the game so that it
+//! can be swapped for a [`SeededDice`] to reproduce a game or a [`ScriptedDice`] in tests.
+//!
+//! [`Game`]: crate::game::Game
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    hash::{BuildHasher, Hasher},
+};
+
+/// The number of faces of the dice.
+pub const DICE_FACES: usize = 6_usize;
+/// The increment of the seeded generator state for every roll.
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15_u64;
+
+/// To roll the dice in the game.
+pub trait DiceRoller: fmt::Debug + Send + Sync {
+    /// To roll the dice. Returns a value between 0 and 5.
+    fn roll(&mut self) -> usize;
+    /// To get the state the dice can be restored from, if it can be restored.
+    fn state(&self) -> Option<DiceState>;
+}
+
+/// The state of a [`SeededDice`] that is kept in the game snapshot.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DiceState {
+    /// The seed the dice started with.
+    pub seed: u64,
+    /// The number of rolls made since the dice was seeded.
+    pub rolls: u64,
+}
+
+/// A dice that gives the same rolls for the same seed.
+///
+/// Uses the `SplitMix64` generator, so the dice can be restored to any roll without rolling
+/// it again.
+#[derive(Debug)]
+pub struct SeededDice {
+    /// The seed the dice started with.
+    seed: u64,
+    /// The number of rolls made since the dice was seeded.
+    rolls: u64,
+}
+
+/// A dice that plays the given rolls in order and starts again from the first one once they run
+/// out. Used to play out a known sequence of rolls in tests.
+#[derive(Debug)]
+pub struct ScriptedDice {
+    /// The rolls to play.
+    script: Vec<usize>,
+    /// The index of the next roll in the script.
+    next: usize,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████SeededDice████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl SeededDice {
+    /// To create a dice from the given seed.
+    #[inline]
+    pub fn from_seed(seed: u64) -> Self {
+        SeededDice { seed, rolls: 0_u64 }
+    }
+    /// To create a dice with a random seed from the operating system.
+    #[inline]
+    pub fn from_entropy() -> Self {
+        SeededDice::from_seed(RandomState::new().build_hasher().finish())
+    }
+    /// To create a dice that continues from the given state.
+    #[inline]
+    pub fn from_state(state: DiceState) -> Self {
+        SeededDice {
+            seed: state.seed,
+            rolls: state.rolls,
+        }
+    }
+    /// To get the seed the dice started with.
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+    /// To get the next random `u64` value.
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rolls = self.rolls.wrapping_add(1_u64);
+        let mut z = self
+            .seed
+            .wrapping_add(self.rolls.wrapping_mul(GOLDEN_GAMMA));
+        z = (z ^ (z >> 30_u32)).wrapping_mul(0xbf58_476d_1ce4_e5b9_u64);
+        z = (z ^ (z >> 27_u32)).wrapping_mul(0x94d0_49bb_1331_11eb_u64);
+        z ^ (z >> 31_u32)
+    }
+    /// To pick a value between 0 and the given count, each being equally likely.
+    ///
+    /// Used to pick the defender when the game is created.
+    #[inline]
+    pub fn pick(&mut self, count: usize) -> usize {
+        ((u128::from(self.next_u64()) * count as u128) >> 64_u32) as usize
+    }
+}
+
+impl DiceRoller for SeededDice {
+    #[inline]
+    fn roll(&mut self) -> usize {
+        self.pick(DICE_FACES)
+    }
+    #[inline]
+    fn state(&self) -> Option<DiceState> {
+        Some(DiceState {
+            seed: self.seed,
+            rolls: self.rolls,
+        })
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████ScriptedDice████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl ScriptedDice {
+    /// To create a dice that plays the given rolls.
+    ///
+    /// Every roll is kept between 0 and 5. An empty script always rolls 0.
+    #[inline]
+    pub fn from(script: Vec<usize>) -> Self {
+        ScriptedDice {
+            script: script
+                .into_iter()
+                .map(|roll| roll % DICE_FACES)
+                .collect::<Vec<usize>>(),
+            next: 0_usize,
+        }
+    }
+}
+
+impl DiceRoller for ScriptedDice {
+    #[inline]
+    fn roll(&mut self) -> usize {
+        let roll = self.script.get(self.next).copied().unwrap_or_default();
+        self.next = match self.next + 1_usize < self.script.len() {
+            true => self.next + 1_usize,
+            false => 0_usize,
+        };
+        roll
+    }
+    #[inline]
+    fn state(&self) -> Option<DiceState> {
+        None
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Serde████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To get a new [`SeededDice`] with a random seed as the dice of the game.
+#[inline]
+pub(crate) fn default_dice() -> Box<dyn DiceRoller> {
+    Box::new(SeededDice::from_entropy())
+}
+
+/// To write the dice of the game as its [`DiceState`].
+///
+/// A dice that cannot be restored is written as `null`.
+#[allow(clippy::borrowed_box)]
+pub(crate) fn serialize<S>(dice: &Box<dyn DiceRoller>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    dice.state().serialize(serializer)
+}
+
+/// To read the dice of the game from its [`DiceState`].
+///
+/// A missing state gives a dice with a random seed.
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Box<dyn DiceRoller>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<DiceState>::deserialize(deserializer)? {
+        Some(state) => Box::new(SeededDice::from_state(state)),
+        None => default_dice(),
+    })
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{at, game, KNIGHT_AND_PAWN},
+        game::GameAction,
+        pieces::{Move, MoveKind},
+    };
+
+    #[test]
+    fn test_scripted_dice_decides_fort_rolls() {
+        let game = game(KNIGHT_AND_PAWN);
+        let winning = game.rules.winning_roll;
+        let missed = (winning + 1_usize) % DICE_FACES;
+        let mut game = game.with_dice(ScriptedDice::from(vec![missed, winning]));
+        let knight = [at("1f4"), at("1d3")];
+        game.apply_move(Move::new(at("1e2"), at("1f2"), MoveKind::Step))
+            .unwrap();
+        game.apply_move(Move::new(knight[0_usize], knight[1_usize], MoveKind::Step))
+            .unwrap();
+        let outcome = game.apply_move(Move::dice_roll(at("1f2"))).unwrap();
+        assert_eq!((outcome.roll, outcome.winner), (Some(missed), false));
+        game.apply_move(Move::new(knight[1_usize], knight[0_usize], MoveKind::Step))
+            .unwrap();
+        let outcome = game.apply_move(Move::dice_roll(at("1f2"))).unwrap();
+        assert_eq!((outcome.roll, outcome.winner), (Some(winning), true));
+        assert!(!game.play);
+    }
+
+    #[test]
+    fn test_scripted_dice_repeats_script() {
+        let mut dice = ScriptedDice::from(vec![1_usize, 8_usize]);
+        let rolls = (0_usize..4_usize)
+            .map(|_| dice.roll())
+            .collect::<Vec<usize>>();
+        assert_eq!(rolls, vec![1_usize, 2_usize, 1_usize, 2_usize]);
+        assert_eq!(ScriptedDice::from(Vec::new()).roll(), 0_usize);
+    }
+
+    #[test]
+    fn test_seeded_dice_restores_from_state() {
+        let mut dice = SeededDice::from_seed(7_u64);
+        dice.roll();
+        let mut restored = SeededDice::from_state(dice.state().unwrap());
+        let rolls = (0_usize..16_usize)
+            .map(|_| (dice.roll(), restored.roll()))
+            .collect::<Vec<(usize, usize)>>();
+        assert!(rolls.iter().all(|(roll, restored)| roll == restored));
+    }
+}
@@ -0,0 +1,349 @@
+//! # fairness module
+//!
+//! Holds the commit-reveal scheme for the dice rolls of a network game, so that neither the host
+//! nor the player rolling the dice can pick the roll.
+//!
+//! 1. The host draws a seed and a [`Nonce`] from the operating system and sends its
+//!    [`Commitment`], the `SHA-256` hash of the nonce followed by the seed. The nonce keeps the seed
+//!    from being found by hashing every possible seed.
+//! 2. The player answers with a seed of its own, without knowing the seed of the host.
+//! 3. The host reveals its nonce and its seed, and the roll is drawn from both seeds.
+//!
+//! The host cannot change its seed once committed, and the player cannot know what its seed will
+//! roll. Each player checks the revealed roll with [`DiceProof::witness`] as it is played, against
+//! the commitment it saw and the seed it sent. Only that check binds the seed of the player.
+//!
+//! The [`DiceProof`] of each roll is kept in the history and written in the [`GameRecord`]. Read
+//! back later, [`DiceProof::check`] only shows that the proof is consistent with itself, that is
+//! the seed of the host matches the commitment and the roll is the one drawn from the seeds. It
+//! cannot show that the commitment was sent before the player picked its seed, nor that the seed
+//! of the player is the one it sent, as a host could make up both seeds after the fact.
+//!
+//! [`GameRecord`]: crate::notation::GameRecord
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    dice::{DiceRoller, SeededDice},
+    player::Team,
+    RED, RST,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The number of bytes of a [`Commitment`].
+const COMMITMENT_LEN: usize = 32_usize;
+/// The number of bytes of a [`Nonce`].
+const NONCE_LEN: usize = 32_usize;
+
+/// Fairness error enum.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// If a proof cannot be read.
+    #[error("{} The dice proof '{0}' is not valid. {}", RED, RST)]
+    InvalidProof(String),
+    /// If the revealed seed of the host is not the one it committed to.
+    #[error("{} The seed of the host does not match its commitment. {}", RED, RST)]
+    SeedMismatch,
+    /// If the host revealed a roll for another commitment than the one it sent.
+    #[error("{} The host revealed a roll it did not commit to. {}", RED, RST)]
+    CommitmentMismatch,
+    /// If the host drew the roll from another seed than the one the player sent.
+    #[error("{} The host did not use the seed of the player. {}", RED, RST)]
+    PlayerSeedMismatch,
+    /// If the roll is not the one drawn from the seeds.
+    #[error("{} The roll does not match the seeds, which roll {0}. {}", RED, RST)]
+    RollMismatch(usize),
+    /// If the fort win of a game record has no proof.
+    #[error("{} The winning roll of move {0} has no proof. {}", RED, RST)]
+    UnprovenRoll(usize),
+    /// If the roll of a team played by a person was revealed without a proof.
+    #[error("{} The dice roll of the {0:?} team has no proof. {}", RED, RST)]
+    MissingProof(Team),
+    /// If the operating system could not give any random bytes.
+    #[error("{} Could not draw random bytes: {0}. {}", RED, RST)]
+    NoEntropy(getrandom::Error),
+}
+
+/// The `SHA-256` hash of the nonce and the seed of the host, sent before the player picks its
+/// seed.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Commitment([u8; COMMITMENT_LEN]);
+
+/// The random bytes the host hashes before its seed in the [`Commitment`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Nonce([u8; NONCE_LEN]);
+
+/// The seeds a dice roll was drawn from, along with the commitment of the host.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DiceProof {
+    /// The commitment the host sent before the roll.
+    pub commitment: Commitment,
+    /// The nonce of the host, revealed along with its seed.
+    pub nonce: Nonce,
+    /// The seed of the host, revealed once the player sent its seed.
+    pub host_seed: u64,
+    /// The seed of the player rolling the dice.
+    pub player_seed: u64,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// To draw a new seed from the operating system.
+#[inline]
+pub fn random_seed() -> Result<u64, Error> {
+    let mut bytes = [0_u8; 8_usize];
+    getrandom::getrandom(&mut bytes).map_err(Error::NoEntropy)?;
+    Ok(u64::from_be_bytes(bytes))
+}
+
+/// To write the bytes as hexadecimal.
+#[inline]
+fn write_hex(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+}
+
+/// To read the bytes written as hexadecimal.
+fn parse_hex<const LEN: usize>(hex: &str) -> Result<[u8; LEN], Error> {
+    let invalid = || Error::InvalidProof(hex.to_string());
+    if !hex.is_ascii() || hex.len() != LEN * 2_usize {
+        return Err(invalid());
+    }
+    let mut bytes = [0_u8; LEN];
+    for (index, byte) in bytes.iter_mut().enumerate() {
+        let digits = hex
+            .get(index * 2_usize..index * 2_usize + 2_usize)
+            .ok_or_else(invalid)?;
+        *byte = u8::from_str_radix(digits, 16_u32).map_err(|_| invalid())?;
+    }
+    Ok(bytes)
+}
+
+/*████Commitment████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Commitment {
+    /// To commit to the seed, hashed after the nonce.
+    #[inline]
+    pub fn of(nonce: &Nonce, seed: u64) -> Self {
+        Commitment(
+            Sha256::new()
+                .chain_update(nonce.0)
+                .chain_update(seed.to_be_bytes())
+                .finalize()
+                .into(),
+        )
+    }
+}
+
+#[doc(hidden)]
+impl fmt::Display for Commitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(&self.0, f)
+    }
+}
+
+impl FromStr for Commitment {
+    type Err = Error;
+
+    /// To read the commitment written as hexadecimal.
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        Ok(Commitment(parse_hex(hex)?))
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Nonce████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Nonce {
+    /// To draw a new nonce from the operating system.
+    #[inline]
+    pub fn random() -> Result<Self, Error> {
+        let mut bytes = [0_u8; NONCE_LEN];
+        getrandom::getrandom(&mut bytes).map_err(Error::NoEntropy)?;
+        Ok(Nonce(bytes))
+    }
+}
+
+impl From<[u8; NONCE_LEN]> for Nonce {
+    #[inline]
+    fn from(bytes: [u8; NONCE_LEN]) -> Self {
+        Nonce(bytes)
+    }
+}
+
+#[doc(hidden)]
+impl fmt::Display for Nonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(&self.0, f)
+    }
+}
+
+impl FromStr for Nonce {
+    type Err = Error;
+
+    /// To read the nonce written as hexadecimal.
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        Ok(Nonce(parse_hex(hex)?))
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████DiceProof████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl DiceProof {
+    /// To create the proof of the roll drawn from both seeds, with the host committed to its seed
+    /// under the nonce.
+    #[inline]
+    pub fn new(nonce: Nonce, host_seed: u64, player_seed: u64) -> Self {
+        DiceProof {
+            commitment: Commitment::of(&nonce, host_seed),
+            nonce,
+            host_seed,
+            player_seed,
+        }
+    }
+    /// To get the roll drawn from the seeds. Returns a value between 0 and 5.
+    #[inline]
+    pub fn roll(&self) -> usize {
+        SeededDice::from_seed(self.host_seed ^ self.player_seed).roll()
+    }
+    /// To check that the proof is consistent with itself, that is the revealed nonce and seed of
+    /// the host hash to the commitment and the roll is the one drawn from the seeds.
+    ///
+    /// This does not show that the host committed before the player picked its seed, nor that the
+    /// seed of the player is the one it sent. Only [`DiceProof::witness`] checks that, as the roll
+    /// is played.
+    #[inline]
+    pub fn check(&self, roll: Option<usize>) -> Result<(), Error> {
+        if Commitment::of(&self.nonce, self.host_seed) != self.commitment {
+            return Err(Error::SeedMismatch);
+        }
+        match roll == Some(self.roll()) {
+            true => Ok(()),
+            false => Err(Error::RollMismatch(self.roll())),
+        }
+    }
+    /// To check the proof as a player that saw the commitment of the host, and sent its own seed
+    /// if it rolled the dice.
+    ///
+    /// The seed of the player is only bound to the roll for the player that sent it.
+    #[inline]
+    pub fn witness(
+        &self,
+        commitment: Commitment,
+        player_seed: Option<u64>,
+        roll: usize,
+    ) -> Result<(), Error> {
+        if self.commitment != commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+        if player_seed.map_or(false, |seed| seed != self.player_seed) {
+            return Err(Error::PlayerSeedMismatch);
+        }
+        self.check(Some(roll))
+    }
+}
+
+#[doc(hidden)]
+impl fmt::Display for DiceProof {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.commitment, self.nonce, self.host_seed, self.player_seed
+        )
+    }
+}
+
+impl FromStr for DiceProof {
+    type Err = Error;
+
+    /// To read the commitment, the nonce, the seed of the host and the seed of the player
+    /// separated by whitespace.
+    fn from_str(proof: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidProof(proof.to_string());
+        let parts = proof.split_whitespace().collect::<Vec<&str>>();
+        match parts[..] {
+            [commitment, nonce, host_seed, player_seed] => Ok(DiceProof {
+                commitment: commitment.parse::<Commitment>()?,
+                nonce: nonce.parse::<Nonce>()?,
+                host_seed: host_seed.parse::<u64>().map_err(|_| invalid())?,
+                player_seed: player_seed.parse::<u64>().map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// To create the proof of a roll with a fixed nonce.
+    fn proof() -> DiceProof {
+        DiceProof::new(Nonce::from([7_u8; NONCE_LEN]), 17_u64, 42_u64)
+    }
+
+    #[test]
+    fn test_proof_of_other_roll_is_rejected() {
+        let proof = proof();
+        assert!(proof.check(Some(proof.roll())).is_ok());
+        assert!(proof
+            .check(Some((proof.roll() + 1_usize) % 6_usize))
+            .is_err());
+        assert!(proof.check(None).is_err());
+    }
+
+    #[test]
+    fn test_revealed_seed_not_committed_is_rejected() {
+        let forged = DiceProof {
+            host_seed: 18_u64,
+            ..proof()
+        };
+        assert!(forged.check(Some(forged.roll())).is_err());
+        let forged = DiceProof {
+            nonce: Nonce::from([8_u8; NONCE_LEN]),
+            ..proof()
+        };
+        assert!(forged.check(Some(forged.roll())).is_err());
+    }
+
+    #[test]
+    fn test_witness_rejects_other_commitment_or_player_seed() {
+        let proof = proof();
+        let other = Commitment::of(&proof.nonce, 18_u64);
+        assert!(proof
+            .witness(proof.commitment, Some(42_u64), proof.roll())
+            .is_ok());
+        assert!(proof.witness(proof.commitment, None, proof.roll()).is_ok());
+        assert!(proof.witness(other, None, proof.roll()).is_err());
+        assert!(proof
+            .witness(proof.commitment, Some(43_u64), proof.roll())
+            .is_err());
+    }
+
+    #[test]
+    fn test_written_proof_reads_back() {
+        let proof = proof();
+        let written = proof.to_string();
+        assert_eq!(written.parse::<DiceProof>().unwrap(), proof);
+        assert!(written.starts_with(&format!("{} {} ", proof.commitment, proof.nonce)));
+        assert_eq!(
+            proof.nonce.to_string().parse::<Nonce>().unwrap(),
+            proof.nonce
+        );
+        for malformed in [
+            "",
+            "17 42",
+            written.trim_end_matches(" 42"),
+            &written.replacen('0', "g", 1_usize),
+            &format!("{written} 42"),
+        ] {
+            assert!(malformed.parse::<DiceProof>().is_err(), "{malformed:?}");
+        }
+    }
+}
@@ -3,12 +3,19 @@
 //! Game module to initialize, stop and exit the game.
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
-use crate::pieces::Piece;
-use crate::player::{Player, PlayerAction};
+use crate::board::Quadrant;
+use crate::dice::{self, DiceRoller, SeededDice};
+use crate::fairness::DiceProof;
+use crate::history::{History, MoveRecord};
+use crate::pieces::{self, Move, MoveKind, MoveOutcome, Piece, PieceType, Position};
+use crate::player::{Player, PlayerAction, PlayerKind, Team};
+use crate::rules::{GameRules, IllegalMoveReason};
+use crate::ruleset::RuleSet;
 use crate::Error;
+use serde::{Deserialize, Serialize};
 
 /// A struct to create a game object.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Game {
     /// To hold the player information.
     pub players: Vec<Player>,
@@ -20,15 +27,31 @@ pub struct Game {
     pub picked: bool,
     /// To notify if the game is still being played.
     pub play: bool,
+    /// To hold the number of players the game started with.
+    pub quadrant_active: usize,
+    /// The rules the game is played with. Saves from before the rules were kept load as classic.
+    #[serde(default)]
+    pub rules: RuleSet,
+    /// To roll the dice at the fort. Saved as the seed and the number of rolls made.
+    #[serde(
+        serialize_with = "dice::serialize",
+        deserialize_with = "dice::deserialize",
+        default = "dice::default_dice"
+    )]
+    pub dice: Box<dyn DiceRoller>,
+    /// To hold the moves played so far to undo and redo them. Not a part of the snapshot.
+    #[serde(skip)]
+    pub history: History,
 }
 
 /// To handle operations over the Game.
 pub trait GameAction {
     fn hunt(&mut self) -> Vec<Player>;
     fn next_player(&mut self) -> &mut Self;
-    fn update_position(&mut self, x: i32, y: i32) -> Result<&mut Self, Error>;
-    fn check_piece_in_pos(&self, x: f32, y: f32) -> bool;
-    fn remove_piece_in_pos(&mut self, x: f32, y: f32) -> Result<Option<Piece>, Error>;
+    fn apply_move(&mut self, step: Move) -> Result<MoveOutcome, Error>;
+    fn undo(&mut self) -> Result<Option<Move>, Error>;
+    fn redo(&mut self) -> Result<Option<MoveOutcome>, Error>;
+    fn check_piece_in_pos(&self, x: i32, y: i32) -> bool;
 }
 
 /*████Functions██████████████████████████████████████████████████████████████████████████████████*/
@@ -45,13 +68,116 @@ impl Game {
     #[inline]
     pub fn init(players: Vec<Player>) -> Self {
         Game {
+            quadrant_active: players.len(),
             players,
             turn: 0_usize,
             update: true,
             picked: false,
             play: true,
+            rules: RuleSet::default(),
+            dice: dice::default_dice(),
+            history: History::default(),
         }
     }
+    /// To create a new game for the given player names.
+    ///
+    /// Each player gets the team of its index. The defender is picked with the dice, which is then
+    /// kept as the dice of the game, and the rest of the players get the quadrants in order. The
+    /// pieces are laid out with the armies of the given rules.
+    #[inline]
+    pub fn new_match(names: Vec<String>, dice: SeededDice, rules: RuleSet) -> Result<Self, Error> {
+        Game::new_match_with_kinds(
+            names
+                .into_iter()
+                .map(|name| (name, PlayerKind::Human))
+                .collect::<Vec<(String, PlayerKind)>>(),
+            dice,
+            rules,
+        )
+    }
+    /// To create a new game for the given player names, some of which can be played by the
+    /// computer.
+    ///
+    /// Works the same as [`Game::new_match`]. The computer can play both the defender and the
+    /// attackers, so the defender is picked from all of the players.
+    pub fn new_match_with_kinds(
+        players: Vec<(String, PlayerKind)>,
+        mut dice: SeededDice,
+        rules: RuleSet,
+    ) -> Result<Self, Error> {
+        let count = players.len();
+        if count < 2_usize {
+            return Err(Error::TooFewPlayers(count));
+        }
+        // Every player needs a team of its own.
+        Team::from_index(count - 1_usize)?;
+        let defender = dice.pick(count);
+        let players = players
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, kind))| Ok((name, kind, Team::from_index(index)?)))
+            .collect::<Result<Vec<(String, PlayerKind, Team)>, Error>>()?;
+        Game::new_seated_match(players, defender, dice, rules)
+    }
+    /// To create a new game for the players seated at the given teams, with the player at the
+    /// given index as the defender.
+    ///
+    /// The players take their turns in the given order, and the attackers get the quadrants in
+    /// that order as well. Used by the [`Lobby`], where the players pick their own team.
+    ///
+    /// [`Lobby`]: crate::lobby::Lobby
+    pub fn new_seated_match(
+        players: Vec<(String, PlayerKind, Team)>,
+        defender: usize,
+        dice: SeededDice,
+        rules: RuleSet,
+    ) -> Result<Self, Error> {
+        let count = players.len();
+        if count < 2_usize {
+            return Err(Error::TooFewPlayers(count));
+        }
+        let mut quadrants = [Quadrant::Q1, Quadrant::Q2, Quadrant::Q3].into_iter();
+        let mut match_players = Vec::with_capacity(count);
+        for (index, (name, kind, team)) in players.into_iter().enumerate() {
+            let is_defender = index == defender;
+            let mut player = Player::from(
+                name,
+                team,
+                is_defender,
+                count,
+                match is_defender {
+                    true => Quadrant::NoQuad,
+                    false => quadrants.next().unwrap_or(Quadrant::NoQuad),
+                },
+                &rules,
+            )?;
+            player.set_kind(kind);
+            match_players.push(player);
+        }
+        Ok(Game::init(match_players).with_dice(dice).with_rules(rules))
+    }
+    /// To set the dice used by the game.
+    ///
+    /// Takes the game and returns it with the new dice, to be chained after [`Game::init`].
+    #[inline]
+    pub fn with_dice(mut self, dice: impl DiceRoller + 'static) -> Self {
+        self.dice = Box::new(dice);
+        self
+    }
+    /// To set the rules the game is played with.
+    ///
+    /// Takes the game and returns it with the new rules, to be chained after [`Game::init`]. The
+    /// pieces of the players are not laid out again, so they should be created with the same rules.
+    #[inline]
+    pub fn with_rules(mut self, rules: RuleSet) -> Self {
+        self.rules = rules;
+        self
+    }
+    /// To roll the dice of the game.
+    #[inline]
+    pub fn roll_dice(&mut self) -> usize {
+        self.dice.roll()
+    }
     /// To change the game state to `true`.
     ///
     /// Takes `self` reference and changes status to `true`.
@@ -111,6 +237,281 @@ impl Game {
     pub fn current_player_mut(&mut self) -> &mut Player {
         &mut self.players[self.turn]
     }
+    /// To update player pieces position every turn.
+    ///
+    /// Moves the chosen piece of the current player without checking the rules. Only to be used
+    /// once the move has been validated.
+    #[inline]
+    pub(crate) fn update_position(&mut self, x: i32, y: i32) -> Result<&mut Self, Error> {
+        self.players[self.turn].update_piece(x, y)?;
+        self.set_update_true();
+        Ok(self)
+    }
+    /// To know if the x and y holds a piece of another player.
+    ///
+    /// Takes x and y values and iterates over all the players in the games to decide which
+    /// particular piece is present and removes that piece to return it along with the index of
+    /// the player it belonged to.
+    #[inline]
+    pub(crate) fn remove_piece_in_pos(
+        &mut self,
+        x: i32,
+        y: i32,
+    ) -> Result<Option<(usize, Piece)>, Error> {
+        Piece::in_board_range(x, y)?;
+        for (player_index, player) in self.players.iter_mut().enumerate() {
+            if let Ok(index) = player.piece_index_from_xy_i32(x, y) {
+                return Ok(Some((player_index, player.kill_piece(index)?)));
+            }
+        }
+        Ok(None)
+    }
+    /// To remove the player at the given index once it runs out of pieces.
+    ///
+    /// The turn is shifted back if the removed player came before the current player so that the
+    /// turn still points to the same player.
+    #[inline]
+    fn eliminate_player(&mut self, index: usize) -> Player {
+        if index < self.turn {
+            self.turn -= 1_usize;
+        }
+        self.players.remove(index)
+    }
+    /// To check that a move can be played by the current player.
+    ///
+    /// Returns the index of the piece to move inside the current player pieces vector. A
+    /// [`MoveKind::Skip`] does not need a piece.
+    fn validate_move(&self, step: &Move) -> Result<Option<usize>, IllegalMoveReason> {
+        if !self.play {
+            return Err(IllegalMoveReason::GameOver);
+        }
+        if step.kind == MoveKind::Skip {
+            return Ok(None);
+        }
+        let index = match self
+            .current_player()
+            .piece_index_from_xy_i32(step.from.x, step.from.y)
+        {
+            Ok(index) => index,
+            Err(_) => {
+                return Err(match self.check_piece_in_pos(step.from.x, step.from.y) {
+                    true => IllegalMoveReason::NotPlayersPiece,
+                    false => IllegalMoveReason::NoPieceAtOrigin,
+                })
+            }
+        };
+        match self
+            .legal_moves(self.turn, index)
+            .map_err(|err| match err {
+                Error::PositionNotInQuadrant(..)
+                | Error::PieceModuleError(pieces::Error::IllegalPosition(..)) => {
+                    IllegalMoveReason::IllegalPosition
+                }
+                _ => IllegalMoveReason::NoPieceAtOrigin,
+            })?
+            .contains(step)
+        {
+            true => Ok(Some(index)),
+            false => Err(IllegalMoveReason::Unreachable),
+        }
+    }
+    /// To set the current player as the winner and end the game.
+    #[inline]
+    fn declare_winner(&mut self, record: &mut MoveRecord) {
+        self.current_player_mut().set_winner();
+        self.set_play_false();
+        record.winner = true;
+    }
+    /// To roll the dice for the current player and set it as the winner on the winning roll of the
+    /// [`RuleSet`].
+    ///
+    /// A roll that is already known, like one read from a game record, is used instead of rolling
+    /// again. Otherwise the state of the dice is recorded before it is rolled.
+    #[inline]
+    fn fort_dice_roll(&mut self, roll: Option<usize>, record: &mut MoveRecord) {
+        let roll = roll.unwrap_or_else(|| {
+            record.dice = self.dice.state();
+            self.roll_dice()
+        });
+        if roll == self.rules.winning_roll {
+            self.declare_winner(record);
+        }
+        record.roll = Some(roll);
+    }
+    /// To move the piece and kill the piece at the destination if there is one.
+    ///
+    /// A pawn is promoted once it is moved if the move says so. A player that loses all of its
+    /// pieces is removed from the game. The current player wins if it is the only one left, or if
+    /// it does not have to roll the dice once it reaches the opposite side.
+    fn move_piece(
+        &mut self,
+        index: usize,
+        step: &Move,
+        record: &mut MoveRecord,
+    ) -> Result<(), Error> {
+        record.captured = self.remove_piece_in_pos(step.to.x, step.to.y)?;
+        if let Some(loser) = self
+            .players
+            .iter()
+            .position(|player| player.pieces.is_empty() && !player.is_winner)
+        {
+            record.eliminated = Some((loser, self.eliminate_player(loser)));
+        }
+        self.current_player_mut().set_chosen_piece_index(index);
+        self.update_position(step.to.x, step.to.y)?;
+        if let Some(piece_type) = step.promotion {
+            self.current_player_mut()
+                .set_piece_type(&step.to, piece_type);
+        }
+        let player = self.current_player();
+        let arrived = player
+            .piece_index_from_xy_i32(step.to.x, step.to.y)
+            .map_or(false, |moved| {
+                player.piece_in_opposite_side(&player.pieces[moved])
+            });
+        if self.players.len() == 1_usize || (arrived && !self.rules.rolls_at_opposite_side(player))
+        {
+            self.declare_winner(record);
+        }
+        Ok(())
+    }
+    /// To validate and play a move, passing the turn to the next player.
+    ///
+    /// Returns the [`MoveRecord`] of the move that can be used to revert it.
+    fn play_move(&mut self, step: Move, roll: Option<usize>) -> Result<MoveRecord, Error> {
+        let index = self
+            .validate_move(&step)
+            .map_err(|reason| Error::IllegalMove(step, reason))?;
+        let mut record = MoveRecord {
+            step,
+            turn: self.turn,
+            chosen_piece_index: self.current_player().chosen_piece_index,
+            captured: None,
+            eliminated: None,
+            roll: None,
+            dice: None,
+            proof: None,
+            winner: false,
+            resigned: None,
+        };
+        match (step.kind, index) {
+            (MoveKind::Step | MoveKind::Capture, Some(index)) => {
+                self.move_piece(index, &step, &mut record)?
+            }
+            (MoveKind::FortDiceRoll, _) => self.fort_dice_roll(roll, &mut record),
+            _ => {}
+        }
+        self.set_update_true().next_player();
+        Ok(record)
+    }
+    /// To play a move with a dice roll that is already known, like one read from a game record.
+    ///
+    /// Works the same as [`GameAction::apply_move`] but a [`MoveKind::FortDiceRoll`] uses the given
+    /// roll instead of rolling the dice. With no roll the dice of the game is rolled.
+    pub fn replay_move(&mut self, step: Move, roll: Option<usize>) -> Result<MoveOutcome, Error> {
+        let record = self.play_move(step, roll)?;
+        let outcome = record.outcome();
+        self.history.push(record);
+        Ok(outcome)
+    }
+    /// To roll the dice for the piece at the position with the roll drawn from the seeds of the
+    /// proof, keeping the proof in the history.
+    ///
+    /// The proof is not checked here, see [`DiceProof::check`].
+    pub fn roll_with_proof(
+        &mut self,
+        at: Position,
+        proof: DiceProof,
+    ) -> Result<MoveOutcome, Error> {
+        let mut record = self.play_move(Move::dice_roll(at), Some(proof.roll()))?;
+        record.proof = Some(proof);
+        let outcome = record.outcome();
+        self.history.push(record);
+        Ok(outcome)
+    }
+    /// To remove the player of the team from the game when it resigns.
+    ///
+    /// The turn passes on if it was the turn of the player, and the player left wins if there is
+    /// only one. The resignation is kept in the history like a move, so undoing it puts the player
+    /// back. Returns `None` if the game is over or if the team is not in it, or if it is the last
+    /// player.
+    pub fn resign(&mut self, team: Team) -> Option<MoveOutcome> {
+        let record = self.remove_resigned(team)?;
+        let outcome = record.outcome();
+        self.history.push(record);
+        Some(outcome)
+    }
+    /// To remove the player of the team that resigned.
+    ///
+    /// Returns the [`MoveRecord`] of the resignation that can be used to revert it.
+    fn remove_resigned(&mut self, team: Team) -> Option<MoveRecord> {
+        if !self.play || self.players.len() < 2_usize {
+            return None;
+        }
+        let index = self.players.iter().position(|player| player.team == team)?;
+        let mut record = MoveRecord {
+            step: Move::skip(),
+            turn: self.turn,
+            chosen_piece_index: self.current_player().chosen_piece_index,
+            captured: None,
+            eliminated: None,
+            roll: None,
+            dice: None,
+            proof: None,
+            winner: false,
+            resigned: Some(team),
+        };
+        record.eliminated = Some((index, self.eliminate_player(index)));
+        if self.turn >= self.players.len() {
+            self.turn = 0_usize;
+        }
+        if self.players.len() == 1_usize {
+            self.declare_winner(&mut record);
+        }
+        self.set_update_true().set_picked_false();
+        Some(record)
+    }
+    /// To put the game back to the state it was in before the recorded move.
+    ///
+    /// The eliminated player is put back first so that the recorded indices point to the same
+    /// players as when the move was played. A promoted piece is turned back into a pawn. The winner
+    /// of a resignation is not the player whose turn it was, so every player loses the win. The
+    /// dice is rewound to before its roll, so that rolling again gives the same value.
+    fn revert_move(&mut self, record: &mut MoveRecord) -> Result<(), Error> {
+        if let Some(state) = record.dice {
+            self.dice = Box::new(SeededDice::from_state(state));
+        }
+        if let Some((index, player)) = record.eliminated.take() {
+            self.players.insert(index, player);
+        }
+        self.turn = record.turn;
+        if record.winner {
+            self.players
+                .iter_mut()
+                .for_each(|player| player.is_winner = false);
+            self.play = true;
+        }
+        let step = record.step;
+        if step.promotion.is_some() {
+            self.current_player_mut()
+                .set_piece_type(&step.to, PieceType::Pawn);
+        }
+        if let (MoveKind::Step | MoveKind::Capture, Ok(index)) = (
+            step.kind,
+            self.current_player()
+                .piece_index_from_xy_i32(step.to.x, step.to.y),
+        ) {
+            self.current_player_mut().set_chosen_piece_index(index);
+            self.update_position(step.from.x, step.from.y)?;
+        }
+        if let Some((index, piece)) = record.captured {
+            self.players[index].restore_piece(piece);
+        }
+        self.current_player_mut()
+            .set_chosen_piece_index(record.chosen_piece_index);
+        self.set_update_true();
+        Ok(())
+    }
 }
 /*-----------------------------------------------------------------------------------------------*/
 
@@ -138,42 +539,74 @@ impl GameAction for Game {
         }
         self
     }
-    /// To update player pieces position every turn.
-    #[inline]
-    fn update_position(&mut self, x: i32, y: i32) -> Result<&mut Self, Error> {
-        self.players[self.turn].update_piece(x, y)?;
-        self.set_update_true();
-        Ok(self)
+    /// To play a move for the current player.
+    ///
+    /// Checks the move against the [`GameRules`] and plays it depending on the [`MoveKind`]. The
+    /// turn is then passed to the next player. Returns [`Error::IllegalMove`] without changing the
+    /// game if the move is not allowed.
+    fn apply_move(&mut self, step: Move) -> Result<MoveOutcome, Error> {
+        self.replay_move(step, None)
+    }
+    /// To take back the last move that was played.
+    ///
+    /// Puts back the killed piece and the eliminated player, and gives the turn back to the player
+    /// that played the move. Returns the move that was undone, or `None` if there is nothing to
+    /// undo.
+    fn undo(&mut self) -> Result<Option<Move>, Error> {
+        let mut record = match self.history.pop_done() {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        self.revert_move(&mut record)?;
+        let step = record.step;
+        self.history.push_undone(record);
+        Ok(Some(step))
+    }
+    /// To play again the last move that was undone.
+    ///
+    /// A dice roll is redone with the same value that was rolled before, rolling the rewound dice
+    /// again if its state was recorded. A resignation removes the same player again. Returns
+    /// `None` if there is nothing to redo.
+    fn redo(&mut self) -> Result<Option<MoveOutcome>, Error> {
+        let record = match self.history.pop_undone() {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        let replayed = match record.resigned {
+            Some(team) => self
+                .remove_resigned(team)
+                .ok_or(Error::IllegalMove(record.step, IllegalMoveReason::GameOver)),
+            None if record.dice.is_some() => self.play_move(record.step, None),
+            None => self.play_move(record.step, record.roll),
+        };
+        match replayed {
+            Ok(mut replayed) => {
+                replayed.proof = record.proof;
+                let outcome = replayed.outcome();
+                self.history.push_redone(replayed);
+                Ok(Some(outcome))
+            }
+            Err(err) => {
+                self.history.push_undone(record);
+                Err(err)
+            }
+        }
     }
     /// Iterates through each piece in a player and searches for a position. If that position
     /// exists then returns true else returns false.
     ///
-    /// Takes `f32` x and y position values and [`binary_search`] the position in the given pieces.
+    /// Takes `i32` x and y position values and [`binary_search`] the position in the given pieces.
     ///
     /// [`binary_search`]: slice::binary_search
     #[inline]
-    fn check_piece_in_pos(&self, x: f32, y: f32) -> bool {
+    fn check_piece_in_pos(&self, x: i32, y: i32) -> bool {
         !self
             .players
             .iter()
-            .filter(|player| player.piece_index_from_xy_f32(x, y).is_ok())
+            .filter(|player| player.piece_index_from_xy_i32(x, y).is_ok())
             .collect::<Vec<&Player>>()
             .is_empty()
     }
-    /// To know if the x and y holds a piece of another player.
-    ///
-    /// Takes x and y values and iterates over all the players in the games to decide which
-    /// particular piece is present and removes that piece to return it.
-    #[inline]
-    fn remove_piece_in_pos(&mut self, x: f32, y: f32) -> Result<Option<Piece>, Error> {
-        Piece::in_board_range(x as i32, y as i32)?;
-        for player in self.players.iter_mut() {
-            if let Ok(index) = player.piece_index_from_xy_f32(x, y) {
-                return Ok(Some(player.kill_piece(index)?));
-            }
-        }
-        Ok(None)
-    }
 }
 /*-----------------------------------------------------------------------------------------------*/
 
@@ -185,6 +618,219 @@ impl Default for Game {
             update: bool::default(),
             picked: bool::default(),
             play: bool::default(),
+            quadrant_active: usize::default(),
+            rules: RuleSet::default(),
+            dice: dice::default_dice(),
+            history: History::default(),
+        }
+    }
+}
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::ScriptedDice;
+    use crate::fairness::{DiceProof, Nonce};
+    use crate::fixtures::{at, game, KNIGHT_AND_PAWN};
+
+    /// To play the move that should be rejected, checking that the game is left as it was.
+    fn rejection(game: &mut Game, step: Move) -> IllegalMoveReason {
+        let (position, turn, moves) = (
+            game.to_position_str().unwrap(),
+            game.turn,
+            game.history.moves(),
+        );
+        let reason = match game.apply_move(step) {
+            Err(Error::IllegalMove(rejected, reason)) if rejected == step => reason,
+            outcome => panic!("the move was not rejected: {outcome:?}"),
+        };
+        assert_eq!(game.to_position_str().unwrap(), position);
+        assert_eq!(game.turn, turn);
+        assert_eq!(game.history.moves(), moves);
+        reason
+    }
+
+    #[test]
+    fn test_move_after_game_over_is_rejected() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        game.set_play_false();
+        let step = Move::new(at("1e2"), at("1f2"), MoveKind::Step);
+        assert_eq!(rejection(&mut game, step), IllegalMoveReason::GameOver);
+    }
+
+    #[test]
+    fn test_move_from_empty_square_is_rejected() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        let step = Move::new(at("1a1"), at("1b1"), MoveKind::Step);
+        assert_eq!(
+            rejection(&mut game, step),
+            IllegalMoveReason::NoPieceAtOrigin
+        );
+    }
+
+    #[test]
+    fn test_move_of_other_players_piece_is_rejected() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        let step = Move::new(at("1f4"), at("1d3"), MoveKind::Step);
+        assert_eq!(
+            rejection(&mut game, step),
+            IllegalMoveReason::NotPlayersPiece
+        );
+    }
+
+    #[test]
+    fn test_move_out_of_reach_is_rejected() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        let step = Move::new(at("1e2"), at("1e4"), MoveKind::Step);
+        assert_eq!(rejection(&mut game, step), IllegalMoveReason::Unreachable);
+    }
+
+    /// To play the move and take it back, checking that the game is back to where it was.
+    fn undone(game: &mut Game, step: Move) -> MoveOutcome {
+        let (position, turn) = (game.to_position_str().unwrap(), game.turn);
+        let outcome = game.apply_move(step).unwrap();
+        assert_eq!(game.undo().unwrap(), Some(step));
+        assert_eq!(game.to_position_str().unwrap(), position);
+        assert_eq!(game.turn, turn);
+        outcome
+    }
+
+    #[test]
+    fn test_undo_capture_restores_piece() {
+        let mut game = game("RD=alice:N1f3,N1a1/B1=bobby:P1e2 B 2");
+        let step = Move::new(at("1e2"), at("1f3"), MoveKind::Capture);
+        let outcome = undone(&mut game, step);
+        assert!(outcome.captured.is_some());
+        assert_eq!(game.players[0_usize].pieces.len(), 2_usize);
+    }
+
+    #[test]
+    fn test_undo_elimination_restores_player() {
+        let mut game = game("RD=alice:N1f3/B1=bobby:P1e2/G2=carol:P2a1 B 3");
+        let step = Move::new(at("1e2"), at("1f3"), MoveKind::Capture);
+        let outcome = undone(&mut game, step);
+        assert_eq!(outcome.eliminated, Some(Team::Red));
+        assert!(!outcome.winner);
+        assert_eq!(game.players[0_usize].team, Team::Red);
+        assert_eq!(game.current_player().team, Team::Blue);
+    }
+
+    #[test]
+    fn test_undo_win_clears_winner() {
+        let mut game = game("RD=alice:N1f3/B1=bobby:P1e2 B 2");
+        let step = Move::new(at("1e2"), at("1f3"), MoveKind::Capture);
+        assert!(undone(&mut game, step).winner);
+        assert!(game.play);
+        assert!(game.players.iter().all(|player| !player.is_winner));
+    }
+
+    #[test]
+    fn test_undo_dice_roll_and_redo_keeps_roll() {
+        let mut game = game(KNIGHT_AND_PAWN).with_dice(ScriptedDice::from(vec![1_usize, 3_usize]));
+        game.apply_move(Move::new(at("1e2"), at("1f2"), MoveKind::Step))
+            .unwrap();
+        game.apply_move(Move::new(at("1f4"), at("1d3"), MoveKind::Step))
+            .unwrap();
+        let roll = Move::dice_roll(at("1f2"));
+        assert_eq!(undone(&mut game, roll).roll, Some(1_usize));
+        assert_eq!(game.history.moves().len(), 2_usize);
+        assert_eq!(game.redo().unwrap().unwrap().roll, Some(1_usize));
+        assert_eq!(game.history.moves().last(), Some(&roll));
+    }
+
+    #[test]
+    fn test_piece_outside_board_is_rejected() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        let outside = Position {
+            x: 20_i32,
+            y: 20_i32,
+        };
+        game.current_player_mut().pieces[0_usize].position = outside;
+        let step = Move::new(outside, at("1f2"), MoveKind::Step);
+        assert!(matches!(
+            game.apply_move(step),
+            Err(Error::IllegalMove(_, IllegalMoveReason::IllegalPosition))
+        ));
+        assert_eq!(game.current_player().pieces[0_usize].position, outside);
+        assert!(game.history.moves().is_empty());
+    }
+
+    #[test]
+    fn test_undo_dice_roll_rewinds_dice() {
+        let mut game = game(KNIGHT_AND_PAWN).with_dice(SeededDice::from_seed(7_u64));
+        game.apply_move(Move::new(at("1e2"), at("1f2"), MoveKind::Step))
+            .unwrap();
+        game.apply_move(Move::new(at("1f4"), at("1d3"), MoveKind::Step))
+            .unwrap();
+        let roll = Move::dice_roll(at("1f2"));
+        let before = game.dice.state();
+        let rolled = game.apply_move(roll).unwrap().roll;
+        let after = game.dice.state();
+        for _ in 0_usize..8_usize {
+            game.undo().unwrap();
+            assert_eq!(game.dice.state(), before);
+            assert_eq!(game.apply_move(roll).unwrap().roll, rolled);
         }
+        game.undo().unwrap();
+        assert_eq!(game.redo().unwrap().unwrap().roll, rolled);
+        assert_eq!(game.dice.state(), after);
+    }
+
+    #[test]
+    fn test_undo_proven_roll_and_redo_keeps_proof() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        game.apply_move(Move::new(at("1e2"), at("1f2"), MoveKind::Step))
+            .unwrap();
+        game.apply_move(Move::new(at("1f4"), at("1d3"), MoveKind::Step))
+            .unwrap();
+        let proof = DiceProof::new(Nonce::from([7_u8; 32_usize]), 17_u64, 42_u64);
+        let outcome = game.roll_with_proof(at("1f2"), proof).unwrap();
+        assert_eq!(outcome.roll, Some(proof.roll()));
+        game.undo().unwrap();
+        assert_eq!(game.redo().unwrap().unwrap().roll, Some(proof.roll()));
+        assert_eq!(game.history.records().last().unwrap().proof, Some(proof));
+    }
+
+    #[test]
+    fn test_new_move_clears_redo() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        undone(&mut game, Move::new(at("1e2"), at("1f2"), MoveKind::Step));
+        assert!(game.history.can_redo());
+        game.apply_move(Move::skip()).unwrap();
+        assert!(!game.history.can_redo());
+        assert!(game.redo().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_undo_resignation_restores_player() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        let position = game.to_position_str().unwrap();
+        assert!(game.resign(Team::Red).unwrap().winner);
+        assert!(game.undo().unwrap().is_some());
+        assert_eq!(game.to_position_str().unwrap(), position);
+        assert!(game.play && !game.players[1_usize].is_winner);
+        assert_eq!(game.redo().unwrap().unwrap().eliminated, Some(Team::Red));
+        assert_eq!(game.players.len(), 1_usize);
+    }
+
+    #[test]
+    fn test_promotion_changes_piece_type_and_undo_reverts_it() {
+        let mut game = game(KNIGHT_AND_PAWN).with_rules(RuleSet {
+            pawn_promotion: true,
+            ..RuleSet::default()
+        });
+        let step = Move::new(at("1e2"), at("1f2"), MoveKind::Step).with_promotion(PieceType::Rook);
+        game.apply_move(step).unwrap();
+        assert_eq!(
+            game.players[1_usize].pieces[0_usize].piece_type,
+            PieceType::Rook
+        );
+        game.undo().unwrap();
+        assert_eq!(
+            game.players[1_usize].pieces[0_usize].piece_type,
+            PieceType::Pawn
+        );
     }
 }
@@ -0,0 +1,127 @@
+//! # history module
+//!
+//! Holds the record of the moves played in a [`Game`] so that they can be undone and redone.
+//!
+//! [`Game`]: crate::game::Game
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    dice::DiceState,
+    fairness::DiceProof,
+    pieces::{Move, MoveOutcome, Piece},
+    player::{Player, Team},
+};
+
+/// The record of a single [`Move`] applied to the game.
+///
+/// Holds everything that the move took away from the game so that it can be put back.
+#[derive(Debug)]
+pub(crate) struct MoveRecord {
+    /// The move that was played.
+    pub(crate) step: Move,
+    /// The turn of the player that played the move.
+    pub(crate) turn: usize,
+    /// The chosen piece index of the player before the move was played.
+    pub(crate) chosen_piece_index: usize,
+    /// The killed piece along with the index of the player it belonged to.
+    pub(crate) captured: Option<(usize, Piece)>,
+    /// The player that lost all of its pieces along with its index in the players vector.
+    pub(crate) eliminated: Option<(usize, Player)>,
+    /// The value of the dice roll, if any.
+    pub(crate) roll: Option<usize>,
+    /// The state of the dice before it was rolled for the move, to rewind it when the move is
+    /// undone.
+    pub(crate) dice: Option<DiceState>,
+    /// The proof of the dice roll, if it was drawn by the commit-reveal scheme.
+    pub(crate) proof: Option<DiceProof>,
+    /// If the move won the game.
+    pub(crate) winner: bool,
+    /// The team that resigned, if the record is a resignation instead of a move.
+    pub(crate) resigned: Option<Team>,
+}
+
+/// The history of the moves played in a [`Game`].
+///
+/// [`Game`]: crate::game::Game
+#[derive(Debug, Default)]
+pub struct History {
+    /// The moves that have been applied, oldest first.
+    done: Vec<MoveRecord>,
+    /// The moves that have been undone, the latest undone move last.
+    undone: Vec<MoveRecord>,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████MoveRecord████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl MoveRecord {
+    /// To get the [`MoveOutcome`] of the recorded move.
+    #[inline]
+    pub(crate) fn outcome(&self) -> MoveOutcome {
+        MoveOutcome {
+            captured: self.captured.map(|(_, piece)| piece),
+            eliminated: self.eliminated.as_ref().map(|(_, player)| player.team),
+            roll: self.roll,
+            winner: self.winner,
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████History████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl History {
+    /// To record a newly played move.
+    ///
+    /// Playing a new move drops the moves that were undone as they cannot be redone anymore.
+    #[inline]
+    pub(crate) fn push(&mut self, record: MoveRecord) {
+        self.done.push(record);
+        self.undone.clear();
+    }
+    /// To record a move that was redone.
+    #[inline]
+    pub(crate) fn push_redone(&mut self, record: MoveRecord) {
+        self.done.push(record);
+    }
+    /// To take out the latest applied move to undo it.
+    #[inline]
+    pub(crate) fn pop_done(&mut self) -> Option<MoveRecord> {
+        self.done.pop()
+    }
+    /// To keep a move that was undone so that it can be redone.
+    #[inline]
+    pub(crate) fn push_undone(&mut self, record: MoveRecord) {
+        self.undone.push(record);
+    }
+    /// To take out the latest undone move to redo it.
+    #[inline]
+    pub(crate) fn pop_undone(&mut self) -> Option<MoveRecord> {
+        self.undone.pop()
+    }
+    /// To check if there is any move that can be undone.
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+    }
+    /// To check if there is any move that can be redone.
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+    /// To get the records of the moves that have been applied, oldest first.
+    #[inline]
+    pub(crate) fn records(&self) -> &[MoveRecord] {
+        &self.done
+    }
+    /// To get the moves that have been applied, oldest first.
+    #[inline]
+    pub fn moves(&self) -> Vec<Move> {
+        self.done
+            .iter()
+            .map(|record| record.step)
+            .collect::<Vec<Move>>()
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
@@ -7,15 +7,27 @@
 #![feature(drain_filter)]
 
 //--------------//
+pub mod ai;
 pub mod board;
+pub mod dice;
+pub mod fairness;
 pub mod game;
+pub mod history;
+pub mod lobby;
+pub mod notation;
+pub mod perft;
 pub mod pieces;
 pub mod player;
+pub mod position;
+pub mod rules;
+pub mod ruleset;
+pub mod snapshot;
 //--------------//
 
 use game::Game;
+use pieces::Move;
 use player::Player;
-use std::time::SystemTime;
+use rules::IllegalMoveReason;
 use thiserror::Error;
 
 /// Holds the breadth size of the board.
@@ -35,6 +47,24 @@ pub enum Error {
     /// Player modile error.
     #[error("{} Error in the player module: {0} {}", RED, RST)]
     PlayerModuleError(#[from] player::Error),
+    /// Fairness module error.
+    #[error("{} Error in the fairness module: {0} {}", RED, RST)]
+    FairnessModuleError(#[from] fairness::Error),
+    /// Lobby module error.
+    #[error("{} Error in the lobby module: {0} {}", RED, RST)]
+    LobbyModuleError(#[from] lobby::Error),
+    /// Notation module error.
+    #[error("{} Error in the notation module: {0} {}", RED, RST)]
+    NotationModuleError(#[from] notation::Error),
+    /// Perft module error.
+    #[error("{} Error in the perft module: {0} {}", RED, RST)]
+    PerftModuleError(#[from] perft::Error),
+    /// Position module error.
+    #[error("{} Error in the position module: {0} {}", RED, RST)]
+    PositionModuleError(#[from] position::Error),
+    /// Snapshot module error.
+    #[error("{} Error in the snapshot module: {0} {}", RED, RST)]
+    SnapshotModuleError(#[from] snapshot::Error),
     /// If invalid Quadrant index was provided.
     #[error(
         "{} The provided index {0} does not have a quadrant corresponding to it. {}",
@@ -49,6 +79,12 @@ pub enum Error {
         RST
     )]
     PositionNotInQuadrant(i32, i32),
+    /// When a move breaks the rules of the game.
+    #[error("{} The move {0:?} is illegal: {1}. {}", RED, RST)]
+    IllegalMove(Move, IllegalMoveReason),
+    /// When a game is created with less than two players.
+    #[error("{} A game needs at least 2 players, found {0}. {}", RED, RST)]
+    TooFewPlayers(usize),
     /// When more than one winner exists.
     #[error("{} There seems to be more than one winner. {}", RED, RST)]
     MoreThanOneWinner(usize),
@@ -79,22 +115,6 @@ pub fn exit(game: Game) -> Result<Option<Player>, Error> {
     results(winners)
 }
 
-/// To get a random value between 1 and 6.
-///
-/// Simulates a dice roll to get a value between 1 to 6. In exceptional cases you might be
-/// constantly getting 1 as return value. This will happen if the system date is set before
-/// 01/01/1970 (Unix Epoch). Please regain sanity and change it back to the current date.
-#[inline]
-pub fn dice_roll() -> usize {
-    match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
-        Ok(n) => (n.as_secs() % 6_u64) as usize,
-        Err(_) => {
-            eprintln!("{} System date set earlier than UNIX EPOCH {}", RED, RST);
-            1_usize
-        }
-    }
-}
-
 /// Function to take in a number and reduce exactly 1 from it if it is greater than 0.
 /// Used to fix the zero axis 'issue' in the board.
 ///
@@ -112,10 +132,37 @@ where
 
 /*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
 
+/// Positions shared by the tests of the modules, written as position strings.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use crate::{game::Game, notation::parse_square, pieces::Position};
+
+    /// The red defender has a knight on the edge of [`Quadrant::Q1`] and the blue attacker a pawn
+    /// one step away from it. Blue plays next.
+    ///
+    /// [`Quadrant::Q1`]: crate::board::Quadrant::Q1
+    pub(crate) const KNIGHT_AND_PAWN: &str = "RD=alice:N1f4/B1=bobby:P1e2 B 2";
+
+    /// To set up the game at the position.
+    #[inline]
+    pub(crate) fn game(position: &str) -> Game {
+        Game::from_position_str(position).unwrap()
+    }
+
+    /// To read the square into a position.
+    #[inline]
+    pub(crate) fn at(square: &str) -> Position {
+        parse_square(square).unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
-    fn test_str_from_string() {
-        assert!("str1", str_from_string("str1".to_string()))
+    fn test_decrement_if_positive() {
+        assert_eq!(decrement_if_positive(1_usize), 0_usize);
+        assert_eq!(decrement_if_positive(0_usize), 0_usize);
     }
 }
@@ -0,0 +1,349 @@
+//! # lobby module
+//!
+//! Holds the [`Lobby`] where the players gather before a new game. Each of the four teams has a
+//! [`Seat`], which a player takes when it enters the lobby. The players can then claim another
+//! free team, ask to play the defender and mark themselves as ready. The game starts once every
+//! seated player is ready.
+//!
+//! The defender is picked with the dice from the players that asked for it, or from every player
+//! if no one did. The lobby is used the same way by a game played on a single window and by the
+//! host of a network game, where the players send their requests as a [`LobbyRequest`].
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    dice::SeededDice,
+    game::Game,
+    player::{Player, PlayerKind, Team},
+    ruleset::RuleSet,
+    RED, RST,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The number of seats of the lobby, one for each team.
+const SEAT_COUNT: usize = 4_usize;
+
+/// Lobby error enum.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// If every seat of the lobby is taken.
+    #[error("{} There is no free seat left in the lobby. {}", RED, RST)]
+    NoFreeSeat,
+    /// If the seat of the team is held by another player.
+    #[error("{} The seat of the {0:?} team is already taken. {}", RED, RST)]
+    SeatTaken(Team),
+    /// If no player holds the seat of the team.
+    #[error("{} No player holds the seat of the {0:?} team. {}", RED, RST)]
+    EmptySeat(Team),
+    /// If the player is not ready when the game is started.
+    #[error("{} The player '{0}' is not ready yet. {}", RED, RST)]
+    NotReady(String),
+    /// If the game is started with less than two players.
+    #[error("{} A game needs at least 2 players, found {0}. {}", RED, RST)]
+    TooFewPlayers(usize),
+}
+
+/// A seat of the lobby before the game starts.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Seat {
+    /// The team of the seat.
+    pub team: Team,
+    /// The name of the player holding the seat, if any.
+    pub name: Option<String>,
+    /// Who plays the player holding the seat.
+    pub kind: PlayerKind,
+    /// If the player asked to play the defender.
+    pub defender: bool,
+    /// If the player is ready to start.
+    pub ready: bool,
+}
+
+/// A request of a player in the lobby.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub enum LobbyRequest {
+    /// To take the seat of the team.
+    Claim(Team),
+    /// To ask to play the defender, or to stop asking.
+    Defender(bool),
+    /// To be marked as ready to start, or not.
+    Ready(bool),
+    /// To start the game. Only the host may start it.
+    Start,
+}
+
+/// The seats of the players before the game starts.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Lobby {
+    /// The seats in the order of the teams.
+    seats: Vec<Seat>,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Seat████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Seat {
+    /// To create the seat of the team with no player in it.
+    #[inline]
+    pub fn free(team: Team) -> Self {
+        Seat {
+            team,
+            name: None,
+            kind: PlayerKind::Human,
+            defender: false,
+            ready: false,
+        }
+    }
+    /// To check if no player holds the seat.
+    #[inline]
+    pub fn is_free(&self) -> bool {
+        self.name.is_none()
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Lobby████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Default for Lobby {
+    /// To create a lobby with every seat free.
+    #[inline]
+    fn default() -> Self {
+        Lobby {
+            seats: (usize::MIN..SEAT_COUNT)
+                .filter_map(|index| Team::from_index(index).ok())
+                .map(Seat::free)
+                .collect::<Vec<Seat>>(),
+        }
+    }
+}
+
+impl Lobby {
+    /// To create the lobby from the seats sent by a host. The seats that are missing are free.
+    #[inline]
+    pub fn from_seats(seats: Vec<Seat>) -> Self {
+        let mut lobby = Lobby::default();
+        seats.into_iter().for_each(|seat| {
+            let index = seat.team.as_usize();
+            lobby.seats[index] = seat;
+        });
+        lobby
+    }
+    /// To get the seats in the order of the teams.
+    #[inline]
+    pub fn seats(&self) -> &[Seat] {
+        &self.seats
+    }
+    /// To get the seat of the team.
+    #[inline]
+    pub fn seat(&self, team: Team) -> &Seat {
+        &self.seats[team.as_usize()]
+    }
+    /// To get the seats held by a player.
+    #[inline]
+    pub fn seated(&self) -> impl Iterator<Item = &Seat> {
+        self.seats.iter().filter(|seat| !seat.is_free())
+    }
+    /// To get the first free seat after the seat of the team, going round the teams in order.
+    #[inline]
+    pub fn next_free(&self, team: Team) -> Option<Team> {
+        (1_usize..SEAT_COUNT)
+            .map(|offset| &self.seats[(team.as_usize() + offset) % SEAT_COUNT])
+            .find(|seat| seat.is_free())
+            .map(|seat| seat.team)
+    }
+    /// To seat the player in the first free seat. Returns the team of the seat.
+    ///
+    /// The players of the computer are always ready.
+    pub fn sit(&mut self, name: String, kind: PlayerKind) -> Result<Team, crate::Error> {
+        let name = Player::validate_name(name)?;
+        let seat = self
+            .seats
+            .iter_mut()
+            .find(|seat| seat.is_free())
+            .ok_or(Error::NoFreeSeat)?;
+        seat.name = Some(name);
+        seat.kind = kind;
+        seat.ready = kind == PlayerKind::Computer;
+        Ok(seat.team)
+    }
+    /// To handle the request of the player holding the seat of the team. Returns the team of the
+    /// seat the player holds afterwards.
+    ///
+    /// A request to start only checks that the game can start, the game itself is created by
+    /// [`Lobby::start`].
+    pub fn request(&mut self, team: Team, request: &LobbyRequest) -> Result<Team, Error> {
+        if self.seat(team).is_free() {
+            return Err(Error::EmptySeat(team));
+        }
+        let seat = &mut self.seats[team.as_usize()];
+        match *request {
+            LobbyRequest::Claim(claimed) => return self.claim(team, claimed),
+            LobbyRequest::Defender(defender) => seat.defender = defender,
+            LobbyRequest::Ready(ready) => seat.ready = ready,
+            LobbyRequest::Start => self.check()?,
+        }
+        Ok(team)
+    }
+    /// To move the player from the seat of the team to the free seat of the claimed team.
+    fn claim(&mut self, team: Team, claimed: Team) -> Result<Team, Error> {
+        if team == claimed {
+            return Ok(team);
+        }
+        if !self.seat(claimed).is_free() {
+            return Err(Error::SeatTaken(claimed));
+        }
+        let seat = std::mem::replace(&mut self.seats[team.as_usize()], Seat::free(team));
+        self.seats[claimed.as_usize()] = Seat {
+            team: claimed,
+            ..seat
+        };
+        Ok(claimed)
+    }
+    /// To free the seat of the team.
+    #[inline]
+    pub fn leave(&mut self, team: Team) {
+        self.seats[team.as_usize()] = Seat::free(team);
+    }
+    /// To check that there are enough players and that every one of them is ready.
+    pub fn check(&self) -> Result<(), Error> {
+        let count = self.seated().count();
+        if count < 2_usize {
+            return Err(Error::TooFewPlayers(count));
+        }
+        match self.seated().find(|seat| !seat.ready) {
+            Some(seat) => Err(Error::NotReady(seat.name.clone().unwrap_or_default())),
+            None => Ok(()),
+        }
+    }
+    /// To create the game for the seated players once every one of them is ready.
+    ///
+    /// The players take their turns in the order of their teams. The defender is picked with the
+    /// dice, which is then kept as the dice of the game.
+    pub fn start(&self, mut dice: SeededDice, rules: RuleSet) -> Result<Game, crate::Error> {
+        self.check()?;
+        let seated = self.seated().collect::<Vec<&Seat>>();
+        let volunteers = (usize::MIN..seated.len())
+            .filter(|&index| seated[index].defender)
+            .collect::<Vec<usize>>();
+        let defender = match volunteers.is_empty() {
+            true => dice.pick(seated.len()),
+            false => volunteers[dice.pick(volunteers.len())],
+        };
+        Game::new_seated_match(
+            seated
+                .into_iter()
+                .map(|seat| (seat.name.clone().unwrap_or_default(), seat.kind, seat.team))
+                .collect::<Vec<(String, PlayerKind, Team)>>(),
+            defender,
+            dice,
+            rules,
+        )
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// To seat alice and bobby, then carol as a computer player.
+    fn seated() -> Lobby {
+        let mut lobby = Lobby::default();
+        for (name, kind) in [
+            ("alice", PlayerKind::Human),
+            ("bobby", PlayerKind::Human),
+            ("carol", PlayerKind::Computer),
+        ] {
+            lobby.sit(String::from(name), kind).unwrap();
+        }
+        lobby
+    }
+
+    #[test]
+    fn test_players_sit_in_order_of_teams() {
+        let mut lobby = seated();
+        let teams = lobby.seated().map(|seat| seat.team).collect::<Vec<Team>>();
+        assert_eq!(teams, vec![Team::Red, Team::Blue, Team::Green]);
+        assert!(lobby.sit(String::from("x"), PlayerKind::Human).is_err());
+        assert_eq!(
+            lobby.sit(String::from("david"), PlayerKind::Human).unwrap(),
+            Team::Yellow
+        );
+        assert!(lobby.sit(String::from("erin"), PlayerKind::Human).is_err());
+    }
+
+    #[test]
+    fn test_taken_seat_cannot_be_claimed() {
+        let mut lobby = seated();
+        assert!(lobby
+            .request(Team::Red, &LobbyRequest::Claim(Team::Green))
+            .is_err());
+        assert_eq!(
+            lobby
+                .request(Team::Red, &LobbyRequest::Claim(Team::Yellow))
+                .unwrap(),
+            Team::Yellow
+        );
+        assert!(lobby.seat(Team::Red).is_free());
+        assert_eq!(lobby.next_free(Team::Blue), Some(Team::Red));
+        assert!(lobby
+            .request(Team::Red, &LobbyRequest::Ready(true))
+            .is_err());
+    }
+
+    #[test]
+    fn test_start_waits_for_every_person() {
+        let mut lobby = seated();
+        assert!(lobby.request(Team::Blue, &LobbyRequest::Start).is_err());
+        lobby
+            .request(Team::Red, &LobbyRequest::Ready(true))
+            .unwrap();
+        assert!(matches!(lobby.check(), Err(Error::NotReady(name)) if name == "bobby"));
+        lobby
+            .request(Team::Blue, &LobbyRequest::Ready(true))
+            .unwrap();
+        assert!(lobby.check().is_ok());
+        let mut alone = Lobby::default();
+        alone.sit(String::from("alice"), PlayerKind::Human).unwrap();
+        assert!(matches!(alone.check(), Err(Error::TooFewPlayers(1_usize))));
+    }
+
+    #[test]
+    fn test_volunteer_plays_defender() {
+        let mut lobby = seated();
+        lobby
+            .request(Team::Red, &LobbyRequest::Claim(Team::Yellow))
+            .unwrap();
+        lobby
+            .request(Team::Blue, &LobbyRequest::Defender(true))
+            .unwrap();
+        for team in [Team::Yellow, Team::Blue] {
+            lobby.request(team, &LobbyRequest::Ready(true)).unwrap();
+        }
+        let game = lobby
+            .start(SeededDice::from_seed(7_u64), RuleSet::default())
+            .unwrap();
+        let players = game
+            .players
+            .iter()
+            .map(|player| (player.name.as_str(), player.team, player.is_defender))
+            .collect::<Vec<(&str, Team, bool)>>();
+        assert_eq!(
+            players,
+            vec![
+                ("bobby", Team::Blue, true),
+                ("carol", Team::Green, false),
+                ("alice", Team::Yellow, false),
+            ]
+        );
+        assert!(game.players[1_usize].is_computer());
+    }
+
+    #[test]
+    fn test_seats_rebuild_same_lobby() {
+        let lobby = seated();
+        assert_eq!(Lobby::from_seats(lobby.seats().to_vec()), lobby);
+    }
+}
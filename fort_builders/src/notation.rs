@@ -0,0 +1,777 @@
+//! # notation module
+//!
+//! Holds the fort chess notation (FCN) to write down the moves of a [`Game`] and read them back.
+//!
+//! ## Squares
+//! A square is written as the quadrant number, a file letter and a rank number counted from the
+//! bottom left corner of that quadrant. `1a1` is the bottom left square of [`Quadrant::Q1`] and
+//! `2d6` is the top right square of [`Quadrant::Q2`].
+//!
+//! ## Moves
+//! Each move starts with the letter of the piece type (`R`, `M`, `Q`, `P` or `N`).
+//!
+//! | Move                  | Notation  |
+//! |-----------------------|-----------|
+//! | Step                  | `P1f2-1e2`|
+//! | Capture               | `R3a1x3a4`|
+//! | Dice roll at the fort | `Q2b1@5`  |
+//! | Skip                  | `--`      |
+//!
+//! A dice roll can be written without the roll, like `Q2b1@`, to roll the dice when it is played.
+//! A step or a capture that promotes a pawn ends with `=` and the letter of the new type, like
+//! `P1e4-1f4=Q`.
+//!
+//! ## Game record
+//! A game record holds the headers followed by the moves separated by whitespace:
+//! ```text
+//! [Red "alice"]
+//! [Blue "bob"]
+//! [Defender "Red"]
+//! [Q1 "Blue"]
+//! [Result "*"]
+//!
+//! P1f2-1e2 P1c2-1d2 --
+//! ```
+//! There is one header for each team with the name of the player, in the order of the turns. The
+//! `Result` holds the winning team or `*` if the game is not over. A game played with one of the
+//! house [`Variant`]s has a `[Variant "Storm"]` header as well, without it the classic rules are
+//! played. Unknown headers are ignored.
+//!
+//! A dice roll drawn by the commit-reveal scheme of the [`fairness`] module has a `Proof` header
+//! with the number of the move counted from 1, the commitment of the host, the nonce of the host,
+//! the seed of the host and the seed of the player:
+//! ```text
+//! [Proof "12 307351d590b868582dfae9b5b99ebe8280653eed9ccf991148312ffe03000642 9f1c0e6b2d47a8350c6e91f2b4d8a7c31e5f60a9d2b7c4e81f03a6d59b2e7c40 17 42"]
+//! ```
+//!
+//! Such a proof can only be checked to be consistent with itself, see the [`fairness`] module.
+//!
+//! [`fairness`]: crate::fairness
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    board::Quadrant,
+    fairness::{self, DiceProof},
+    game::Game,
+    pieces::{Move, MoveKind, PieceType, Position},
+    player::{Player, PlayerAction, Team},
+    ruleset::Variant,
+    RED, RST,
+};
+use std::{collections::BTreeMap, fmt, str::FromStr};
+use thiserror::Error;
+
+/// The number of moves written on each line of a game record.
+const MOVES_PER_LINE: usize = 8_usize;
+/// The notation of a skip.
+const SKIP: &str = "--";
+/// The result of a game that is not over.
+const ONGOING: &str = "*";
+/// The separator between a move and the type the pawn is promoted to.
+const PROMOTION: char = '=';
+
+/// Notation error enum.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// If the square is not written as quadrant, file and rank.
+    #[error("{} The square '{0}' is not valid. {}", RED, RST)]
+    InvalidSquare(String),
+    /// If the position cannot be written as a square.
+    #[error("{} The position ({0}, {1}) is not inside a quadrant. {}", RED, RST)]
+    PositionNotInQuadrant(i32, i32),
+    /// If the move cannot be read.
+    #[error("{} The move '{0}' is not valid. {}", RED, RST)]
+    InvalidMove(String),
+    /// If the header cannot be read.
+    #[error("{} The header '{0}' is not valid. {}", RED, RST)]
+    InvalidHeader(String),
+    /// If a header needed to set up the game is not present.
+    #[error("{} The header '{0}' is missing. {}", RED, RST)]
+    MissingHeader(&'static str),
+    /// If the team in a header does not play in the game.
+    #[error("{} The team '{0}' does not play in the game. {}", RED, RST)]
+    UnknownTeam(String),
+    /// If the game record does not have enough players to play.
+    #[error("{} A game needs at least 2 players, found {0}. {}", RED, RST)]
+    TooFewPlayers(usize),
+    /// If the piece at the start of the move is not the one in the record.
+    #[error("{} The move '{0}' does not move a piece of that type. {}", RED, RST)]
+    PieceMismatch(String),
+    /// If the recorded moves do not lead to the same game.
+    #[error("{} The game cannot be recorded: {0}. {}", RED, RST)]
+    IncompleteHistory(&'static str),
+    /// If replaying the moves does not give the recorded result.
+    #[error("{} The recorded result does not match the moves played. {}", RED, RST)]
+    ResultMismatch,
+    /// If the variant in the header is not known.
+    #[error("{} The variant '{0}' is not known. {}", RED, RST)]
+    UnknownVariant(String),
+}
+
+/// A player as written in the headers of a [`GameRecord`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RecordedPlayer {
+    /// The name of the player.
+    pub name: String,
+    /// The team of the player.
+    pub team: Team,
+    /// The quadrant the player starts from. The defender starts from [`Quadrant::NoQuad`].
+    pub quadrant: Quadrant,
+}
+
+/// A [`Move`] as written in a [`GameRecord`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct RecordedMove {
+    /// The type of the moved piece. A skip does not move a piece.
+    pub piece_type: Option<PieceType>,
+    /// The move that was played.
+    pub step: Move,
+    /// The value of the dice roll, if any.
+    pub roll: Option<usize>,
+}
+
+/// The record of a whole game that can be written and replayed.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct GameRecord {
+    /// The players of the game in the order of their turns.
+    pub players: Vec<RecordedPlayer>,
+    /// The moves played, oldest first.
+    pub moves: Vec<RecordedMove>,
+    /// The winning team, or `None` if the game is not over.
+    pub result: Option<Team>,
+    /// The variant of the rules the game is played with.
+    pub variant: Variant,
+    /// The proofs of the dice rolls by the index of their move.
+    pub proofs: BTreeMap<usize, DiceProof>,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Squares████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To get the number of the quadrant used in the notation.
+#[inline]
+fn quadrant_number(quadrant: Quadrant) -> Option<u32> {
+    match quadrant {
+        Quadrant::Q1 => Some(1_u32),
+        Quadrant::Q2 => Some(2_u32),
+        Quadrant::Q3 => Some(3_u32),
+        Quadrant::NoQuad => None,
+    }
+}
+
+/// To write a [`Position`] as a square.
+pub fn square(position: &Position) -> Result<String, Error> {
+    let (x, y) = (position.x, position.y);
+    let quadrant = Quadrant::from_xy(x, y).map_err(|_| Error::PositionNotInQuadrant(x, y))?;
+    let (number, (origin_x, origin_y)) = match (quadrant_number(quadrant), quadrant.origin()) {
+        (Some(number), Some(origin)) => (number, origin),
+        _ => return Err(Error::PositionNotInQuadrant(x, y)),
+    };
+    Ok(format!(
+        "{}{}{}",
+        number,
+        (b'a' + (x - origin_x) as u8) as char,
+        y - origin_y + 1_i32
+    ))
+}
+
+/// To read a square into a [`Position`].
+///
+/// The square has to be inside the quadrant it names.
+pub fn parse_square(square: &str) -> Result<Position, Error> {
+    let invalid = || Error::InvalidSquare(square.to_string());
+    let mut chars = square.chars();
+    let quadrant = match chars.next().and_then(|ch| ch.to_digit(10_u32)) {
+        Some(number @ 1_u32..=3_u32) => {
+            Quadrant::from_index(number as usize - 1_usize).map_err(|_| invalid())?
+        }
+        _ => return Err(invalid()),
+    };
+    let file = match chars.next() {
+        Some(file @ 'a'..='z') => file as i32 - 'a' as i32,
+        _ => return Err(invalid()),
+    };
+    let rank = chars.as_str().parse::<i32>().map_err(|_| invalid())?;
+    let (origin_x, origin_y) = quadrant.origin().ok_or_else(invalid)?;
+    let (x, y) = (origin_x + file, origin_y + rank - 1_i32);
+    match Quadrant::from_xy(x, y) {
+        Ok(found) if found == quadrant => Ok(Position { x, y }),
+        _ => Err(invalid()),
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████RecordedPlayer████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl From<&Player> for RecordedPlayer {
+    #[inline]
+    fn from(player: &Player) -> Self {
+        RecordedPlayer {
+            name: player.name.clone(),
+            team: player.team,
+            quadrant: player.quadrant,
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████RecordedMove████*/
+/*-----------------------------------------------------------------------------------------------*/
+#[doc(hidden)]
+impl fmt::Display for RecordedMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let piece_type = match (self.step.kind, self.piece_type) {
+            (MoveKind::Skip, _) => return write!(f, "{SKIP}"),
+            (_, Some(piece_type)) => piece_type,
+            (_, None) => return Err(fmt::Error),
+        };
+        let from = square(&self.step.from).map_err(|_| fmt::Error)?;
+        write!(f, "{}{}", piece_type.as_char(), from)?;
+        match (self.step.kind, self.roll) {
+            (MoveKind::FortDiceRoll, Some(roll)) => write!(f, "@{roll}"),
+            (MoveKind::FortDiceRoll, None) => write!(f, "@"),
+            (MoveKind::Step, _) => write!(f, "-{}", square(&self.step.to).map_err(|_| fmt::Error)?),
+            (MoveKind::Capture, _) => {
+                write!(f, "x{}", square(&self.step.to).map_err(|_| fmt::Error)?)
+            }
+            _ => Err(fmt::Error),
+        }?;
+        match self.step.promotion {
+            Some(piece_type) => write!(f, "{PROMOTION}{}", piece_type.as_char()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for RecordedMove {
+    type Err = Error;
+
+    /// To read a move written in the notation.
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidMove(token.to_string());
+        if token == SKIP {
+            return Ok(RecordedMove {
+                piece_type: None,
+                step: Move::skip(),
+                roll: None,
+            });
+        }
+        let piece_type = token
+            .chars()
+            .next()
+            .and_then(PieceType::from_char)
+            .ok_or_else(invalid)?;
+        let (from, separator, rest) = match (
+            token.get(1_usize..4_usize),
+            token.get(4_usize..5_usize),
+            token.get(5_usize..),
+        ) {
+            (Some(from), Some(separator), Some(rest)) => (parse_square(from)?, separator, rest),
+            _ => return Err(invalid()),
+        };
+        let (rest, promotion) = match rest.split_once(PROMOTION) {
+            Some((rest, letter)) => {
+                let mut letters = letter.chars();
+                match (
+                    letters.next().and_then(PieceType::from_char),
+                    letters.next(),
+                ) {
+                    (Some(piece_type), None) if separator != "@" => (rest, Some(piece_type)),
+                    _ => return Err(invalid()),
+                }
+            }
+            None => (rest, None),
+        };
+        let (mut step, roll) = match separator {
+            "-" => (Move::new(from, parse_square(rest)?, MoveKind::Step), None),
+            "x" => (
+                Move::new(from, parse_square(rest)?, MoveKind::Capture),
+                None,
+            ),
+            "@" => (
+                Move::dice_roll(from),
+                match rest {
+                    "" => None,
+                    rest => Some(rest.parse::<usize>().map_err(|_| invalid())?),
+                },
+            ),
+            _ => return Err(invalid()),
+        };
+        step.promotion = promotion;
+        Ok(RecordedMove {
+            piece_type: Some(piece_type),
+            step,
+            roll,
+        })
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████GameRecord████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To get the [`Team`] from its name.
+#[inline]
+fn team_from_str(teamstr: &str) -> Result<Team, Error> {
+    (0_usize..4_usize)
+        .filter_map(|index| Team::from_index(index).ok())
+        .find(|team| Team::teamstr_from_team(*team) == teamstr)
+        .ok_or_else(|| Error::UnknownTeam(teamstr.to_string()))
+}
+
+/// To read a header line into its key and value.
+#[inline]
+fn parse_header(line: &str) -> Result<(&str, &str), Error> {
+    line.strip_prefix('[')
+        .and_then(|line| line.strip_suffix(']'))
+        .and_then(|line| line.split_once(' '))
+        .and_then(|(key, value)| {
+            value
+                .trim()
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .map(|value| (key, value))
+        })
+        .ok_or_else(|| Error::InvalidHeader(line.to_string()))
+}
+
+impl GameRecord {
+    /// To record the moves played in the game so far.
+    ///
+    /// The players eliminated during the game are taken back from the history. The moves are
+    /// replayed from the start to find the moved pieces, so the history has to hold every move
+    /// since the game was created. A game loaded from a snapshot cannot be recorded, and neither can
+    /// a game played with rules that are not one of the [`Variant`]s or a game a player resigned
+    /// from, as the notation has no resignation.
+    pub fn from_game(game: &Game) -> Result<Self, crate::Error> {
+        let variant = Variant::from_rules(&game.rules).ok_or(Error::IncompleteHistory(
+            "the rules are not a known variant",
+        ))?;
+        let records = game.history.records();
+        let mut players = game
+            .players
+            .iter()
+            .map(RecordedPlayer::from)
+            .collect::<Vec<RecordedPlayer>>();
+        records
+            .iter()
+            .rev()
+            .filter_map(|record| record.eliminated.as_ref())
+            .for_each(|(index, player)| players.insert(*index, RecordedPlayer::from(player)));
+        let mut record = GameRecord {
+            players,
+            moves: Vec::new(),
+            result: None,
+            variant,
+            proofs: BTreeMap::new(),
+        };
+        let mut replay = record.start()?;
+        for (index, step_record) in records.iter().enumerate() {
+            if step_record.resigned.is_some() {
+                return Err(Error::IncompleteHistory("a player resigned").into());
+            }
+            let step = step_record.step;
+            let piece_type = match step.kind {
+                MoveKind::Skip => None,
+                _ => {
+                    let player = replay.current_player();
+                    let index = player
+                        .piece_index_from_xy_i32(step.from.x, step.from.y)
+                        .map_err(|_| Error::IncompleteHistory("a moved piece is missing"))?;
+                    Some(player.pieces[index].piece_type)
+                }
+            };
+            replay.replay_move(step, step_record.roll)?;
+            if let Some(proof) = step_record.proof {
+                record.proofs.insert(index, proof);
+            }
+            record.moves.push(RecordedMove {
+                piece_type,
+                step,
+                roll: step_record.roll,
+            });
+        }
+        if replay.turn != game.turn
+            || replay.players.len() != game.players.len()
+            || replay
+                .players
+                .iter()
+                .zip(game.players.iter())
+                .any(|(replayed, player)| replayed.pieces != player.pieces)
+        {
+            return Err(
+                Error::IncompleteHistory("the history does not start from the first move").into(),
+            );
+        }
+        record.result = GameRecord::winner(game);
+        Ok(record)
+    }
+    /// To play the recorded moves on a new game.
+    ///
+    /// Dice rolls use the recorded values so the replayed game ends the same way. A dice roll with
+    /// a proof has to be the roll drawn from its seeds.
+    pub fn replay(&self) -> Result<Game, crate::Error> {
+        let mut game = self.start()?;
+        for (index, recorded) in self.moves.iter().enumerate() {
+            if let Some(piece_type) = recorded.piece_type {
+                let from = recorded.step.from;
+                let player = game.current_player();
+                match player.piece_index_from_xy_i32(from.x, from.y) {
+                    Ok(index) if player.pieces[index].piece_type == piece_type => {}
+                    _ => return Err(Error::PieceMismatch(recorded.to_string()).into()),
+                }
+            }
+            match self.proofs.get(&index) {
+                Some(proof) => {
+                    proof.check(recorded.roll)?;
+                    game.roll_with_proof(recorded.step.from, *proof)?;
+                }
+                None => {
+                    game.replay_move(recorded.step, recorded.roll)?;
+                }
+            }
+        }
+        if GameRecord::winner(&game) != self.result {
+            return Err(Error::ResultMismatch.into());
+        }
+        Ok(game)
+    }
+    /// To replay the game and check that its winner did not win with a dice roll at the fort
+    /// that has no proof.
+    ///
+    /// The proofs of the other rolls are checked while replaying. Like [`DiceProof::check`], this
+    /// only shows that the proofs are consistent with themselves.
+    pub fn verify(&self) -> Result<Game, crate::Error> {
+        let game = self.replay()?;
+        let last = self.moves.len().saturating_sub(1_usize);
+        match self.moves.last() {
+            Some(recorded)
+                if self.result.is_some()
+                    && recorded.step.kind == MoveKind::FortDiceRoll
+                    && !self.proofs.contains_key(&last) =>
+            {
+                Err(fairness::Error::UnprovenRoll(last + 1_usize).into())
+            }
+            _ => Ok(game),
+        }
+    }
+    /// To create the game as it was before the first move, with the rules of the variant.
+    fn start(&self) -> Result<Game, crate::Error> {
+        if self.players.len() < 2_usize {
+            return Err(Error::TooFewPlayers(self.players.len()).into());
+        }
+        let quadrant_active = self.players.len();
+        let rules = self.variant.rules();
+        Ok(Game::init(
+            self.players
+                .iter()
+                .map(|player| {
+                    Player::from(
+                        player.name.clone(),
+                        player.team,
+                        player.quadrant == Quadrant::NoQuad,
+                        quadrant_active,
+                        player.quadrant,
+                        &rules,
+                    )
+                })
+                .collect::<Result<Vec<Player>, _>>()?,
+        )
+        .with_rules(rules))
+    }
+    /// To get the team of the winner of the game, if any.
+    #[inline]
+    fn winner(game: &Game) -> Option<Team> {
+        game.players
+            .iter()
+            .find(|player| player.is_winner)
+            .map(|player| player.team)
+    }
+}
+
+#[doc(hidden)]
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for player in self.players.iter() {
+            writeln!(
+                f,
+                "[{} \"{}\"]",
+                Team::teamstr_from_team(player.team),
+                player.name
+            )?;
+        }
+        if let Some(defender) = self
+            .players
+            .iter()
+            .find(|player| player.quadrant == Quadrant::NoQuad)
+        {
+            writeln!(
+                f,
+                "[Defender \"{}\"]",
+                Team::teamstr_from_team(defender.team)
+            )?;
+        }
+        for quadrant in [Quadrant::Q1, Quadrant::Q2, Quadrant::Q3] {
+            if let (Some(player), Some(number)) = (
+                self.players
+                    .iter()
+                    .find(|player| player.quadrant == quadrant),
+                quadrant_number(quadrant),
+            ) {
+                writeln!(
+                    f,
+                    "[Q{} \"{}\"]",
+                    number,
+                    Team::teamstr_from_team(player.team)
+                )?;
+            }
+        }
+        if self.variant != Variant::Classic {
+            writeln!(f, "[Variant \"{}\"]", self.variant)?;
+        }
+        writeln!(
+            f,
+            "[Result \"{}\"]",
+            self.result.map_or(ONGOING, Team::teamstr_from_team)
+        )?;
+        for (index, proof) in self.proofs.iter() {
+            writeln!(f, "[Proof \"{} {proof}\"]", index + 1_usize)?;
+        }
+        for line in self.moves.chunks(MOVES_PER_LINE) {
+            writeln!(f)?;
+            for (index, recorded) in line.iter().enumerate() {
+                match index {
+                    0_usize => write!(f, "{recorded}")?,
+                    _ => write!(f, " {recorded}")?,
+                }
+            }
+        }
+        writeln!(f)
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = Error;
+
+    /// To read a game record written in the notation.
+    fn from_str(record: &str) -> Result<Self, Self::Err> {
+        let mut players: Vec<RecordedPlayer> = Vec::new();
+        let mut quadrants: Vec<(Quadrant, Team)> = Vec::new();
+        let (mut defender, mut result) = (None, None);
+        let mut variant = Variant::Classic;
+        let mut moves: Vec<RecordedMove> = Vec::new();
+        let mut proofs = BTreeMap::new();
+        for line in record.lines().map(str::trim) {
+            if !line.starts_with('[') {
+                for token in line.split_whitespace() {
+                    moves.push(token.parse::<RecordedMove>()?);
+                }
+                continue;
+            }
+            let (key, value) = parse_header(line)?;
+            match key {
+                "Red" | "Blue" | "Green" | "Yellow" => players.push(RecordedPlayer {
+                    name: value.to_string(),
+                    team: team_from_str(key)?,
+                    quadrant: Quadrant::NoQuad,
+                }),
+                "Defender" => defender = Some(team_from_str(value)?),
+                "Q1" => quadrants.push((Quadrant::Q1, team_from_str(value)?)),
+                "Q2" => quadrants.push((Quadrant::Q2, team_from_str(value)?)),
+                "Q3" => quadrants.push((Quadrant::Q3, team_from_str(value)?)),
+                "Variant" => {
+                    variant = value
+                        .parse::<Variant>()
+                        .map_err(|_| Error::UnknownVariant(value.to_string()))?
+                }
+                "Proof" => {
+                    let (number, proof) = value
+                        .split_once(' ')
+                        .ok_or_else(|| Error::InvalidHeader(line.to_string()))?;
+                    match number.parse::<usize>() {
+                        Ok(number) if number > 0_usize => {
+                            let proof = proof
+                                .parse::<DiceProof>()
+                                .map_err(|_| Error::InvalidHeader(line.to_string()))?;
+                            proofs.insert(number - 1_usize, proof);
+                        }
+                        _ => return Err(Error::InvalidHeader(line.to_string())),
+                    }
+                }
+                "Result" => {
+                    result = match value {
+                        ONGOING => Some(None),
+                        team => Some(Some(team_from_str(team)?)),
+                    }
+                }
+                _ => {}
+            }
+        }
+        let defender = defender.ok_or(Error::MissingHeader("Defender"))?;
+        for (quadrant, team) in quadrants {
+            match players.iter_mut().find(|player| player.team == team) {
+                Some(player) if team != defender => player.quadrant = quadrant,
+                _ => {
+                    return Err(Error::UnknownTeam(
+                        Team::teamstr_from_team(team).to_string(),
+                    ))
+                }
+            }
+        }
+        if !players.iter().any(|player| player.team == defender) {
+            return Err(Error::UnknownTeam(
+                Team::teamstr_from_team(defender).to_string(),
+            ));
+        }
+        if players
+            .iter()
+            .any(|player| player.team != defender && player.quadrant == Quadrant::NoQuad)
+        {
+            return Err(Error::MissingHeader("Q1, Q2 or Q3"));
+        }
+        Ok(GameRecord {
+            players,
+            moves,
+            result: result.ok_or(Error::MissingHeader("Result"))?,
+            variant,
+            proofs,
+        })
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dice::SeededDice, game::GameAction, rules::GameRules, ruleset::RuleSet};
+
+    /// To play the first legal move of each turn until the game is over or the moves run out.
+    fn played(moves: usize) -> Game {
+        let names = vec![
+            String::from("alice"),
+            String::from("bobby"),
+            String::from("carol"),
+        ];
+        let mut game =
+            Game::new_match(names, SeededDice::from_seed(7_u64), RuleSet::default()).unwrap();
+        for _ in 0_usize..moves {
+            if !game.play {
+                break;
+            }
+            let step = game.all_legal_moves()[0_usize];
+            game.apply_move(step).unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn test_written_record_replays_same_game() {
+        let game = played(40_usize);
+        let record = GameRecord::from_game(&game).unwrap();
+        let parsed = record.to_string().parse::<GameRecord>().unwrap();
+        assert_eq!(parsed, record);
+        let replayed = parsed.replay().unwrap();
+        assert_eq!(
+            replayed.to_position_str().unwrap(),
+            game.to_position_str().unwrap()
+        );
+        assert_eq!(replayed.history.moves(), game.history.moves());
+    }
+
+    #[test]
+    fn test_written_move_reads_back() {
+        let game = played(0_usize);
+        let steps = game.all_legal_moves().into_iter();
+        for step in steps.filter(|step| step.kind != MoveKind::Skip) {
+            let recorded = RecordedMove {
+                piece_type: Some(PieceType::Pawn),
+                step,
+                roll: None,
+            };
+            assert_eq!(
+                recorded.to_string().parse::<RecordedMove>().unwrap(),
+                recorded
+            );
+        }
+    }
+
+    #[test]
+    fn test_promotion_is_written_after_move() {
+        let step = Move::new(
+            parse_square("1e2").unwrap(),
+            parse_square("1f2").unwrap(),
+            MoveKind::Step,
+        );
+        let recorded = RecordedMove {
+            piece_type: Some(PieceType::Pawn),
+            step: step.with_promotion(PieceType::Rook),
+            roll: None,
+        };
+        assert_eq!(recorded.to_string(), "P1e2-1f2=R");
+        assert_eq!(
+            recorded.to_string().parse::<RecordedMove>().unwrap(),
+            recorded
+        );
+    }
+
+    #[test]
+    fn test_malformed_move_is_rejected() {
+        let tokens = [
+            "",
+            "-",
+            "P",
+            "P1e",
+            "P1e2",
+            "P1e2-",
+            "X1e2-1f2",
+            "P4a1-4a2",
+            "P1e2?1f2",
+            "P1e2@x",
+            "P1e2@3=Q",
+            "P1e2-1f2=",
+            "P1e2-1f2=QQ",
+            "P1e2-1z9",
+            "P\u{e9}2-1f2",
+            "P1e\u{e9}-1f2",
+            "P1e2-\u{e9}",
+            "\u{e9}\u{e9}\u{e9}",
+        ];
+        for token in tokens {
+            assert!(token.parse::<RecordedMove>().is_err(), "{token:?}");
+        }
+    }
+
+    #[test]
+    fn test_malformed_record_is_rejected() {
+        let record = GameRecord::from_game(&played(4_usize)).unwrap().to_string();
+        let without_defender = record
+            .lines()
+            .filter(|line| !line.starts_with("[Defender"))
+            .collect::<Vec<&str>>()
+            .join("\n");
+        assert!(without_defender.parse::<GameRecord>().is_err());
+        assert!(format!("{record} P1e\u{e9}-1f2")
+            .parse::<GameRecord>()
+            .is_err());
+        assert!(record
+            .replace("[Result \"*\"]", "[Result \"Purple\"]")
+            .parse::<GameRecord>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_proof_header_reads_back() {
+        let proof = DiceProof::new(fairness::Nonce::from([7_u8; 32_usize]), 17_u64, 42_u64);
+        let mut record = GameRecord::from_game(&played(0_usize)).unwrap();
+        record.proofs.insert(3_usize, proof);
+        let written = record.to_string();
+        assert!(written.contains(&format!("[Proof \"4 {proof}\"]")));
+        assert_eq!(written.parse::<GameRecord>().unwrap(), record);
+        assert!(written
+            .replace(&format!("\"4 {proof}\""), "\"4 17 42\"")
+            .parse::<GameRecord>()
+            .is_err());
+    }
+}
@@ -0,0 +1,196 @@
+//! # perft module
+//!
+//! Holds the perft utility that counts the leaf nodes of the move tree of a [`Game`] to a given
+//! depth. The counts of known positions are kept in a suite, so that a change to the rules shows up
+//! as a change in the counts.
+//!
+//! Every legal move of the [`GameRules`] is a branch, [`MoveKind::Skip`] included. A
+//! [`MoveKind::FortDiceRoll`] is played with the [`missed_roll`] of the rules, which never wins, so
+//! the counts do not depend on the dice. A game that is over has no moves left.
+//!
+//! ## Suite
+//! Each line of a suite holds a position string, a `|` and the counts from depth 1 upwards:
+//! ```text
+//! # A knight in the middle of a quadrant.
+//! RD=alice:N2c3/B2=bobby:P2a6 R 2 | 7 15 64 130
+//! ```
+//! The empty lines and the lines starting with `#` are skipped. The suite of the rules is kept in
+//! `perft_suite.txt` and checked by the tests.
+//!
+//! [`MoveKind::Skip`]: crate::pieces::MoveKind::Skip
+//! [`MoveKind::FortDiceRoll`]: crate::pieces::MoveKind::FortDiceRoll
+//! [`missed_roll`]: crate::ruleset::RuleSet::missed_roll
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    game::{Game, GameAction},
+    pieces::Move,
+    rules::GameRules,
+    RED, RST,
+};
+use thiserror::Error;
+
+/// The separator between the position and the counts of a suite line.
+const COUNT_SEPARATOR: char = '|';
+/// The start of a comment line in a suite.
+const COMMENT: char = '#';
+
+/// Perft error enum.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// If a line of the suite cannot be read.
+    #[error("{} The suite line '{0}' is not valid. {}", RED, RST)]
+    InvalidSuiteLine(String),
+}
+
+/// A position of the suite with its counts.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SuiteEntry {
+    /// The position string.
+    pub position: String,
+    /// The expected counts from depth 1 upwards.
+    pub counts: Vec<u64>,
+}
+
+/// A count of the suite that does not match the rules.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Mismatch {
+    /// The position string.
+    pub position: String,
+    /// The depth of the count.
+    pub depth: u32,
+    /// The count in the suite.
+    pub expected: u64,
+    /// The count found with the rules.
+    pub found: u64,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Perft████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To count the leaf nodes of the move tree to the depth.
+///
+/// Every move is played and then undone, so the game is left as it was.
+pub fn perft(game: &mut Game, depth: u32) -> Result<u64, crate::Error> {
+    if depth == 0_u32 {
+        return Ok(1_u64);
+    }
+    if !game.play {
+        return Ok(0_u64);
+    }
+    let mut nodes = 0_u64;
+    for step in game.all_legal_moves() {
+        game.replay_move(step, Some(game.rules.missed_roll()))?;
+        nodes += perft(game, depth - 1_u32)?;
+        game.undo()?;
+    }
+    Ok(nodes)
+}
+
+/// To count the leaf nodes under each move of the player whose turn it is.
+///
+/// The counts add up to the [`perft`] of the depth. Used to find the move where two counts differ.
+pub fn divide(game: &mut Game, depth: u32) -> Result<Vec<(Move, u64)>, crate::Error> {
+    let mut counts = Vec::new();
+    if depth == 0_u32 || !game.play {
+        return Ok(counts);
+    }
+    for step in game.all_legal_moves() {
+        game.replay_move(step, Some(game.rules.missed_roll()))?;
+        counts.push((step, perft(game, depth - 1_u32)?));
+        game.undo()?;
+    }
+    Ok(counts)
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Suite████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To read the positions and their counts from a suite.
+pub fn parse_suite(suite: &str) -> Result<Vec<SuiteEntry>, Error> {
+    suite
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with(COMMENT))
+        .map(|line| {
+            let invalid = || Error::InvalidSuiteLine(line.to_string());
+            let (position, counts) = line.split_once(COUNT_SEPARATOR).ok_or_else(invalid)?;
+            Ok(SuiteEntry {
+                position: position.trim().to_string(),
+                counts: counts
+                    .split_whitespace()
+                    .map(|count| count.parse::<u64>().map_err(|_| invalid()))
+                    .collect::<Result<Vec<u64>, Error>>()?,
+            })
+        })
+        .collect::<Result<Vec<SuiteEntry>, Error>>()
+}
+
+/// To check every count of the suite against the rules.
+///
+/// Returns the counts that do not match, which is empty if the rules did not change.
+pub fn check_suite(suite: &str) -> Result<Vec<Mismatch>, crate::Error> {
+    let mut mismatches = Vec::new();
+    for entry in parse_suite(suite)? {
+        let mut game = Game::from_position_str(&entry.position)?;
+        for (depth, &expected) in (1_u32..).zip(entry.counts.iter()) {
+            let found = perft(&mut game, depth)?;
+            if found != expected {
+                mismatches.push(Mismatch {
+                    position: entry.position.clone(),
+                    depth,
+                    expected,
+                    found,
+                });
+            }
+        }
+    }
+    Ok(mismatches)
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{game, KNIGHT_AND_PAWN};
+
+    #[test]
+    fn test_suite_counts_match() {
+        let mismatches = check_suite(include_str!("../perft_suite.txt")).unwrap();
+        assert!(mismatches.is_empty(), "{mismatches:#?}");
+    }
+
+    #[test]
+    fn test_divide_adds_up_to_perft() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        let total = perft(&mut game, 3_u32).unwrap();
+        let divided = divide(&mut game, 3_u32).unwrap();
+        assert_eq!(divided.iter().map(|(_, count)| count).sum::<u64>(), total);
+    }
+
+    #[test]
+    fn test_perft_leaves_game_unchanged() {
+        let mut game = game(KNIGHT_AND_PAWN);
+        perft(&mut game, 3_u32).unwrap();
+        assert_eq!(game.to_position_str().unwrap(), KNIGHT_AND_PAWN);
+        assert!(game.history.moves().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_suite_line_is_rejected() {
+        for line in [
+            "RD=alice:N1f4/B1=bobby:P1e2 B 2",
+            "RD=alice:N1f4 | 7",
+            "| 1 2",
+        ] {
+            assert!(check_suite(line).is_err(), "{line:?}");
+        }
+        assert!(matches!(
+            parse_suite("RD=alice:N1f4/B1=bobby:P1e2 B 2 | 3 x"),
+            Err(Error::InvalidSuiteLine(_))
+        ));
+    }
+}
@@ -6,17 +6,20 @@
 mod piece_alignment;
 
 use crate::{
-    board::{Quadrant, X_MAX, X_MIN, Y_MAX, Y_MIN},
+    board::{Quadrant, BOARD_LAYOUT},
+    player::Team,
+    ruleset::RuleSet,
     RED, RST,
 };
 use piece_alignment::{piece_type, position_from_quadrant};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
 /// To hold the number of maximum enemies at a given point for `defender`.
 const DEFND_COUNT: usize = 24_usize;
 /// To hold the number of maximum enemies at a given point for `non-defender`.
-const ENEMY_COUNT: usize = 8_usize;
+pub(crate) const ENEMY_COUNT: usize = 8_usize;
 
 /// Piece error enum.
 #[derive(Error, Debug)]
@@ -55,7 +58,7 @@ pub enum Error {
 ///
 /// All the possible chess pieces to be used in game.
 /// Does not contain king as this variation doesn't have it.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum PieceType {
     Rook,     // 0
     Minister, // 1
@@ -68,7 +71,7 @@ pub enum PieceType {
 ///
 /// The x value corresponds to the x axis.
 /// Similarly, the y value corresponds to the y axis.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct Position {
     /// The x-axis value.
     pub x: i32,
@@ -77,7 +80,7 @@ pub struct Position {
 }
 
 /// Piece struct that holds the type and the position of each piece.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub struct Piece {
     /// To hold the type information for the piece.
     pub piece_type: PieceType,
@@ -85,6 +88,48 @@ pub struct Piece {
     pub position: Position,
 }
 
+/// The kind of [`Move`] a player can make in a turn.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub enum MoveKind {
+    /// Moving a piece to an empty position.
+    Step,
+    /// Moving a piece to a position held by another player's piece.
+    Capture,
+    /// Rolling the dice with a piece that has reached the opposite side.
+    FortDiceRoll,
+    /// Passing the turn to the next player.
+    Skip,
+}
+
+/// A move played by the current player.
+///
+/// For [`MoveKind::FortDiceRoll`] the `from` and `to` positions are the same. For
+/// [`MoveKind::Skip`] the positions are not used.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub struct Move {
+    /// The position of the piece to move.
+    pub from: Position,
+    /// The position to move the piece to.
+    pub to: Position,
+    /// The kind of the move.
+    pub kind: MoveKind,
+    /// The type a pawn is promoted to once it is moved, if any.
+    pub promotion: Option<PieceType>,
+}
+
+/// The result of a [`Move`] applied to the game.
+#[derive(Debug, Default)]
+pub struct MoveOutcome {
+    /// The piece that was killed by the move, if any.
+    pub captured: Option<Piece>,
+    /// The team of the player that lost all of its pieces due to the move, if any.
+    pub eliminated: Option<Team>,
+    /// The value of the dice roll, if any.
+    pub roll: Option<usize>,
+    /// If the move won the game.
+    pub winner: bool,
+}
+
 /*████Functions██████████████████████████████████████████████████████████████████████████████████*/
 
 /*████PieceType████*/
@@ -112,19 +157,35 @@ impl<'a> PieceType {
 }
 
 impl PieceType {
-    /// Returns a [`Result`] type with [`PieceType`] by taking in a `u8` value to correspond with
-    /// it.
-    ///
-    /// [`Result`]: std::result::Result
-    #[inline]
-    fn from_index(index: u8) -> Result<PieceType, Error> {
-        match index {
-            0_u8 => Ok(PieceType::Rook),
-            1_u8 => Ok(PieceType::Minister),
-            2_u8 => Ok(PieceType::Queen),
-            3_u8 => Ok(PieceType::Pawn),
-            4_u8 => Ok(PieceType::Knight),
-            _ => Err(Error::InvalidPieceTypeIndex(index)),
+    /// The types that a pawn can be promoted to, the strongest first.
+    pub const PROMOTIONS: [PieceType; 4_usize] = [
+        PieceType::Queen,
+        PieceType::Rook,
+        PieceType::Minister,
+        PieceType::Knight,
+    ];
+
+    /// Takes a self reference and returns the letter used for the type in the game notation.
+    #[inline]
+    pub fn as_char(&self) -> char {
+        match self {
+            PieceType::Rook => 'R',
+            PieceType::Minister => 'M',
+            PieceType::Queen => 'Q',
+            PieceType::Pawn => 'P',
+            PieceType::Knight => 'N',
+        }
+    }
+    /// Returns the [`PieceType`] corresponding to the letter used in the game notation.
+    #[inline]
+    pub fn from_char(letter: char) -> Option<PieceType> {
+        match letter {
+            'R' => Some(PieceType::Rook),
+            'M' => Some(PieceType::Minister),
+            'Q' => Some(PieceType::Queen),
+            'P' => Some(PieceType::Pawn),
+            'N' => Some(PieceType::Knight),
+            _ => None,
         }
     }
     /// Takes a self reference and returns a `usize` value that corresponds to the type.
@@ -163,6 +224,39 @@ impl Position {
 }
 /*-----------------------------------------------------------------------------------------------*/
 
+/*████Move████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Move {
+    /// To create a [`Move`] struct.
+    #[inline]
+    pub fn new(from: Position, to: Position, kind: MoveKind) -> Self {
+        Move {
+            from,
+            to,
+            kind,
+            promotion: None,
+        }
+    }
+    /// To get the same move with the moved pawn promoted to the given type.
+    #[inline]
+    pub fn with_promotion(mut self, piece_type: PieceType) -> Self {
+        self.promotion = Some(piece_type);
+        self
+    }
+    /// To create a [`MoveKind::FortDiceRoll`] move for the piece at the given position.
+    #[inline]
+    pub fn dice_roll(at: Position) -> Self {
+        Move::new(at, at, MoveKind::FortDiceRoll)
+    }
+    /// To create a [`MoveKind::Skip`] move.
+    #[inline]
+    pub fn skip() -> Self {
+        let origin = Position { x: 0_i32, y: 0_i32 };
+        Move::new(origin, origin, MoveKind::Skip)
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
 /*████Piece████*/
 /*-----------------------------------------------------------------------------------------------*/
 #[doc(hidden)]
@@ -185,17 +279,20 @@ impl Piece {
         })
     }
     /// Function used to initialize the [`Piece`] vector.
+    ///
+    /// The types of the pieces are taken from the armies of the [`RuleSet`].
     pub(crate) fn init_pieces(
         is_defender: bool,
         quadrant: Quadrant,
         quadrant_active: usize,
-    ) -> Result<Vec<Piece>, Error> {
+        rules: &RuleSet,
+    ) -> Result<Vec<Piece>, crate::Error> {
         let mut pieces: Vec<Piece> = Vec::new();
-        for ((pos1, pos2), piece_type) in position_from_quadrant(&quadrant, quadrant_active)
+        for ((pos1, pos2), piece_type) in position_from_quadrant(&quadrant, quadrant_active)?
             .into_iter()
-            .zip(piece_type(is_defender, quadrant_active))
+            .zip(piece_type(is_defender, quadrant_active, rules)?)
         {
-            pieces.push(Piece::from(pos1, pos2, PieceType::from_index(piece_type)?)?);
+            pieces.push(Piece::from(pos1, pos2, piece_type)?);
         }
         Ok(pieces)
     }
@@ -237,12 +334,13 @@ impl Piece {
     }
     /// To check if a [`Position`] value is inside the board.
     ///
-    /// This function is used to check if a particular [`Position`] is inside the board.
+    /// This function is used to check if a particular [`Position`] is inside one of the quadrants
+    /// of the [`BOARD_LAYOUT`].
     #[inline]
     pub(crate) fn in_board_range(x: i32, y: i32) -> Result<(), Error> {
-        match (x <= X_MAX && x >= X_MIN) && (y <= Y_MAX && y >= Y_MIN) {
+        match BOARD_LAYOUT.contains(x, y) {
             true => Ok(()),
-            false => return Err(Error::IllegalPosition(x, y)),
+            false => Err(Error::IllegalPosition(x, y)),
         }
     }
 }
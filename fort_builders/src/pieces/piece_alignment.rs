@@ -3,135 +3,76 @@
 //! Holds the chess piece alignments and their type for each player.
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
-use crate::board::Quadrant;
-use crate::pieces::ENEMY_COUNT;
+use crate::board::{Quadrant, BOARD_LAYOUT};
+use crate::decrement_if_positive;
+use crate::pieces::{PieceType, ENEMY_COUNT};
+use crate::ruleset::RuleSet;
+use crate::Error;
 
 /// Type to abstract the position tuple.
 type PositionVectori32 = Vec<(i32, i32)>;
-/// Type to abstract the type *u8* value.
-type PieceTypeVectoru8 = Vec<u8>;
+/// Type to abstract the piece types.
+type PieceTypeVector = Vec<PieceType>;
 
 /*████Functions██████████████████████████████████████████████████████████████████████████████████*/
 
-/// To return the defender position inside a board based on the number of players.
-fn defender_position(quadrant_active: usize) -> PositionVectori32 {
-    (1..quadrant_active)
-        .into_iter()
-        .flat_map(|index| match index {
-            1_usize => vec![
-                (-3_i32, -2_i32),
-                (-3_i32, -1_i32),
-                (-3_i32, 0_i32),
-                (-3_i32, 1_i32),
-                (-4_i32, -2_i32),
-                (-4_i32, -1_i32),
-                (-4_i32, 0_i32),
-                (-4_i32, 1_i32),
-            ],
-            2_usize => vec![
-                (-2_i32, 2_i32),
-                (-1_i32, 2_i32),
-                (0_i32, 2_i32),
-                (1_i32, 2_i32),
-                (-2_i32, 3_i32),
-                (-1_i32, 3_i32),
-                (0_i32, 3_i32),
-                (1_i32, 3_i32),
-            ],
-            3_usize => vec![
-                (2_i32, 1_i32),
-                (2_i32, 0_i32),
-                (2_i32, -1_i32),
-                (2_i32, -2_i32),
-                (3_i32, 1_i32),
-                (3_i32, 0_i32),
-                (3_i32, -1_i32),
-                (3_i32, -2_i32),
-            ],
-            _ => panic!("There can't be more than 4 players. index: {}.", index),
-        })
-        .collect::<PositionVectori32>()
-}
-
-/// Returns the non-defender player positions in [`Quadrant::Q1`].
-#[inline]
-fn enemy_position_q1() -> PositionVectori32 {
-    vec![
-        (-7_i32, -2_i32),
-        (-7_i32, -1_i32),
-        (-7_i32, 0_i32),
-        (-7_i32, 1_i32),
-        (-8_i32, -2_i32),
-        (-8_i32, -1_i32),
-        (-8_i32, 0_i32),
-        (-8_i32, 1_i32),
-    ]
-}
-
-/// Returns the non-defender player positions in [`Quadrant::Q2`].
-#[inline]
-fn enemy_position_q2() -> PositionVectori32 {
-    vec![
-        (-2_i32, 6_i32),
-        (-1_i32, 6_i32),
-        (0_i32, 6_i32),
-        (1_i32, 6_i32),
-        (-2_i32, 7_i32),
-        (-1_i32, 7_i32),
-        (0_i32, 7_i32),
-        (1_i32, 7_i32),
-    ]
-}
-
-/// Returns the non-defender player positions in [`Quadrant::Q3`].
-#[inline]
-fn enemy_position_q3() -> PositionVectori32 {
-    vec![
-        (6_i32, 1_i32),
-        (6_i32, 0_i32),
-        (6_i32, -1_i32),
-        (6_i32, -2_i32),
-        (7_i32, 1_i32),
-        (7_i32, 0_i32),
-        (7_i32, -1_i32),
-        (7_i32, -2_i32),
-    ]
+/// To return the defender position inside a board based on the number of players. The defender
+/// stands in front of each attacker, on the start squares of the [`BOARD_LAYOUT`].
+///
+/// Returns [`Error::InvalidQuadrantIndex`] if there are more players than the board can hold.
+fn defender_position(quadrant_active: usize) -> Result<PositionVectori32, Error> {
+    if quadrant_active > BOARD_LAYOUT.quadrants.len() + 1_usize {
+        return Err(Error::InvalidQuadrantIndex(quadrant_active));
+    }
+    Ok(BOARD_LAYOUT
+        .quadrants
+        .iter()
+        .take(decrement_if_positive(quadrant_active))
+        .flat_map(|layout| layout.start_squares(true).to_vec())
+        .collect::<PositionVectori32>())
 }
 
-/// Returns `u8` value vector that corresponds to the [`PieceType`] value for non-defender type.
-///
-/// [`PieceType`]: crate::pieces::PieceType
+/// Returns the non-defender player positions in the quadrant, from the start squares of the
+/// [`BOARD_LAYOUT`].
 #[inline]
-fn enemy_type() -> PieceTypeVectoru8 {
-    vec![3_u8, 3_u8, 3_u8, 3_u8, 4_u8, 3_u8, 3_u8, 4_u8]
+fn enemy_position(quadrant: &Quadrant) -> PositionVectori32 {
+    BOARD_LAYOUT
+        .quadrant(*quadrant)
+        .map(|layout| layout.start_squares(false).to_vec())
+        .unwrap_or_default()
 }
 
-/// Returns `u8` value vector that corresponds to the [`PieceType`] value for defender type.
+/// Returns the [`PieceType`] vector of the defender, with the army of the [`RuleSet`] placed in
+/// front of each attacker.
 ///
-/// [`PieceType`]: crate::pieces::PieceType
-fn defender_type(quadrant_active: usize) -> PieceTypeVectoru8 {
+/// Returns [`Error::InvalidQuadrantIndex`] if there are more players than the board can hold.
+fn defender_type(quadrant_active: usize, rules: &RuleSet) -> Result<PieceTypeVector, Error> {
     if quadrant_active > 4_usize {
-        panic!("There can't be more than 4 players. index: {quadrant_active}.")
+        return Err(Error::InvalidQuadrantIndex(quadrant_active));
     }
-    vec![4_u8, 1_u8, 2_u8, 0_u8, 3_u8, 3_u8, 3_u8, 3_u8]
-        .into_iter()
+    Ok(rules
+        .army(true)
+        .iter()
+        .copied()
         .cycle()
         .take(ENEMY_COUNT * quadrant_active)
-        .collect::<PieceTypeVectoru8>()
+        .collect::<PieceTypeVector>())
 }
 
 /*-----------------------------------------------------------------------------------------------*/
 /*████Public functions████*/
 /*-----------------------------------------------------------------------------------------------*/
 /// Returns the [`PieceType`] vector depending on wether the player is a a defender or not and what
-/// qudrant the player resides in.
-///
-/// [`PieceType`]: crate::pieces::PieceType
+/// qudrant the player resides in. The pieces are taken from the armies of the [`RuleSet`].
 #[inline]
-pub(crate) fn piece_type(is_defender: bool, quadrant_active: usize) -> PieceTypeVectoru8 {
+pub(crate) fn piece_type(
+    is_defender: bool,
+    quadrant_active: usize,
+    rules: &RuleSet,
+) -> Result<PieceTypeVector, Error> {
     match is_defender {
-        true => defender_type(quadrant_active),
-        false => enemy_type(),
+        true => defender_type(quadrant_active, rules),
+        false => Ok(rules.army(false).to_vec()),
     }
 }
 
@@ -142,12 +83,10 @@ pub(crate) fn piece_type(is_defender: bool, quadrant_active: usize) -> PieceType
 pub(crate) fn position_from_quadrant(
     quadrant: &Quadrant,
     quadrant_active: usize,
-) -> PositionVectori32 {
+) -> Result<PositionVectori32, Error> {
     match quadrant {
-        Quadrant::Q1 => enemy_position_q1(),
-        Quadrant::Q2 => enemy_position_q2(),
-        Quadrant::Q3 => enemy_position_q3(),
         Quadrant::NoQuad => defender_position(quadrant_active),
+        _ => Ok(enemy_position(quadrant)),
     }
 }
 /*-----------------------------------------------------------------------------------------------*/
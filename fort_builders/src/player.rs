@@ -7,14 +7,19 @@
 
 use crate::board::{check_in_opposite_defender, check_in_opposite_enemy, Quadrant};
 use crate::pieces;
-use crate::pieces::{Piece, Position};
+use crate::pieces::{Piece, PieceType, Position};
+use crate::ruleset::RuleSet;
 use crate::{RED, RST};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Maximum name length.
 pub const NAME_MAX_LEN: usize = 15_usize;
 /// Minimum name length.
 pub const NAME_MIN_LEN: usize = 3_usize;
+/// The characters a name cannot hold, as they separate the fields of the position strings and the
+/// game records.
+pub const NAME_RESERVED_CHARS: [char; 7_usize] = [':', '/', ',', '=', '"', '[', ']'];
 
 /// Player Error enum.
 #[derive(Error, Debug)]
@@ -27,6 +32,9 @@ pub enum Error {
         RST
     )]
     InvalidNameLength(String, usize),
+    /// If the name holds whitespace or a reserved character.
+    #[error("{} The name '{0}' cannot hold the character {1:?}. {}", RED, RST)]
+    InvalidNameCharacter(String, char),
     /// If the position referenced is not present in the pieces vector.
     #[error(
         "{} The given index of the piece {0} does not exist in a vec of length {1}. {}",
@@ -59,7 +67,7 @@ pub enum Error {
 /// Used to distinguish players from team to team.
 /// Each player must have a unique team.
 /// Maximum of only four players can play at a time.
-#[derive(Copy, Eq, PartialEq, Ord, PartialOrd, Clone, Debug)]
+#[derive(Copy, Eq, PartialEq, Ord, PartialOrd, Clone, Debug, Serialize, Deserialize)]
 pub enum Team {
     Red,    // 0
     Blue,   // 1
@@ -67,10 +75,22 @@ pub enum Team {
     Yellow, // 3
 }
 
+/// Who makes the moves of a [`Player`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum PlayerKind {
+    /// The moves are made by a person.
+    #[default]
+    Human,
+    /// The moves are picked by the [`ai`] module.
+    ///
+    /// [`ai`]: crate::ai
+    Computer,
+}
+
 /// [`Player`] struct used to handle player specific information.
 ///
 /// Contains data such as name, pieces held, team, etc.
-#[derive(Debug)]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Player {
     /// The name of the player.
     pub name: String,
@@ -84,12 +104,16 @@ pub struct Player {
     pub is_winner: bool,
     /// Current position of the player piece in the vec.
     pub chosen_piece_index: usize,
+    /// The quadrant the player started from.
+    pub quadrant: Quadrant,
+    /// Who makes the moves of the player. Saves from before the computer players load as humans.
+    #[serde(default)]
+    pub kind: PlayerKind,
 }
 
 /// A public trait to handle [`Player`] actions.
 pub trait PlayerAction {
     fn piece_index_from_xy_i32(&self, x: i32, y: i32) -> Result<usize, usize>;
-    fn piece_index_from_xy_f32(&self, x: f32, y: f32) -> Result<usize, usize>;
     fn kill_piece(&mut self, pos: usize) -> Result<Piece, Error>;
     fn update_piece(&mut self, x: i32, y: i32) -> Result<bool, Error>;
     fn in_opposite_side(&self) -> bool;
@@ -143,21 +167,50 @@ impl Team {
 impl Player {
     /// Used to initialize a new player based on the inputs.
     ///
-    /// Takes the name, team and is_defender boolean value to create a [`Player`] struct.
+    /// Takes the name, team and is_defender boolean value to create a [`Player`] struct. The
+    /// pieces are laid out with the armies of the [`RuleSet`].
     pub fn from(
         name: String,
         team: Team,
         is_defender: bool,
         quadrant_active: usize,
         quadrant: Quadrant,
+        rules: &RuleSet,
+    ) -> Result<Self, crate::Error> {
+        Ok(Player {
+            name: Player::validate_name(name)?,
+            pieces: Piece::init_pieces(is_defender, quadrant, quadrant_active, rules)?,
+            team,
+            is_defender,
+            is_winner: false,
+            chosen_piece_index: 0_usize,
+            quadrant,
+            kind: PlayerKind::Human,
+        }
+        .to_sorted())
+    }
+    /// To create a player holding the given pieces instead of the initial layout.
+    ///
+    /// Used to set up a game from a position in the middle of the game.
+    pub(crate) fn with_pieces(
+        name: String,
+        team: Team,
+        is_defender: bool,
+        quadrant: Quadrant,
+        pieces: Vec<Piece>,
     ) -> Result<Self, Error> {
+        if let Some(last) = pieces.len().checked_sub(1_usize) {
+            Piece::is_valid_index(last, is_defender)?;
+        }
         Ok(Player {
             name: Player::validate_name(name)?,
-            pieces: Piece::init_pieces(is_defender, quadrant, quadrant_active)?,
+            pieces,
             team,
             is_defender,
             is_winner: false,
             chosen_piece_index: 0_usize,
+            quadrant,
+            kind: PlayerKind::Human,
         }
         .to_sorted())
     }
@@ -170,6 +223,16 @@ impl Player {
     pub fn set_winner(&mut self) {
         self.is_winner = true;
     }
+    /// To set who makes the moves of the player.
+    #[inline]
+    pub fn set_kind(&mut self, kind: PlayerKind) {
+        self.kind = kind;
+    }
+    /// To check if the moves of the player are picked by the computer.
+    #[inline]
+    pub fn is_computer(&self) -> bool {
+        self.kind == PlayerKind::Computer
+    }
     /// For sorting the pieces.
     ///
     /// TimeSorts the pieces based on their position value.
@@ -195,11 +258,22 @@ impl Player {
     pub fn pieces_mut(&mut self) -> &mut Vec<Piece> {
         &mut self.pieces
     }
-    /// A simple function to check if the name length is too big or too small.
+    /// To check if the character can be a part of a name.
+    ///
+    /// Whitespace and the [`NAME_RESERVED_CHARS`] are not allowed.
+    #[inline]
+    pub fn is_name_char(ch: char) -> bool {
+        !ch.is_whitespace() && !ch.is_control() && !NAME_RESERVED_CHARS.contains(&ch)
+    }
+    /// A simple function to check if the name length is too big or too small, and that every
+    /// character of it can be a part of a name.
     ///
     /// returns false if the name length is invalid. The constraints are 2 < name_length < 15.
     #[inline]
-    fn validate_name(name: String) -> Result<String, Error> {
+    pub(crate) fn validate_name(name: String) -> Result<String, Error> {
+        if let Some(ch) = name.chars().find(|&ch| !Player::is_name_char(ch)) {
+            return Err(Error::InvalidNameCharacter(name, ch));
+        }
         let len = name.len();
         match len >= NAME_MIN_LEN && len < NAME_MAX_LEN {
             true => Ok(name),
@@ -211,12 +285,51 @@ impl Player {
     pub fn set_chosen_piece_index(&mut self, chosen_piece_index: usize) {
         self.chosen_piece_index = chosen_piece_index
     }
+    /// To put back a piece that was killed.
+    #[inline]
+    pub(crate) fn restore_piece(&mut self, piece: Piece) {
+        self.pieces.push(piece);
+        self.sort_pieces();
+    }
+    /// To check if the given piece is on the opposite side of the board.
+    ///
+    /// Depends on if you're a defender piece or an enemy piece.
+    #[inline]
+    pub(crate) fn piece_in_opposite_side(&self, piece: &Piece) -> bool {
+        self.position_in_opposite_side(&piece.position)
+    }
+    /// To check if the given position is on the opposite side of the board for the player.
+    #[inline]
+    pub(crate) fn position_in_opposite_side(&self, position: &Position) -> bool {
+        let (x, y) = (position.x, position.y);
+        match self.is_defender {
+            true => check_in_opposite_defender(x, y),
+            false => check_in_opposite_enemy(x, y),
+        }
+    }
+    /// To change the type of the piece at the given position, like when a pawn is promoted.
+    #[inline]
+    pub(crate) fn set_piece_type(&mut self, position: &Position, piece_type: PieceType) {
+        if let Ok(index) = self.piece_index_from_xy_i32(position.x, position.y) {
+            self.pieces[index].piece_type = piece_type;
+        }
+    }
     /// To get the chosen piece reference.
     #[inline]
     pub fn current_chosen_piece(&self) -> Result<&Piece, Error> {
         Piece::is_in_bounds(self.chosen_piece_index, self.pieces.len())?;
         Ok(&self.pieces[self.chosen_piece_index])
     }
+    /// To check if the index is inside the players vector bounds.
+    ///
+    /// takes a `usize` value and checks it with the length of the players `vec`.
+    #[inline]
+    pub(crate) fn is_in_bounds(index: usize, len: usize) -> Result<(), Error> {
+        match index < len {
+            true => Ok(()),
+            false => Err(Error::PlayerVectorIndexOutOfBounds(index, len)),
+        }
+    }
     /// To get the chosen piece mutable reference.
     #[inline]
     pub fn current_chosen_piece_mut(&mut self) -> Result<&mut Piece, Error> {
@@ -229,18 +342,6 @@ impl Player {
 /*████PlayerAction for Player████*/
 /*-----------------------------------------------------------------------------------------------*/
 impl PlayerAction for Player {
-    /// To get position of the piece that is clicked in relevance to the vec.
-    ///
-    /// Takes x and y coordinate position, as `f32`, of the clicked piece. Then checks the piece
-    /// which is being referenced and returns the position of the piece inside the [`pieces`]
-    /// vector. This can be used to update the piece position. Returns null if not piece exist at
-    /// that location.
-    ///
-    /// [`pieces`]: crate::pieces
-    #[inline]
-    fn piece_index_from_xy_f32(&self, x: f32, y: f32) -> Result<usize, usize> {
-        self.piece_index_from_xy_i32(x as i32, y as i32)
-    }
     /// To get position of the piece that is clicked in relevance to the vec.
     ///
     /// Takes x and y coordinate position, as `i32`, of the clicked piece. Then checks the piece
@@ -289,11 +390,7 @@ impl PlayerAction for Player {
     /// or an enemy piece.
     fn in_opposite_side(&self) -> bool {
         if let Ok(current_piece) = self.current_chosen_piece() {
-            let (x, y) = (current_piece.position.x, current_piece.position.y);
-            match self.is_defender {
-                true => return check_in_opposite_defender(x, y),
-                false => return check_in_opposite_enemy(x, y),
-            }
+            return self.piece_in_opposite_side(current_piece);
         };
         false
     }
@@ -311,6 +408,8 @@ impl Default for Player {
             is_defender: bool::default(),
             is_winner: bool::default(),
             chosen_piece_index: usize::default(),
+            quadrant: Quadrant::NoQuad,
+            kind: PlayerKind::default(),
         }
     }
 }
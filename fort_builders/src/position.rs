@@ -0,0 +1,336 @@
+//! # position module
+//!
+//! Holds the one line position string used to set up a [`Game`] from any position, like the
+//! middle of a game for rule tests and bug reports.
+//!
+//! ## Format
+//! ```text
+//! RD=alice:P1e1,P1e2/B1=bobby:N1a1,R1a2 R 2
+//! ```
+//! The string has three fields separated by a space:
+//! 1. The players in the order of their turns, separated by `/`. Each player is written as the
+//!    team letter (`R`, `B`, `G` or `Y`), the role (`D` for the defender or the quadrant number
+//!    `1`, `2` or `3`), an optional `=name` and the pieces after a `:`. Each piece is the piece
+//!    letter followed by the square as written in the [`notation`] module. The name defaults to
+//!    the team name.
+//!
+//!    A team can only have one player and there can only be one defender. The name cannot hold
+//!    whitespace or any of the [`NAME_RESERVED_CHARS`].
+//! 2. The letter of the team whose turn it is.
+//! 3. The number of players the game started with.
+//!
+//! [`notation`]: crate::notation
+//! [`NAME_RESERVED_CHARS`]: crate::player::NAME_RESERVED_CHARS
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    board::Quadrant,
+    game::Game,
+    notation::{parse_square, square},
+    pieces::{Piece, PieceType, Position},
+    player::{Player, Team},
+    RED, RST,
+};
+use thiserror::Error;
+
+/// The separator between the players.
+const PLAYER_SEPARATOR: char = '/';
+/// The separator between the pieces of a player.
+const PIECE_SEPARATOR: char = ',';
+/// The letter of the defender role.
+const DEFENDER: char = 'D';
+
+/// Position string error enum.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// If the position string does not have all of its fields.
+    #[error("{} The position string needs 3 fields, found {0}. {}", RED, RST)]
+    FieldCount(usize),
+    /// If a player cannot be read.
+    #[error("{} The player '{0}' is not valid. {}", RED, RST)]
+    InvalidPlayer(String),
+    /// If a piece cannot be read.
+    #[error("{} The piece '{0}' is not valid. {}", RED, RST)]
+    InvalidPiece(String),
+    /// If more than one piece is on the same square.
+    #[error("{} More than one piece is on the square '{0}'. {}", RED, RST)]
+    SquareTaken(String),
+    /// If more than one player is of the same team.
+    #[error("{} More than one player is of the team '{0}'. {}", RED, RST)]
+    DuplicateTeam(char),
+    /// If more than one player is a defender.
+    #[error("{} There can only be one defender, found {0}. {}", RED, RST)]
+    TooManyDefenders(usize),
+    /// If the team to play does not have a player.
+    #[error("{} The team '{0}' to play is not in the game. {}", RED, RST)]
+    InvalidTurn(String),
+    /// If the number of players the game started with is not valid.
+    #[error(
+        "{} The game cannot start with '{0}' players when {1} are still playing. {}",
+        RED,
+        RST
+    )]
+    InvalidQuadrantActive(String, usize),
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// To get the letter of the [`Team`].
+#[inline]
+fn team_char(team: Team) -> char {
+    match team {
+        Team::Red => 'R',
+        Team::Blue => 'B',
+        Team::Green => 'G',
+        Team::Yellow => 'Y',
+    }
+}
+
+/// To get the [`Team`] from its letter.
+#[inline]
+fn team_from_char(letter: char) -> Option<Team> {
+    match letter {
+        'R' => Some(Team::Red),
+        'B' => Some(Team::Blue),
+        'G' => Some(Team::Green),
+        'Y' => Some(Team::Yellow),
+        _ => None,
+    }
+}
+
+/// To get the letter of the role the player plays from its quadrant.
+#[inline]
+fn role_char(quadrant: Quadrant) -> char {
+    match quadrant {
+        Quadrant::Q1 => '1',
+        Quadrant::Q2 => '2',
+        Quadrant::Q3 => '3',
+        Quadrant::NoQuad => DEFENDER,
+    }
+}
+
+/// To get the quadrant of the player from the letter of its role.
+#[inline]
+fn quadrant_from_char(letter: char) -> Option<Quadrant> {
+    match letter {
+        '1' => Some(Quadrant::Q1),
+        '2' => Some(Quadrant::Q2),
+        '3' => Some(Quadrant::Q3),
+        DEFENDER => Some(Quadrant::NoQuad),
+        _ => None,
+    }
+}
+
+/// To read a single piece written as the piece letter and the square.
+fn parse_piece(piece: &str) -> Result<Piece, crate::Error> {
+    let invalid = || Error::InvalidPiece(piece.to_string());
+    let mut chars = piece.chars();
+    let piece_type = chars
+        .next()
+        .and_then(PieceType::from_char)
+        .ok_or_else(invalid)?;
+    let position = parse_square(chars.as_str())?;
+    Ok(Piece::from(position.x, position.y, piece_type)?)
+}
+
+/// To read a single player.
+fn parse_player(player: &str) -> Result<Player, crate::Error> {
+    let invalid = || Error::InvalidPlayer(player.to_string());
+    let (head, pieces) = player.split_once(':').ok_or_else(invalid)?;
+    let mut chars = head.chars();
+    let (team, quadrant) = match (
+        chars.next().and_then(team_from_char),
+        chars.next().and_then(quadrant_from_char),
+    ) {
+        (Some(team), Some(quadrant)) => (team, quadrant),
+        _ => return Err(invalid().into()),
+    };
+    let name = match chars.as_str() {
+        "" => Team::teamstr_from_team(team).to_string(),
+        rest => rest.strip_prefix('=').ok_or_else(invalid)?.to_string(),
+    };
+    let pieces = pieces
+        .split(PIECE_SEPARATOR)
+        .filter(|piece| !piece.is_empty())
+        .map(parse_piece)
+        .collect::<Result<Vec<Piece>, crate::Error>>()?;
+    Ok(Player::with_pieces(
+        name,
+        team,
+        quadrant == Quadrant::NoQuad,
+        quadrant,
+        pieces,
+    )?)
+}
+
+/*████Game████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Game {
+    /// To set up a game from a position string.
+    ///
+    /// The game starts with no history, so the moves played before the position cannot be
+    /// undone.
+    pub fn from_position_str(position: &str) -> Result<Game, crate::Error> {
+        let fields = position.split_whitespace().collect::<Vec<&str>>();
+        let (players, turn, quadrant_active) = match fields[..] {
+            [players, turn, quadrant_active] => (players, turn, quadrant_active),
+            _ => return Err(Error::FieldCount(fields.len()).into()),
+        };
+        let players = players
+            .split(PLAYER_SEPARATOR)
+            .map(parse_player)
+            .collect::<Result<Vec<Player>, crate::Error>>()?;
+        let mut teams = players
+            .iter()
+            .map(|player| player.team)
+            .collect::<Vec<Team>>();
+        teams.sort();
+        if let Some(taken) = teams
+            .windows(2_usize)
+            .find(|pair| pair[0_usize] == pair[1_usize])
+        {
+            return Err(Error::DuplicateTeam(team_char(taken[0_usize])).into());
+        }
+        let defenders = players.iter().filter(|player| player.is_defender).count();
+        if defenders > 1_usize {
+            return Err(Error::TooManyDefenders(defenders).into());
+        }
+        let mut squares = players
+            .iter()
+            .flat_map(|player| player.pieces.iter().map(|piece| piece.position))
+            .collect::<Vec<Position>>();
+        squares.sort();
+        if let Some(taken) = squares
+            .windows(2_usize)
+            .find(|pair| pair[0_usize] == pair[1_usize])
+        {
+            return Err(Error::SquareTaken(square(&taken[0_usize])?).into());
+        }
+        let turn = match turn.chars().collect::<Vec<char>>()[..] {
+            [letter] => team_from_char(letter)
+                .and_then(|team| players.iter().position(|player| player.team == team)),
+            _ => None,
+        }
+        .ok_or_else(|| Error::InvalidTurn(turn.to_string()))?;
+        let count = players.len();
+        let quadrant_active = quadrant_active
+            .parse::<usize>()
+            .ok()
+            .filter(|active| (count.max(2_usize)..=4_usize).contains(active))
+            .ok_or_else(|| Error::InvalidQuadrantActive(quadrant_active.to_string(), count))?;
+        let mut game = Game::init(players);
+        game.turn = turn;
+        game.quadrant_active = quadrant_active;
+        Ok(game)
+    }
+    /// To write the position of the game as a position string.
+    ///
+    /// Fails if the name of a player cannot be written, like a name loaded from a snapshot that
+    /// holds a separator.
+    pub fn to_position_str(&self) -> Result<String, crate::Error> {
+        let mut players = Vec::with_capacity(self.players.len());
+        for player in self.players.iter() {
+            Player::validate_name(player.name.clone())?;
+            let pieces = player
+                .pieces
+                .iter()
+                .map(|piece| {
+                    Ok(format!(
+                        "{}{}",
+                        piece.piece_type.as_char(),
+                        square(&piece.position)?
+                    ))
+                })
+                .collect::<Result<Vec<String>, crate::Error>>()?;
+            players.push(format!(
+                "{}{}={}:{}",
+                team_char(player.team),
+                role_char(player.quadrant),
+                player.name,
+                pieces.join(&PIECE_SEPARATOR.to_string())
+            ));
+        }
+        Ok(format!(
+            "{} {} {}",
+            players.join(&PLAYER_SEPARATOR.to_string()),
+            team_char(self.current_player().team),
+            self.quadrant_active
+        ))
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dice::SeededDice,
+        fixtures::{game, KNIGHT_AND_PAWN},
+        ruleset::RuleSet,
+    };
+
+    /// To check that the position reads and writes back to the same string.
+    fn assert_round_trip(position: &str) {
+        assert_eq!(game(position).to_position_str().unwrap(), position);
+    }
+
+    #[test]
+    fn test_position_round_trip() {
+        assert_round_trip(KNIGHT_AND_PAWN);
+        assert_round_trip("BD=bobby:N1f4,Q2a1/G1=carol:P1e2/R2=alice:R2d6 R 4");
+        assert_round_trip("YD=dave:R3a1/R1=alice: R 2");
+    }
+
+    #[test]
+    fn test_new_match_position_round_trip() {
+        let names = ["alice", "bobby", "carol", "dave"]
+            .map(String::from)
+            .to_vec();
+        let game =
+            Game::new_match(names, SeededDice::from_seed(7_u64), RuleSet::default()).unwrap();
+        let position = game.to_position_str().unwrap();
+        assert_round_trip(&position);
+        assert_eq!(Game::from_position_str(&position).unwrap().turn, game.turn);
+    }
+
+    #[test]
+    fn test_missing_name_defaults_to_team() {
+        let written = game("RD:N1f4/B1:P1e2 B 2").to_position_str().unwrap();
+        assert_eq!(written, "RD=Red:N1f4/B1=Blue:P1e2 B 2");
+    }
+
+    #[test]
+    fn test_name_with_separator_is_rejected() {
+        for name in ["al/ce", "al:ce", "al,ce", "al=ce", "al ce", "al\"ce"] {
+            let names = vec![String::from(name), String::from("bobby")];
+            let dice = SeededDice::from_seed(7_u64);
+            assert!(
+                Game::new_match(names, dice, RuleSet::default()).is_err(),
+                "{name:?}"
+            );
+            let mut game = game(KNIGHT_AND_PAWN);
+            game.players[0_usize].name = String::from(name);
+            assert!(game.to_position_str().is_err(), "{name:?}");
+        }
+    }
+
+    #[test]
+    fn test_duplicate_team_is_rejected() {
+        let err = Game::from_position_str("RD=alice:N1f4/R1=bobby:P1e2 R 2").unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::PositionModuleError(Error::DuplicateTeam('R'))
+        ));
+    }
+
+    #[test]
+    fn test_second_defender_is_rejected() {
+        let err = Game::from_position_str("RD=alice:N1f4/BD=bobby:P1e2 B 2").unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::PositionModuleError(Error::TooManyDefenders(2_usize))
+        ));
+    }
+}
@@ -0,0 +1,234 @@
+//! # rules module
+//!
+//! Holds the move generation logic for each of the chess pieces. The legal moves of any piece can
+//! be queried from the [`Game`] through the [`GameRules`] trait without any graphics involved.
+//! Each legal move is a [`Move`] value which can be passed to [`GameAction::apply_move`].
+//!
+//! [`GameAction::apply_move`]: crate::game::GameAction::apply_move
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+//----------//
+mod knight;
+mod minister;
+mod pawn;
+mod queen;
+mod rook;
+//----------//
+
+use crate::{
+    board::{
+        position_in_board_bounds, position_in_q1_bounds, position_in_q2_bounds,
+        position_in_q3_bounds, Quadrant,
+    },
+    game::{Game, GameAction},
+    pieces::{Move, MoveKind, Piece, PieceType, Position},
+    player::Player,
+    Error,
+};
+use knight::analyse_knight_paths;
+use minister::analyse_minister_paths;
+use pawn::analyse_pawn_paths;
+use queen::analyse_queen_paths;
+use rook::analyse_rook_paths;
+use std::fmt;
+
+/// The step size just holds 1 as the value. Not necessary but I do a lot of unnecessary stuff.
+const STEP: i32 = 1_i32;
+
+/// The reasons for which a move can be rejected.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum IllegalMoveReason {
+    /// The game has already ended.
+    GameOver,
+    /// There is no piece at the starting position.
+    NoPieceAtOrigin,
+    /// The piece at the starting position belongs to another player.
+    NotPlayersPiece,
+    /// The piece cannot reach the given position.
+    Unreachable,
+    /// The piece at the starting position stands outside of the board or of every quadrant.
+    IllegalPosition,
+}
+
+/// To query the legal moves of the [`Game`].
+pub trait GameRules {
+    fn legal_moves(&self, player: usize, piece_index: usize) -> Result<Vec<Move>, Error>;
+    fn all_legal_moves(&self) -> Vec<Move>;
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// To detect possible paths of a piece belonging to the given player.
+#[inline]
+pub(crate) fn possible_piece_paths(
+    piece: &Piece,
+    player: &Player,
+    game: &Game,
+) -> Result<Vec<Position>, Error> {
+    (match piece.piece_type {
+        PieceType::Rook => analyse_rook_paths,
+        PieceType::Pawn => analyse_pawn_paths,
+        PieceType::Knight => analyse_knight_paths,
+        PieceType::Minister => analyse_minister_paths,
+        PieceType::Queen => analyse_queen_paths,
+    })(piece.position.x, piece.position.y, player, game)
+}
+
+/// To get the bounds that a knight or a minister at the given position may move inside.
+///
+/// The pieces are kept inside their quadrant, unless the [`RuleSet`] lets the player cross the
+/// quadrants, in which case the whole board is open. Returns [`Error::PositionNotInQuadrant`] if
+/// the position is not inside any quadrant.
+///
+/// [`RuleSet`]: crate::ruleset::RuleSet
+#[inline]
+pub(crate) fn quadrant_bounds(
+    x: i32,
+    y: i32,
+    player: &Player,
+    game: &Game,
+) -> Result<fn(i32, i32) -> bool, Error> {
+    if game.rules.crosses_quadrants(player) {
+        return Ok(position_in_board_bounds);
+    }
+    match Quadrant::from_xy(x, y)? {
+        Quadrant::Q1 => Ok(position_in_q1_bounds),
+        Quadrant::Q2 => Ok(position_in_q2_bounds),
+        Quadrant::Q3 => Ok(position_in_q3_bounds),
+        Quadrant::NoQuad => Err(Error::PositionNotInQuadrant(x, y)),
+    }
+}
+
+/// To get the moves of a pawn reaching the given position.
+///
+/// If the [`RuleSet`] promotes the pawns and the position is on the opposite side, the pawn has to
+/// be promoted, so there is a move for each of the [`PieceType::PROMOTIONS`] instead.
+///
+/// [`RuleSet`]: crate::ruleset::RuleSet
+#[inline]
+fn promoted_moves(step: Move, piece: &Piece, player: &Player, game: &Game) -> Vec<Move> {
+    match game.rules.pawn_promotion
+        && piece.piece_type == PieceType::Pawn
+        && player.position_in_opposite_side(&step.to)
+    {
+        true => PieceType::PROMOTIONS
+            .into_iter()
+            .map(|piece_type| step.with_promotion(piece_type))
+            .collect::<Vec<Move>>(),
+        false => vec![step],
+    }
+}
+
+/// To get the moves of a piece belonging to the given player.
+///
+/// A position holding another player's piece is a [`MoveKind::Capture`]. If the piece is on the
+/// opposite side and the player rolls the dice there, a [`MoveKind::FortDiceRoll`] is added as
+/// well. A pawn that is promoted on the position gets a move for each type it can become.
+fn piece_moves(piece: &Piece, player: &Player, game: &Game) -> Result<Vec<Move>, Error> {
+    let mut moves = possible_piece_paths(piece, player, game)?
+        .into_iter()
+        .flat_map(|to| {
+            promoted_moves(
+                Move::new(
+                    piece.position,
+                    to,
+                    match game.check_piece_in_pos(to.x, to.y) {
+                        true => MoveKind::Capture,
+                        false => MoveKind::Step,
+                    },
+                ),
+                piece,
+                player,
+                game,
+            )
+        })
+        .collect::<Vec<Move>>();
+    if player.piece_in_opposite_side(piece) && game.rules.rolls_at_opposite_side(player) {
+        moves.push(Move::dice_roll(piece.position));
+    }
+    Ok(moves)
+}
+
+/*████IllegalMoveReason████*/
+/*-----------------------------------------------------------------------------------------------*/
+#[doc(hidden)]
+impl fmt::Display for IllegalMoveReason {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                IllegalMoveReason::GameOver => "the game has already ended",
+                IllegalMoveReason::NoPieceAtOrigin => "there is no piece at the starting position",
+                IllegalMoveReason::NotPlayersPiece => "the piece belongs to another player",
+                IllegalMoveReason::Unreachable => "the piece cannot reach the given position",
+                IllegalMoveReason::IllegalPosition => "the piece stands outside of the board",
+            }
+        )
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████GameRules for Game████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl GameRules for Game {
+    /// To get the moves that a piece can legally make.
+    ///
+    /// Takes the index of the player inside the players vector and the index of the piece inside
+    /// that player's pieces vector. Returns error if either of the index is out of bounds, or if the
+    /// paths of the piece cannot be analysed.
+    fn legal_moves(&self, player: usize, piece_index: usize) -> Result<Vec<Move>, Error> {
+        Player::is_in_bounds(player, self.players.len())?;
+        let player = &self.players[player];
+        Piece::is_in_bounds(piece_index, player.pieces.len())?;
+        piece_moves(&player.pieces[piece_index], player, self)
+    }
+    /// To get the legal moves of every piece of the player whose turn it is.
+    ///
+    /// A [`MoveKind::Skip`] is always a part of the legal moves. A piece whose paths cannot be
+    /// analysed, like one outside of every quadrant, has no moves.
+    fn all_legal_moves(&self) -> Vec<Move> {
+        let player = self.current_player();
+        player
+            .pieces
+            .iter()
+            .flat_map(|piece| piece_moves(piece, player, self).unwrap_or_default())
+            .chain(std::iter::once(Move::skip()))
+            .collect::<Vec<Move>>()
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{at, game, KNIGHT_AND_PAWN},
+        ruleset::Variant,
+    };
+
+    #[test]
+    fn test_pawn_reaching_opposite_side_has_move_per_promotion() {
+        let game = game(KNIGHT_AND_PAWN).with_rules(Variant::Promotion.rules());
+        let promotions = game
+            .all_legal_moves()
+            .into_iter()
+            .filter(|step| step.to == at("1f2"))
+            .map(|step| step.promotion)
+            .collect::<Vec<Option<PieceType>>>();
+        let expected = PieceType::PROMOTIONS.map(Some).to_vec();
+        assert_eq!(promotions, expected);
+    }
+
+    #[test]
+    fn test_pawn_is_not_promoted_in_classic_rules() {
+        let game = game(KNIGHT_AND_PAWN);
+        assert!(game
+            .all_legal_moves()
+            .into_iter()
+            .all(|step| step.promotion.is_none()));
+    }
+}
@@ -0,0 +1,55 @@
+//! knight module.
+//!
+//! Handles the knight's possible paths analysis.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    game::Game,
+    pieces::Position,
+    player::{Player, PlayerAction},
+    rules::quadrant_bounds,
+    Error,
+};
+
+/// The `L` shaped offsets that a knight can jump to from its position.
+const KNIGHT_OFFSETS: [(i32, i32); 8_usize] = [
+    (1_i32, 2_i32),
+    (2_i32, 1_i32),
+    (2_i32, -1_i32),
+    (1_i32, -2_i32),
+    (-1_i32, -2_i32),
+    (-2_i32, -1_i32),
+    (-2_i32, 1_i32),
+    (-1_i32, 2_i32),
+];
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// To analyse paths for the knight from a given position.
+///
+/// when drawn on a graph paper, I noticed that the positions seem to be along a circumference of a
+/// circle of 2 steps/units radius with the knight in the middle. Skipping the middle "cross" path
+/// of that circle leaves the usual chess `L` jumps which are held in [`KNIGHT_OFFSETS`].
+///
+/// These offsets are added to the knight's position to get the resultant positions. Positions
+/// outside the knight's quadrant and positions with the pieces of the same team are skipped. A
+/// defender that may cross the quadrants only skips the positions outside the board.
+pub(crate) fn analyse_knight_paths(
+    x: i32,
+    y: i32,
+    player: &Player,
+    game: &Game,
+) -> Result<Vec<Position>, Error> {
+    let in_quadrant_bounds = quadrant_bounds(x, y, player, game)?;
+    Ok(KNIGHT_OFFSETS
+        .into_iter()
+        .map(|(offset_x, offset_y)| Position {
+            x: x + offset_x,
+            y: y + offset_y,
+        })
+        .filter(|step| {
+            in_quadrant_bounds(step.x, step.y)
+                && player.piece_index_from_xy_i32(step.x, step.y).is_err()
+        })
+        .collect::<Vec<Position>>())
+}
@@ -3,12 +3,13 @@
 //! Handles the minister paths analysis.
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
-use crate::listener::possible_paths::PositionVectorf32;
-use fort_builders::{
-    board::{position_in_q1_bounds, position_in_q2_bounds, position_in_q3_bounds, Quadrant},
+use crate::{
+    board::position_in_board_bounds,
     game::{Game, GameAction},
-    player::PlayerAction,
-    BREADTH,
+    pieces::Position,
+    player::{Player, PlayerAction},
+    rules::quadrant_bounds,
+    Error, BREADTH,
 };
 
 /*████Functions██████████████████████████████████████████████████████████████████████████████████*/
@@ -20,65 +21,69 @@ use fort_builders::{
 /// for at a given time inside a quadrant bound.
 ///
 /// There remaining positions are then filtered out based on the qudrant that the piece lies
-/// inside.
-pub(crate) fn analyse_minister_paths(x: f32, y: f32, game: &Game) -> PositionVectorf32 {
-    let mut _possiblepaths: PositionVectorf32 = Vec::new();
+/// inside, or the whole board for a defender that may cross the quadrants.
+pub(crate) fn analyse_minister_paths(
+    x: i32,
+    y: i32,
+    player: &Player,
+    game: &Game,
+) -> Result<Vec<Position>, Error> {
+    let mut _possiblepaths: Vec<Position> = Vec::new();
     minister_step_analysis(
         x,
         y,
-        |x, y, breadth| (x + breadth as f32, y + breadth as f32),
+        |x, y, breadth| (x + breadth, y + breadth),
+        player,
         game,
         &mut _possiblepaths,
     );
     minister_step_analysis(
         x,
         y,
-        |x, y, breadth| (x + breadth as f32, y - breadth as f32),
+        |x, y, breadth| (x + breadth, y - breadth),
+        player,
         game,
         &mut _possiblepaths,
     );
     minister_step_analysis(
         x,
         y,
-        |x, y, breadth| (x - breadth as f32, y + breadth as f32),
+        |x, y, breadth| (x - breadth, y + breadth),
+        player,
         game,
         &mut _possiblepaths,
     );
     minister_step_analysis(
         x,
         y,
-        |x, y, breadth| (x - breadth as f32, y - breadth as f32),
+        |x, y, breadth| (x - breadth, y - breadth),
+        player,
         game,
         &mut _possiblepaths,
     );
-    _possiblepaths
+    let in_quadrant_bounds = quadrant_bounds(x, y, player, game)?;
+    Ok(_possiblepaths
         .into_iter()
-        .filter(|(_x, _y)| {
-            (match Quadrant::from_xy(x, y).unwrap() {
-                Quadrant::Q1 => position_in_q1_bounds,
-                Quadrant::Q2 => position_in_q2_bounds,
-                Quadrant::Q3 => position_in_q3_bounds,
-                _ => panic!("Cannot analyse minister paths for pieces in \'NoQuad\' Quadrant."),
-            })(*_x, *_y)
-        })
-        .collect::<PositionVectorf32>()
+        .filter(|step| in_quadrant_bounds(step.x, step.y))
+        .collect::<Vec<Position>>())
 }
 
 fn minister_step_analysis<F>(
-    x: f32,
-    y: f32,
+    x: i32,
+    y: i32,
     step: F,
+    player: &Player,
     game: &Game,
-    _possiblepaths: &mut PositionVectorf32,
+    _possiblepaths: &mut Vec<Position>,
 ) where
-    F: Fn(f32, f32, i32) -> (f32, f32),
+    F: Fn(i32, i32, i32) -> (i32, i32),
 {
     for breadth in 1..(BREADTH * 2_i32) {
         let (x, y) = step(x, y, breadth);
-        if game.current_player().piece_index_from_xy_f32(x, y).is_ok() {
+        if !position_in_board_bounds(x, y) || player.piece_index_from_xy_i32(x, y).is_ok() {
             break;
         }
-        _possiblepaths.push((x, y));
+        _possiblepaths.push(Position { x, y });
         if game.check_piece_in_pos(x, y) {
             break;
         }
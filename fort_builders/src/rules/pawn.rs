@@ -0,0 +1,96 @@
+//! pawn module.
+//!
+//! Handles the pawn's possible paths analysis.
+//!
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::rules::STEP;
+use crate::{
+    board::{position_in_board_bounds, BOARD_LAYOUT},
+    game::{Game, GameAction},
+    pieces::Position,
+    player::{Player, PlayerAction},
+    Error,
+};
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// Function to analyse possible pawn paths from a given position.
+///
+/// The pawn steps along the forward direction of the quadrant it is in, as given by the
+/// [`BOARD_LAYOUT`].
+///
+/// ### If the peice is:
+/// #### Defender:
+///
+/// The pawn steps away from the fort, towards the back rank of the attacker. In the classic board
+/// that is along the `negative x-axis` in [`Quadrant::Q1`], the `y-axis` in [`Quadrant::Q2`] and
+/// the `positive x-axis` in [`Quadrant::Q3`].
+///
+/// #### Not defender:
+///
+/// The pawn steps towards the fort, the other way around.
+///
+/// [`Quadrant::Q1`]: crate::board::Quadrant::Q1
+/// [`Quadrant::Q2`]: crate::board::Quadrant::Q2
+/// [`Quadrant::Q3`]: crate::board::Quadrant::Q3
+pub(crate) fn analyse_pawn_paths(
+    x: i32,
+    y: i32,
+    player: &Player,
+    game: &Game,
+) -> Result<Vec<Position>, Error> {
+    let mut _possiblepaths: Vec<Position> = Vec::new();
+    let (forward_x, forward_y) = BOARD_LAYOUT
+        .quadrant_at(x, y)
+        .ok_or(Error::PositionNotInQuadrant(x, y))?
+        .forward;
+    let step = match player.is_defender {
+        true => (-forward_x * STEP, -forward_y * STEP),
+        false => (forward_x * STEP, forward_y * STEP),
+    };
+    iter_pawn_path_step_analysis(x, y, step, player, game, &mut _possiblepaths);
+    Ok(_possiblepaths)
+}
+
+/// To handle the pawn step analysis and find the killable pieces.
+///
+/// The pawn possible killable pieces detection. After taking a step front, check for diagonal
+/// pieces that can be killed, on each side across the step.
+fn iter_pawn_path_step_analysis(
+    mut _x: i32,
+    mut _y: i32,
+    (step_x, step_y): (i32, i32),
+    player: &Player,
+    game: &Game,
+    _possiblepaths: &mut Vec<Position>,
+) {
+    (_x, _y) = (_x + step_x, _y + step_y);
+    let (side_x, side_y) = (step_y.abs(), step_x.abs());
+    pawn_possible_path_if_piece_at_pos(_x + side_x, _y + side_y, player, game, _possiblepaths);
+    pawn_possible_path_if_piece_at_pos(_x - side_x, _y - side_y, player, game, _possiblepaths);
+    if game.check_piece_in_pos(_x, _y) || !position_in_board_bounds(_x, _y) {
+        return;
+    }
+    _possiblepaths.push(Position { x: _x, y: _y });
+}
+
+/// Used to detect pawn's killable pieces.
+///
+/// check if there is piece in the given position or not. If true then register that piece.
+/// OR check if there is a piece from the current palyer's pieces and return if true.
+///
+/// This is an arbitrary function written to reduce code clutter. As in there is nothingin
+/// particular that this function does specific to pawn pieces.
+fn pawn_possible_path_if_piece_at_pos(
+    x: i32,
+    y: i32,
+    player: &Player,
+    game: &Game,
+    _possiblepaths: &mut Vec<Position>,
+) {
+    if !game.check_piece_in_pos(x, y) || player.piece_index_from_xy_i32(x, y).is_ok() {
+        return;
+    }
+    _possiblepaths.push(Position { x, y });
+}
@@ -4,10 +4,8 @@
 //!
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
-use crate::listener::possible_paths::{
-    analyse_minister_paths, analyse_rook_paths, PositionVectorf32,
-};
-use fort_builders::game::Game;
+use crate::rules::{analyse_minister_paths, analyse_rook_paths};
+use crate::{game::Game, pieces::Position, player::Player, Error};
 
 /*████Functions██████████████████████████████████████████████████████████████████████████████████*/
 
@@ -15,9 +13,14 @@ use fort_builders::game::Game;
 ///
 /// Queen is essentially just the combination of rook paths and minister paths. Hence we append
 /// those two.
-pub(crate) fn analyse_queen_paths(x: f32, y: f32, game: &Game) -> PositionVectorf32 {
-    let mut _possiblepaths: PositionVectorf32 = Vec::new();
-    _possiblepaths.append(&mut analyse_rook_paths(x, y, game));
-    _possiblepaths.append(&mut analyse_minister_paths(x, y, game));
-    _possiblepaths
+pub(crate) fn analyse_queen_paths(
+    x: i32,
+    y: i32,
+    player: &Player,
+    game: &Game,
+) -> Result<Vec<Position>, Error> {
+    let mut _possiblepaths: Vec<Position> = Vec::new();
+    _possiblepaths.append(&mut analyse_rook_paths(x, y, player, game)?);
+    _possiblepaths.append(&mut analyse_minister_paths(x, y, player, game)?);
+    Ok(_possiblepaths)
 }
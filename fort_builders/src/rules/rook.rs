@@ -4,11 +4,13 @@
 //!
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
-use crate::listener::possible_paths::{PositionVectorf32, STEP};
-use fort_builders::{
+use crate::rules::STEP;
+use crate::{
     board::position_in_board_bounds,
     game::{Game, GameAction},
-    player::PlayerAction,
+    pieces::Position,
+    player::{Player, PlayerAction},
+    Error,
 };
 
 /*████Functions██████████████████████████████████████████████████████████████████████████████████*/
@@ -16,13 +18,46 @@ use fort_builders::{
 /// Takes the given position and draws the possible rook path from there.
 ///
 /// Checks for steps along `+ve X-axis`, `-ve X-axis`, `+ve Y-axis` and `-ve Y-axis`.
-pub(crate) fn analyse_rook_paths(x: f32, y: f32, game: &Game) -> PositionVectorf32 {
-    let mut _possiblepaths: PositionVectorf32 = Vec::new();
-    iter_rook_path_step_analysis(x, y, |_x, _y| *_x += STEP, game, &mut _possiblepaths);
-    iter_rook_path_step_analysis(x, y, |_x, _y| *_x -= STEP, game, &mut _possiblepaths);
-    iter_rook_path_step_analysis(x, y, |_x, _y| *_y += STEP, game, &mut _possiblepaths);
-    iter_rook_path_step_analysis(x, y, |_x, _y| *_y -= STEP, game, &mut _possiblepaths);
-    _possiblepaths
+pub(crate) fn analyse_rook_paths(
+    x: i32,
+    y: i32,
+    player: &Player,
+    game: &Game,
+) -> Result<Vec<Position>, Error> {
+    let mut _possiblepaths: Vec<Position> = Vec::new();
+    iter_rook_path_step_analysis(
+        x,
+        y,
+        |_x, _y| *_x += STEP,
+        player,
+        game,
+        &mut _possiblepaths,
+    );
+    iter_rook_path_step_analysis(
+        x,
+        y,
+        |_x, _y| *_x -= STEP,
+        player,
+        game,
+        &mut _possiblepaths,
+    );
+    iter_rook_path_step_analysis(
+        x,
+        y,
+        |_x, _y| *_y += STEP,
+        player,
+        game,
+        &mut _possiblepaths,
+    );
+    iter_rook_path_step_analysis(
+        x,
+        y,
+        |_x, _y| *_y -= STEP,
+        player,
+        game,
+        &mut _possiblepaths,
+    );
+    Ok(_possiblepaths)
 }
 
 /// To analyse the rook path in a given direction using the step function.
@@ -30,25 +65,21 @@ pub(crate) fn analyse_rook_paths(x: f32, y: f32, game: &Game) -> PositionVectorf
 /// Step and move in a given direction until the position is either in the current player pieces,
 /// out of bound of the board or another player piece present. Else push to possible paths.
 fn iter_rook_path_step_analysis<F>(
-    mut _x: f32,
-    mut _y: f32,
+    mut _x: i32,
+    mut _y: i32,
     step: F,
+    player: &Player,
     game: &Game,
-    _possiblepaths: &mut PositionVectorf32,
+    _possiblepaths: &mut Vec<Position>,
 ) where
-    F: Fn(&mut f32, &mut f32),
+    F: Fn(&mut i32, &mut i32),
 {
     loop {
         step(&mut _x, &mut _y);
-        if !position_in_board_bounds(_x, _y)
-            || game
-                .current_player()
-                .piece_index_from_xy_f32(_x, _y)
-                .is_ok()
-        {
+        if !position_in_board_bounds(_x, _y) || player.piece_index_from_xy_i32(_x, _y).is_ok() {
             break;
         }
-        _possiblepaths.push((_x, _y));
+        _possiblepaths.push(Position { x: _x, y: _y });
         if game.check_piece_in_pos(_x, _y) {
             break;
         }
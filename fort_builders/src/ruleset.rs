@@ -0,0 +1,298 @@
+//! # ruleset module
+//!
+//! Holds the [`RuleSet`] that a [`Game`] is built with. The rules that used to be fixed, like the
+//! roll that wins at the fort and the starting armies, are kept here so that the house variants
+//! can be played without changing the code.
+//!
+//! The house variants are named by the [`Variant`] enum and can be picked on the start screen, by
+//! the simulator and in the headers of a game record.
+//!
+//! [`Game`]: crate::game::Game
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    dice::DICE_FACES,
+    pieces::{PieceType, ENEMY_COUNT},
+    player::Player,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// The dice roll value that lets a piece on the opposite side win the game in the classic rules.
+pub const CLASSIC_WINNING_ROLL: usize = 5_usize;
+
+/// The army of an attacker in the classic rules, in the order of the positions of a quadrant. The
+/// first four pieces stand on the row closer to the fort.
+const CLASSIC_ATTACKER_ARMY: Army = [
+    PieceType::Pawn,
+    PieceType::Pawn,
+    PieceType::Pawn,
+    PieceType::Pawn,
+    PieceType::Knight,
+    PieceType::Pawn,
+    PieceType::Pawn,
+    PieceType::Knight,
+];
+/// The army the defender places in front of each attacker in the classic rules. The first four
+/// pieces stand on the row closer to the fort.
+const CLASSIC_DEFENDER_ARMY: Army = [
+    PieceType::Knight,
+    PieceType::Minister,
+    PieceType::Queen,
+    PieceType::Rook,
+    PieceType::Pawn,
+    PieceType::Pawn,
+    PieceType::Pawn,
+    PieceType::Pawn,
+];
+/// The army of an attacker in the [`Variant::Cavalry`] rules, with a row of knights at the back.
+const CAVALRY_ATTACKER_ARMY: Army = [
+    PieceType::Pawn,
+    PieceType::Pawn,
+    PieceType::Pawn,
+    PieceType::Pawn,
+    PieceType::Knight,
+    PieceType::Knight,
+    PieceType::Knight,
+    PieceType::Knight,
+];
+
+/// Type to abstract the pieces placed in the positions of a quadrant.
+pub type Army = [PieceType; ENEMY_COUNT];
+
+/// The rules a [`Game`] is played with.
+///
+/// [`Game`]: crate::game::Game
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct RuleSet {
+    /// The dice roll value that lets a piece on the opposite side win the game.
+    pub winning_roll: usize,
+    /// If the attackers have to roll the dice at the fort. Otherwise an attacker wins as soon as
+    /// one of its pieces reaches the fort.
+    pub attackers_roll: bool,
+    /// If the pawns are promoted once they reach the opposite side. An attacker pawn is promoted
+    /// on the fort wall and a defender pawn on the back rank of an attacker.
+    pub pawn_promotion: bool,
+    /// If the knights, the ministers and the diagonals of the queens of the defender may leave the
+    /// quadrant they are in.
+    pub defender_crosses_quadrants: bool,
+    /// The pieces each attacker starts with.
+    pub attacker_army: Army,
+    /// The pieces the defender starts with in front of each attacker.
+    pub defender_army: Army,
+}
+
+/// The house variants of the rules.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum Variant {
+    /// The rules as they have always been played.
+    #[default]
+    Classic,
+    /// The attackers win as soon as they reach the fort, without rolling the dice.
+    Storm,
+    /// The pieces of the defender may ride out of the quadrant they are in.
+    Sortie,
+    /// The attackers start with a row of knights at the back.
+    Cavalry,
+    /// The pawns are promoted to a piece of their choice once they reach the opposite side.
+    Promotion,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████RuleSet████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Default for RuleSet {
+    /// The classic rules.
+    #[inline]
+    fn default() -> Self {
+        RuleSet {
+            winning_roll: CLASSIC_WINNING_ROLL,
+            attackers_roll: true,
+            pawn_promotion: false,
+            defender_crosses_quadrants: false,
+            attacker_army: CLASSIC_ATTACKER_ARMY,
+            defender_army: CLASSIC_DEFENDER_ARMY,
+        }
+    }
+}
+
+impl RuleSet {
+    /// To get a dice roll value that does not win the game.
+    #[inline]
+    pub fn missed_roll(&self) -> usize {
+        (self.winning_roll + 1_usize) % DICE_FACES
+    }
+    /// To check if the player rolls the dice once its piece reaches the opposite side.
+    #[inline]
+    pub fn rolls_at_opposite_side(&self, player: &Player) -> bool {
+        player.is_defender || self.attackers_roll
+    }
+    /// To check if the pieces of the player may leave the quadrant they are in.
+    #[inline]
+    pub fn crosses_quadrants(&self, player: &Player) -> bool {
+        player.is_defender && self.defender_crosses_quadrants
+    }
+    /// To get the pieces the player starts with, for each quadrant it starts in.
+    #[inline]
+    pub(crate) fn army(&self, is_defender: bool) -> &Army {
+        match is_defender {
+            true => &self.defender_army,
+            false => &self.attacker_army,
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Variant████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Variant {
+    /// All the variants in the order they are switched through.
+    pub const ALL: [Variant; 5_usize] = [
+        Variant::Classic,
+        Variant::Storm,
+        Variant::Sortie,
+        Variant::Cavalry,
+        Variant::Promotion,
+    ];
+
+    /// To get the rules of the variant.
+    #[inline]
+    pub fn rules(&self) -> RuleSet {
+        let classic = RuleSet::default();
+        match self {
+            Variant::Classic => classic,
+            Variant::Storm => RuleSet {
+                attackers_roll: false,
+                ..classic
+            },
+            Variant::Sortie => RuleSet {
+                defender_crosses_quadrants: true,
+                ..classic
+            },
+            Variant::Cavalry => RuleSet {
+                attacker_army: CAVALRY_ATTACKER_ARMY,
+                ..classic
+            },
+            Variant::Promotion => RuleSet {
+                pawn_promotion: true,
+                ..classic
+            },
+        }
+    }
+    /// To get the variant played with the given rules, if any.
+    #[inline]
+    pub fn from_rules(rules: &RuleSet) -> Option<Variant> {
+        Variant::ALL
+            .into_iter()
+            .find(|variant| variant.rules() == *rules)
+    }
+    /// To get the variant that comes after this one, going back to the first after the last.
+    #[inline]
+    pub fn next(&self) -> Variant {
+        let index = Variant::ALL
+            .iter()
+            .position(|variant| variant == self)
+            .unwrap_or_default();
+        Variant::ALL[(index + 1_usize) % Variant::ALL.len()]
+    }
+}
+
+impl FromStr for Variant {
+    type Err = String;
+
+    /// To read the variant from its name, ignoring the case.
+    fn from_str(variant: &str) -> Result<Self, Self::Err> {
+        Variant::ALL
+            .into_iter()
+            .find(|known| known.to_string().eq_ignore_ascii_case(variant))
+            .ok_or_else(|| format!("Unknown variant '{variant}'."))
+    }
+}
+
+#[doc(hidden)]
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Variant::Classic => "Classic",
+                Variant::Storm => "Storm",
+                Variant::Sortie => "Sortie",
+                Variant::Cavalry => "Cavalry",
+                Variant::Promotion => "Promotion",
+            }
+        )
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{at, game, KNIGHT_AND_PAWN},
+        game::GameAction,
+        pieces::{Move, MoveKind, PieceType},
+        rules::GameRules,
+    };
+
+    #[test]
+    fn test_storm_wins_on_reaching_opposite_side() {
+        let mut storm = game(KNIGHT_AND_PAWN).with_rules(Variant::Storm.rules());
+        let step = Move::new(at("1e2"), at("1f2"), MoveKind::Step);
+        assert!(storm.apply_move(step).unwrap().winner);
+        let mut classic = game(KNIGHT_AND_PAWN);
+        assert!(!classic.apply_move(step).unwrap().winner);
+    }
+
+    #[test]
+    fn test_sortie_lets_defender_leave_quadrant() {
+        let position = "RD=alice:N1f4/B1=bobby:P1e2 R 2";
+        let classic = game(position);
+        let sortie = game(position).with_rules(Variant::Sortie.rules());
+        assert!(sortie.all_legal_moves().len() > classic.all_legal_moves().len());
+    }
+
+    #[test]
+    fn test_cavalry_attackers_have_more_knights() {
+        let knights = |variant: Variant| {
+            variant
+                .rules()
+                .army(false)
+                .iter()
+                .filter(|&&piece_type| piece_type == PieceType::Knight)
+                .count()
+        };
+        assert!(knights(Variant::Cavalry) > knights(Variant::Classic));
+    }
+
+    #[test]
+    fn test_variant_found_from_its_rules() {
+        for variant in Variant::ALL {
+            assert_eq!(Variant::from_rules(&variant.rules()), Some(variant));
+        }
+    }
+
+    #[test]
+    fn test_variant_name_reads_back() {
+        for variant in Variant::ALL {
+            assert_eq!(variant.to_string().parse::<Variant>(), Ok(variant));
+            let shouted = variant.to_string().to_uppercase();
+            assert_eq!(shouted.parse::<Variant>(), Ok(variant));
+        }
+        assert!("Chaos".parse::<Variant>().is_err());
+    }
+
+    #[test]
+    fn test_next_variant_goes_through_all() {
+        let mut variant = Variant::Classic;
+        for expected in Variant::ALL.into_iter().cycle().skip(1_usize).take(5_usize) {
+            variant = variant.next();
+            assert_eq!(variant, expected);
+        }
+    }
+}
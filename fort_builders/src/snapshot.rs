@@ -0,0 +1,230 @@
+//! # snapshot module
+//!
+//! To save a [`Game`] to a file and load it back. The snapshot is a versioned `JSON` document
+//! holding the players, their pieces and the turn. The move history is not saved.
+//!
+//! A network game is sent as a [`GameSnapshot`] instead, which holds the same data as plain values
+//! that can be copied and compared, and is written in the encoding of the connection.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    dice::{self, DiceState, SeededDice},
+    game::Game,
+    player::Player,
+    ruleset::RuleSet,
+    RED, RST,
+};
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+use thiserror::Error;
+
+/// The version of the snapshot format written by this library.
+pub const SNAPSHOT_VERSION: u32 = 1_u32;
+
+/// Snapshot error enum.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// If the snapshot was written by an unknown version of the format.
+    #[error(
+        "{} The snapshot version {0} is not supported. Supported version: {1}. {}",
+        RED,
+        RST
+    )]
+    UnsupportedVersion(u32, u32),
+    /// If the snapshot could not be read or written as `JSON`.
+    #[error("{} The snapshot is not valid JSON: {0} {}", RED, RST)]
+    InvalidJson(#[from] serde_json::Error),
+    /// If the snapshot does not describe a game that can be played.
+    #[error("{} The snapshot does not hold a valid game: {0}. {}", RED, RST)]
+    InvalidGame(&'static str),
+}
+
+/// The versioned snapshot document.
+///
+/// Generic over the game so that it can be written from a reference and read into an owned
+/// [`Game`].
+#[derive(Serialize, Deserialize)]
+struct Snapshot<G> {
+    /// The version of the snapshot format.
+    version: u32,
+    /// The game data.
+    game: G,
+}
+
+/// The game as it is sent to the clients of a network game.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    /// The version of the snapshot format.
+    pub version: u32,
+    /// The players along with their pieces.
+    pub players: Vec<Player>,
+    /// The index of the player whose turn it is.
+    pub turn: usize,
+    /// If the game is still being played.
+    pub play: bool,
+    /// The number of players the game started with.
+    pub quadrant_active: usize,
+    /// The rules the game is played with.
+    pub rules: RuleSet,
+    /// The state of the dice, if it can be restored.
+    pub dice: Option<DiceState>,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// To write the game into a snapshot string.
+#[inline]
+pub fn to_string(game: &Game) -> Result<String, Error> {
+    Ok(serde_json::to_string_pretty(&Snapshot {
+        version: SNAPSHOT_VERSION,
+        game,
+    })?)
+}
+
+/// To read a game from a snapshot string.
+///
+/// Checks the version of the snapshot and the turn of the game. The loaded game is set to be
+/// drawn with no piece picked.
+pub fn from_str(snapshot: &str) -> Result<Game, Error> {
+    let snapshot: Snapshot<Game> = serde_json::from_str(snapshot)?;
+    if snapshot.version != SNAPSHOT_VERSION {
+        return Err(Error::UnsupportedVersion(
+            snapshot.version,
+            SNAPSHOT_VERSION,
+        ));
+    }
+    checked(snapshot.game)
+}
+
+/// To check the turn of the loaded game, and set it to be drawn with no piece picked.
+fn checked(mut game: Game) -> Result<Game, Error> {
+    if game.players.is_empty() {
+        return Err(Error::InvalidGame("there are no players"));
+    }
+    if game.turn >= game.players.len() {
+        return Err(Error::InvalidGame("the turn does not belong to any player"));
+    }
+    game.set_update_true().set_picked_false();
+    Ok(game)
+}
+
+/// To save the game to the file at the given path.
+///
+/// The parent directories are created if they do not exist.
+pub fn save(game: &Game, path: &Path) -> Result<(), crate::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, to_string(game)?)?;
+    Ok(())
+}
+
+/// To load a game from the file at the given path.
+#[inline]
+pub fn load(path: &Path) -> Result<Game, crate::Error> {
+    Ok(from_str(&fs::read_to_string(path)?)?)
+}
+
+/*████GameSnapshot████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl From<&Game> for GameSnapshot {
+    /// To take the snapshot of the game. The move history is not a part of it.
+    fn from(game: &Game) -> Self {
+        GameSnapshot {
+            version: SNAPSHOT_VERSION,
+            players: game.players.clone(),
+            turn: game.turn,
+            play: game.play,
+            quadrant_active: game.quadrant_active,
+            rules: game.rules,
+            dice: game.dice.state(),
+        }
+    }
+}
+
+impl TryFrom<GameSnapshot> for Game {
+    type Error = Error;
+
+    /// To restore the game from the snapshot, checked the same way as a snapshot string.
+    ///
+    /// A dice without a state is replaced by a dice with a random seed.
+    fn try_from(snapshot: GameSnapshot) -> Result<Self, Self::Error> {
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(Error::UnsupportedVersion(
+                snapshot.version,
+                SNAPSHOT_VERSION,
+            ));
+        }
+        let mut game = Game::init(snapshot.players);
+        game.turn = snapshot.turn;
+        game.play = snapshot.play;
+        game.quadrant_active = snapshot.quadrant_active;
+        game.rules = snapshot.rules;
+        game.dice = match snapshot.dice {
+            Some(state) => Box::new(SeededDice::from_state(state)),
+            None => dice::default_dice(),
+        };
+        checked(game)
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameAction;
+
+    #[test]
+    fn test_loaded_game_keeps_dice_sequence() {
+        let names = vec![String::from("alice"), String::from("bobby")];
+        let mut game =
+            Game::new_match(names, SeededDice::from_seed(7_u64), RuleSet::default()).unwrap();
+        (0_usize..3_usize).for_each(|_| {
+            game.roll_dice();
+        });
+        let mut loaded = from_str(&to_string(&game).unwrap()).unwrap();
+        assert_eq!(loaded.dice.state(), game.dice.state());
+        let rolls = (0_usize..16_usize)
+            .map(|_| game.roll_dice())
+            .collect::<Vec<usize>>();
+        let loaded_rolls = (0_usize..16_usize)
+            .map(|_| loaded.roll_dice())
+            .collect::<Vec<usize>>();
+        assert_eq!(loaded_rolls, rolls);
+    }
+
+    #[test]
+    fn test_game_snapshot_restores_same_game() {
+        let names = vec![String::from("alice"), String::from("bobby")];
+        let mut game =
+            Game::new_match(names, SeededDice::from_seed(7_u64), RuleSet::default()).unwrap();
+        game.roll_dice();
+        game.next_player();
+        let snapshot = GameSnapshot::from(&game);
+        let restored = Game::try_from(snapshot.clone()).unwrap();
+        assert_eq!(GameSnapshot::from(&restored), snapshot);
+        assert_eq!(to_string(&restored).unwrap(), to_string(&game).unwrap());
+        let turn = game.players.len();
+        assert!(matches!(
+            Game::try_from(GameSnapshot { turn, ..snapshot }),
+            Err(Error::InvalidGame(_))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_version_is_rejected() {
+        let names = vec![String::from("alice"), String::from("bobby")];
+        let game =
+            Game::new_match(names, SeededDice::from_seed(7_u64), RuleSet::default()).unwrap();
+        let snapshot =
+            to_string(&game)
+                .unwrap()
+                .replacen("\"version\": 1", "\"version\": 99", 1_usize);
+        assert!(matches!(
+            from_str(&snapshot),
+            Err(Error::UnsupportedVersion(99_u32, SNAPSHOT_VERSION))
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+//! args module.
+//!
+//! Reads the command line arguments of the runner. Only the standard library is used so that the
+//! runner stays a small binary to call from scripts.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use fort_builders::ruleset::Variant;
+use std::{path::PathBuf, str::FromStr};
+
+/// The usage line printed on an invalid argument.
+pub(crate) const USAGE: &str = "fort_chess-cli [--players <count>] [--seed <seed>] \
+[--moves <file>] [--position <position>] [--perft <depth>] [--variant <variant>] \
+[--verify <record>] [name]...";
+/// The number of players when neither the names nor the count are given.
+const DEFAULT_COUNT: usize = 2_usize;
+/// The most players a game can have.
+const MAX_COUNT: usize = 4_usize;
+
+/// The arguments of the runner.
+#[derive(Debug, Default)]
+pub(crate) struct Args {
+    /// The names of the players in the order of their teams.
+    pub(crate) names: Vec<String>,
+    /// The number of players. Defaults to the number of names.
+    pub(crate) count: Option<usize>,
+    /// The seed of the dice. A random seed is used if not given.
+    pub(crate) seed: Option<u64>,
+    /// The notation file to read the moves from. The moves are read from the standard input if
+    /// not given.
+    pub(crate) moves: Option<PathBuf>,
+    /// The position string to start the game from instead of the starting position.
+    pub(crate) position: Option<String>,
+    /// The depth to count the move tree to with perft, instead of playing the moves.
+    pub(crate) perft: Option<u32>,
+    /// The variant of the rules to play with.
+    pub(crate) variant: Variant,
+    /// The game record to replay and check the dice proofs of, instead of playing.
+    pub(crate) verify: Option<PathBuf>,
+    /// If the usage should be printed instead of playing.
+    pub(crate) help: bool,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Args████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Args {
+    /// To read the arguments, without the name of the binary.
+    pub(crate) fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => parsed.help = true,
+                "-n" | "--players" => parsed.count = Some(Args::value(&arg, args.next())?),
+                "-s" | "--seed" => parsed.seed = Some(Args::value(&arg, args.next())?),
+                "-m" | "--moves" => parsed.moves = Some(Args::value(&arg, args.next())?),
+                "-p" | "--position" => parsed.position = Some(Args::value(&arg, args.next())?),
+                "--perft" => parsed.perft = Some(Args::value(&arg, args.next())?),
+                "-v" | "--variant" => parsed.variant = Args::value(&arg, args.next())?,
+                "--verify" => parsed.verify = Some(Args::value(&arg, args.next())?),
+                flag if flag.starts_with('-') => return Err(format!("Unknown option '{flag}'.")),
+                _ => parsed.names.push(arg),
+            }
+        }
+        Ok(parsed)
+    }
+    /// To read the value given after an option.
+    #[inline]
+    fn value<T: FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+        let value = value.ok_or_else(|| format!("The option '{option}' needs a value."))?;
+        value
+            .parse::<T>()
+            .map_err(|_| format!("The value '{value}' of '{option}' is not valid."))
+    }
+    /// To get the names of the players.
+    ///
+    /// The missing names up to the player count are filled with `player1`, `player2` and so on.
+    pub(crate) fn player_names(&self) -> Result<Vec<String>, String> {
+        let count = self
+            .count
+            .unwrap_or_else(|| self.names.len().max(DEFAULT_COUNT));
+        if !(DEFAULT_COUNT..=MAX_COUNT).contains(&count) {
+            return Err(format!(
+                "The game needs {DEFAULT_COUNT} to {MAX_COUNT} players, found {count}."
+            ));
+        }
+        if self.names.len() > count {
+            return Err(format!(
+                "Found {} names for {count} players.",
+                self.names.len()
+            ));
+        }
+        let mut names = self.names.clone();
+        names.extend((names.len()..count).map(|index| format!("player{}", index + 1_usize)));
+        Ok(names)
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// To read the arguments from the words.
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_options_and_names_are_read() {
+        let args = parse(&["-n", "3", "--seed", "42", "-v", "storm", "alice", "bobby"]).unwrap();
+        assert_eq!(args.count, Some(3_usize));
+        assert_eq!(args.seed, Some(42_u64));
+        assert_eq!(args.variant, Variant::Storm);
+        assert_eq!(
+            args.player_names().unwrap(),
+            vec!["alice", "bobby", "player3"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_options_are_refused() {
+        assert!(parse(&["--colour", "red"]).is_err());
+        assert!(parse(&["--seed"]).is_err());
+        assert!(parse(&["--perft", "deep"]).is_err());
+    }
+
+    #[test]
+    fn test_player_count_is_checked() {
+        assert!(parse(&["-n", "5"]).unwrap().player_names().is_err());
+        assert!(parse(&["-n", "2", "alice", "bobby", "carol"])
+            .unwrap()
+            .player_names()
+            .is_err());
+        assert_eq!(parse(&[]).unwrap().player_names().unwrap().len(), 2_usize);
+    }
+}
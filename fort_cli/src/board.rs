@@ -0,0 +1,69 @@
+//! board module.
+//!
+//! Writes the board as plain text so it can be read in a log or compared between runs.
+//!
+//! Each square is three characters wide. A piece is written as the lower case team letter followed
+//! by the piece letter, like `rP` for a red pawn. An empty square is written as `.` and the fort in
+//! the middle of the board as `##`.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use fort_builders::{
+    board::{position_in_board_bounds, BOARD_LAYOUT},
+    game::Game,
+    pieces::Position,
+    player::Team,
+};
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// To get the lower case letter of the team.
+#[inline]
+fn team_char(team: Team) -> char {
+    match team {
+        Team::Red => 'r',
+        Team::Blue => 'b',
+        Team::Green => 'g',
+        Team::Yellow => 'y',
+    }
+}
+
+/// To check if the position is inside the fort in the middle of the board.
+#[inline]
+fn in_fort(x: i32, y: i32) -> bool {
+    BOARD_LAYOUT.fort.contains(x, y)
+}
+
+/// To write a single square of the board.
+fn square_text(game: &Game, position: Position) -> String {
+    let (x, y) = (position.x, position.y);
+    if !position_in_board_bounds(x, y) {
+        return match in_fort(x, y) {
+            true => String::from("## "),
+            false => String::from("   "),
+        };
+    }
+    game.players
+        .iter()
+        .find_map(|player| {
+            player
+                .pieces
+                .iter()
+                .find(|piece| piece.position == position)
+                .map(|piece| format!("{}{} ", team_char(player.team), piece.piece_type.as_char()))
+        })
+        .unwrap_or_else(|| String::from(".  "))
+}
+
+/// To write the whole board, top row first.
+pub(crate) fn board_text(game: &Game) -> String {
+    let mut text = String::new();
+    let bounds = BOARD_LAYOUT.bounds();
+    for y in (bounds.y_min..=bounds.y_max).rev() {
+        let row = (bounds.x_min..=bounds.x_max)
+            .map(|x| square_text(game, Position { x, y }))
+            .collect::<String>();
+        text.push_str(row.trim_end());
+        text.push('\n');
+    }
+    text
+}
@@ -0,0 +1,296 @@
+//! A command line runner for fort chess to play scripted games and to pipe engines against each
+//! other without the game window. For instructions to play please read the "README.md" file:
+//! [README](../../../README.md).
+//!
+//! main module.
+//! Creates the game from the arguments, plays the moves read from a notation file or the standard
+//! input and prints the board and the result.
+//!
+//! ## Usage:
+//! ```text
+//! fort_chess-cli [--players <count>] [--seed <seed>] [--moves <file>] [--position <position>]
+//!                [--perft <depth>] [--variant <variant>] [--verify <record>] [name]...
+//! ```
+//! The moves are written in the fort chess notation and separated by whitespace. The header lines
+//! of a game record are skipped. Every move played is printed back on its own line with the value
+//! of its dice roll, so a roll written as `Q2b1@` shows what was rolled. The seed is printed to the
+//! standard error so that the same game can be played again.
+//!
+//! A game can start from a position string instead of the starting position. With `--perft` the
+//! moves are not played. The leaf nodes of the move tree are counted to the depth instead and
+//! printed for each move of the player whose turn it is, followed by the total.
+//!
+//! The game is played with the classic rules unless one of the house variants (`classic`, `storm`,
+//! `sortie`, `cavalry` or `promotion`) is given. A position string is played with the rules of the
+//! variant as well.
+//!
+//! With `--verify` a whole game record, like one written at the end of a network game, is replayed
+//! instead. Every dice roll with a `Proof` header is checked against its seeds, and a game won by a
+//! dice roll at the fort is refused if that roll has no proof. This only shows that the proofs are
+//! consistent with themselves, as the seeds of a record could have been made up after the game.
+//!
+//! ## Exit status:
+//! | Status  | Meaning                                  |
+//! |---------|------------------------------------------|
+//! | 0       | The game has no winner.                  |
+//! | 1       | A move could not be read or played.      |
+//! | 2       | The arguments are not valid.             |
+//! | 10 - 13 | The red, blue, green or yellow team won. |
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+/*------------*/
+mod args;
+mod board;
+/*------------*/
+
+use args::{Args, USAGE};
+use fort_builders::{
+    dice::SeededDice,
+    exit,
+    game::{Game, GameAction},
+    notation::{self, GameRecord, RecordedMove},
+    perft::divide,
+    player::{PlayerAction, Team},
+    Error, RED, RST,
+};
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+};
+
+/// The exit status when a move could not be read or played.
+const ERROR_STATUS: i32 = 1_i32;
+/// The exit status when the arguments are not valid.
+const USAGE_STATUS: i32 = 2_i32;
+/// The exit status of the first team to win. The team index is added to it.
+const WINNER_STATUS: i32 = 10_i32;
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Moves████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To play a single move written in the notation for the current player.
+///
+/// Returns the move as played, with the value of its dice roll.
+fn play_token(game: &mut Game, token: &str) -> Result<RecordedMove, Error> {
+    let recorded = token.parse::<RecordedMove>()?;
+    if let Some(piece_type) = recorded.piece_type {
+        let from = recorded.step.from;
+        let player = game.current_player();
+        match player.piece_index_from_xy_i32(from.x, from.y) {
+            Ok(index) if player.pieces[index].piece_type == piece_type => {}
+            _ => return Err(notation::Error::PieceMismatch(token.to_string()).into()),
+        }
+    }
+    let outcome = game.replay_move(recorded.step, recorded.roll)?;
+    let _dead = game.hunt();
+    Ok(RecordedMove {
+        roll: outcome.roll,
+        ..recorded
+    })
+}
+
+/// To play the moves from the input until it runs out or the game is over.
+///
+/// The input is read a line at a time so that an engine can answer each move as it is printed.
+fn play(game: &mut Game, input: impl BufRead, out: &mut impl Write) -> Result<(), Error> {
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.starts_with('[') {
+            continue;
+        }
+        for token in line.split_whitespace() {
+            if !game.play {
+                break;
+            }
+            let played = play_token(game, token)?;
+            writeln!(out, "{played}")?;
+            out.flush()?;
+        }
+        if !game.play {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// To play the moves from the notation file, or from the standard input if there is no file.
+fn play_input(game: &mut Game, args: &Args) -> Result<(), Error> {
+    let mut out = io::stdout().lock();
+    match args.moves.as_ref() {
+        Some(path) => play(game, BufReader::new(File::open(path)?), &mut out),
+        None => play(game, io::stdin().lock(), &mut out),
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Perft████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To print the perft count under each move of the player whose turn it is, and the total.
+fn print_perft(game: &mut Game, depth: u32) -> Result<(), Error> {
+    let mut total = 0_u64;
+    for (step, nodes) in divide(game, depth)? {
+        let player = game.current_player();
+        let recorded = RecordedMove {
+            piece_type: player
+                .piece_index_from_xy_i32(step.from.x, step.from.y)
+                .ok()
+                .map(|index| player.pieces[index].piece_type),
+            step,
+            roll: None,
+        };
+        println!("{recorded}: {nodes}");
+        total += nodes;
+    }
+    println!();
+    println!("Total: {total}");
+    Ok(())
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Verify████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To replay the game record in the file and check the proofs of its dice rolls.
+///
+/// Returns the replayed game.
+fn verify_record(path: &Path) -> Result<Game, Error> {
+    let record = fs::read_to_string(path)?.parse::<GameRecord>()?;
+    let game = record.verify()?;
+    println!("{} dice rolls proven.", record.proofs.len());
+    Ok(game)
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Result████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To print the board, the position and the result of the game.
+fn print_result(game: &Game) -> Result<(), Error> {
+    println!();
+    print!("{}", board::board_text(game));
+    println!();
+    println!("{}", game.to_position_str()?);
+    match game.players.iter().find(|player| player.is_winner) {
+        Some(player) => println!(
+            "{} ({}) wins.",
+            player.name,
+            Team::teamstr_from_team(player.team)
+        ),
+        None => println!("No winner."),
+    }
+    Ok(())
+}
+
+/// To close the game and get the exit status for its winner.
+#[inline]
+fn exit_status(game: Game) -> i32 {
+    match exit(game) {
+        Ok(Some(player)) => WINNER_STATUS + player.team as i32,
+        Ok(None) => 0_i32,
+        Err(err) => {
+            eprintln!("{err}");
+            ERROR_STATUS
+        }
+    }
+}
+
+/// To print the error with the usage line and exit.
+fn usage_error(err: impl std::fmt::Display) -> ! {
+    eprintln!("{err}");
+    eprintln!("{RED}USAGE:{RST} {USAGE}");
+    std::process::exit(USAGE_STATUS);
+}
+
+/// Main entry function.
+fn main() {
+    let args = Args::parse(std::env::args().skip(1_usize)).unwrap_or_else(|err| usage_error(err));
+    if args.help {
+        println!("{USAGE}");
+        return;
+    }
+    if let Some(path) = args.verify.as_ref() {
+        let game = verify_record(path).unwrap_or_else(|err| {
+            eprintln!("{err}");
+            std::process::exit(ERROR_STATUS);
+        });
+        if let Err(err) = print_result(&game) {
+            eprintln!("{err}");
+        }
+        std::process::exit(exit_status(game));
+    }
+    let names = args.player_names().unwrap_or_else(|err| usage_error(err));
+    let dice = match args.seed {
+        Some(seed) => SeededDice::from_seed(seed),
+        None => SeededDice::from_entropy(),
+    };
+    eprintln!("Seed: {}", dice.seed());
+    let mut game = match args.position.as_ref() {
+        Some(position) => Game::from_position_str(position)
+            .map(|game| game.with_dice(dice).with_rules(args.variant.rules())),
+        None => Game::new_match(names, dice, args.variant.rules()),
+    }
+    .unwrap_or_else(|err| usage_error(err));
+    if let Some(depth) = args.perft {
+        if let Err(err) = print_perft(&mut game, depth) {
+            eprintln!("{err}");
+            std::process::exit(ERROR_STATUS);
+        }
+        return;
+    }
+    let played = play_input(&mut game, &args);
+    if let Err(err) = print_result(&game) {
+        eprintln!("{err}");
+    }
+    if let Err(err) = played {
+        eprintln!("{err}");
+        std::process::exit(ERROR_STATUS);
+    }
+    std::process::exit(exit_status(game));
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The red defender has a knight on the edge of the first quadrant and the blue attacker a pawn
+    /// one step away from it. Blue plays next.
+    const KNIGHT_AND_PAWN: &str = "RD=alice:N1f4/B1=bobby:P1e2 B 2";
+    /// The moves that bring the blue pawn to the fort and win it with the roll.
+    const SCRIPT: &str = "[Event \"test\"]\nP1e2-1f2 N1f4-1d3\nP1f2@5 N1d3-1f4\n";
+
+    /// To play the script from the position and get the moves printed back.
+    fn replay(script: &str) -> (Game, Result<(), Error>, String) {
+        let mut game = Game::from_position_str(KNIGHT_AND_PAWN).unwrap();
+        let mut out = Vec::new();
+        let played = play(&mut game, script.as_bytes(), &mut out);
+        (game, played, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_script_is_played_until_game_ends() {
+        let (game, played, out) = replay(SCRIPT);
+        played.unwrap();
+        assert_eq!(out, "P1e2-1f2\nN1f4-1d3\nP1f2@5\n");
+        assert!(!game.play);
+    }
+
+    #[test]
+    fn test_script_stops_at_illegal_move() {
+        let (game, played, out) = replay("P1e2-1f2 N1f4-1a1 N1f4-1d3");
+        assert!(played.is_err());
+        assert_eq!(out, "P1e2-1f2\n");
+        assert!(game.play);
+    }
+
+    #[test]
+    fn test_exit_status_follows_winner() {
+        let (game, _, _) = replay(SCRIPT);
+        assert_eq!(exit_status(game), WINNER_STATUS + Team::Blue as i32);
+        let (game, _, _) = replay("P1e2-1f2");
+        assert_eq!(exit_status(game), 0_i32);
+    }
+}
@@ -0,0 +1,122 @@
+//! # difficulty module
+//!
+//! Holds the levels the engine can be played at. Each level is a pair of [`SearchLimits`], so a
+//! harder level looks further ahead and is given more time to do so.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    eval::Weights,
+    search::{search, SearchLimits, SearchResult},
+};
+use fort_builders::{game::Game, Error};
+use std::{fmt, time::Duration};
+
+/// The level the engine plays at.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum Difficulty {
+    /// Looks at its own move only.
+    Easy,
+    /// Looks at its own move and the reply of the next player.
+    #[default]
+    Medium,
+    /// Looks ahead for a full round of a four player game.
+    Hard,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Difficulty████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Difficulty {
+    /// To get the limits of the search for the level.
+    #[inline]
+    pub fn limits(&self) -> SearchLimits {
+        match self {
+            Difficulty::Easy => SearchLimits {
+                depth: 1_u32,
+                time: Duration::from_millis(100_u64),
+            },
+            Difficulty::Medium => SearchLimits {
+                depth: 2_u32,
+                time: Duration::from_millis(500_u64),
+            },
+            Difficulty::Hard => SearchLimits {
+                depth: 4_u32,
+                time: Duration::from_millis(2_000_u64),
+            },
+        }
+    }
+    /// To get the level after this one, going back to [`Difficulty::Easy`] after the hardest.
+    #[inline]
+    pub fn next(&self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Medium,
+            Difficulty::Medium => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+    /// To search the board for the best move at the level with the default [`Weights`].
+    ///
+    /// The board is searched on directly, so it should be a copy made with [`search_board`].
+    ///
+    /// [`search_board`]: crate::search::search_board
+    #[inline]
+    pub fn search(&self, board: Game) -> Result<SearchResult, Error> {
+        search(board, self.limits(), &Weights::default())
+    }
+}
+
+impl fmt::Display for Difficulty {
+    #[doc(hidden)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Difficulty::Easy => "Easy",
+                Difficulty::Medium => "Medium",
+                Difficulty::Hard => "Hard",
+            }
+        )
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::search_board;
+
+    #[test]
+    fn test_limits_of_each_level() {
+        let limits = |difficulty: Difficulty| {
+            let limits = difficulty.limits();
+            (limits.depth, limits.time.as_millis())
+        };
+        assert_eq!(limits(Difficulty::Easy), (1_u32, 100_u128));
+        assert_eq!(limits(Difficulty::Medium), (2_u32, 500_u128));
+        assert_eq!(limits(Difficulty::Hard), (4_u32, 2_000_u128));
+    }
+
+    #[test]
+    fn test_search_stays_within_level_depth() {
+        let game = Game::from_position_str("RD=alice:Q1a1/B1=bobby:P1e2 B 2").unwrap();
+        let result = Difficulty::Easy
+            .search(search_board(&game).unwrap())
+            .unwrap();
+        assert_eq!(result.depth, 1_u32);
+        let result = Difficulty::Medium
+            .search(search_board(&game).unwrap())
+            .unwrap();
+        assert!((1_u32..=2_u32).contains(&result.depth));
+    }
+
+    #[test]
+    fn test_next_cycles_through_levels() {
+        assert_eq!(Difficulty::Easy.next(), Difficulty::Medium);
+        assert_eq!(Difficulty::Medium.next(), Difficulty::Hard);
+        assert_eq!(Difficulty::Hard.next(), Difficulty::Easy);
+    }
+}
@@ -0,0 +1,173 @@
+//! # eval module
+//!
+//! Holds the evaluation that scores a position for a [`Team`]. Each player is scored by the value
+//! of its pieces and by how far its pieces have to go. The attackers have to reach the fort in the
+//! middle of the board, while the defender pushes to the border the attackers come from. The score
+//! of the team is its own score minus the scores of the other players.
+//!
+//! The values are held in [`Weights`] so they can be tuned without touching the search.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use fort_builders::{
+    board::{border_distance, fort_distance},
+    game::Game,
+    pieces::{Piece, PieceType},
+    player::{Player, Team},
+};
+
+/// The score of a won game. Higher than the score of any position that is still being played.
+pub const WIN_SCORE: i32 = 1_000_000_i32;
+
+/// The values the evaluation scores a position with.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Weights {
+    /// The value of a pawn.
+    pub pawn: i32,
+    /// The value of a knight.
+    pub knight: i32,
+    /// The value of a minister.
+    pub minister: i32,
+    /// The value of a rook.
+    pub rook: i32,
+    /// The value of a queen.
+    pub queen: i32,
+    /// The score lost for each square between an attacker piece and the fort.
+    pub fort_distance: i32,
+    /// The score lost for each square between a defender piece and the border of the attackers.
+    pub border_distance: i32,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Weights████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Default for Weights {
+    /// The pieces are valued the way they are counted in chess.
+    #[inline]
+    fn default() -> Self {
+        Weights {
+            pawn: 100_i32,
+            knight: 300_i32,
+            minister: 300_i32,
+            rook: 500_i32,
+            queen: 900_i32,
+            fort_distance: 10_i32,
+            border_distance: 5_i32,
+        }
+    }
+}
+
+impl Weights {
+    /// To get the value of a piece type.
+    #[inline]
+    pub fn piece_value(&self, piece_type: PieceType) -> i32 {
+        match piece_type {
+            PieceType::Pawn => self.pawn,
+            PieceType::Knight => self.knight,
+            PieceType::Minister => self.minister,
+            PieceType::Rook => self.rook,
+            PieceType::Queen => self.queen,
+        }
+    }
+    /// To get the score of a single piece of the player.
+    #[inline]
+    fn piece_score(&self, player: &Player, piece: &Piece) -> i32 {
+        let (x, y) = (piece.position.x, piece.position.y);
+        self.piece_value(piece.piece_type)
+            - match player.is_defender {
+                true => border_distance(x, y) * self.border_distance,
+                false => fort_distance(x, y) * self.fort_distance,
+            }
+    }
+    /// To get the score of the player, without the other players.
+    #[inline]
+    fn player_score(&self, player: &Player) -> i32 {
+        player
+            .pieces
+            .iter()
+            .map(|piece| self.piece_score(player, piece))
+            .sum::<i32>()
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Evaluation████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To score the position for the team.
+///
+/// A game that is over scores [`WIN_SCORE`] for the winner and the negative of it for everyone
+/// else, including a team that has been eliminated.
+pub fn evaluate(game: &Game, team: Team, weights: &Weights) -> i32 {
+    if let Some(winner) = game.players.iter().find(|player| player.is_winner) {
+        return match winner.team == team {
+            true => WIN_SCORE,
+            false => -WIN_SCORE,
+        };
+    }
+    if !game.players.iter().any(|player| player.team == team) {
+        return -WIN_SCORE;
+    }
+    game.players
+        .iter()
+        .map(|player| match player.team == team {
+            true => weights.player_score(player),
+            false => -weights.player_score(player),
+        })
+        .sum::<i32>()
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// To set up the game at the position string.
+    fn game(position: &str) -> Game {
+        Game::from_position_str(position).unwrap()
+    }
+
+    #[test]
+    fn test_score_of_one_side_is_negative_of_other() {
+        let game = game("RD=alice:Q1a1/B1=bobby:P1e2 B 2");
+        let weights = Weights::default();
+        let red = evaluate(&game, Team::Red, &weights);
+        assert!(red > 0_i32);
+        assert_eq!(red, -evaluate(&game, Team::Blue, &weights));
+    }
+
+    #[test]
+    fn test_attacker_closer_to_fort_scores_higher() {
+        let weights = Weights::default();
+        let near = evaluate(
+            &game("RD=alice:N1c3/B1=bobby:P1f2 B 2"),
+            Team::Blue,
+            &weights,
+        );
+        let far = evaluate(
+            &game("RD=alice:N1c3/B1=bobby:P1a2 B 2"),
+            Team::Blue,
+            &weights,
+        );
+        assert!(near > far);
+    }
+
+    #[test]
+    fn test_won_game_scores_win_for_winner_only() {
+        let mut game = game("RD=alice:Q1a1/B1=bobby:P1e2 B 2");
+        game.players[1_usize].is_winner = true;
+        let weights = Weights::default();
+        assert_eq!(evaluate(&game, Team::Blue, &weights), WIN_SCORE);
+        assert_eq!(evaluate(&game, Team::Red, &weights), -WIN_SCORE);
+    }
+
+    #[test]
+    fn test_team_out_of_game_scores_loss() {
+        let game = game("RD=alice:Q1a1/B1=bobby:P1e2 B 2");
+        assert_eq!(
+            evaluate(&game, Team::Green, &Weights::default()),
+            -WIN_SCORE
+        );
+    }
+}
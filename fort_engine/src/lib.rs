@@ -0,0 +1,19 @@
+//! # main fort engine module.
+//!
+//! A search engine for fort chess that looks ahead over the legal moves of the [`GameRules`]. The
+//! moves of the players are searched with alpha-beta and the fort dice rolls are chance nodes, so
+//! the engine knows that only one face of the dice wins the game.
+//!
+//! The search is limited by a depth and a time, picked through a [`Difficulty`]. The positions at
+//! the end of the search are scored by the [`Weights`] of the evaluation.
+//!
+//! [`GameRules`]: fort_builders::rules::GameRules
+//! [`Difficulty`]: difficulty::Difficulty
+//! [`Weights`]: eval::Weights
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+//--------------//
+pub mod difficulty;
+pub mod eval;
+pub mod search;
+//--------------//
@@ -0,0 +1,314 @@
+//! # search module
+//!
+//! Holds the expectiminimax search over the legal moves of the game. The player whose turn it is
+//! at the root is the maximizing player and every other player is taken to play against it. The
+//! moves of the players are searched with alpha-beta pruning.
+//!
+//! A fort dice roll is a chance node. Only the winning roll of the [`RuleSet`] wins the game and
+//! every other face leaves the same position, so the node is the average of the two outcomes
+//! weighted by the number of faces that give them.
+//!
+//! The search deepens one ply at a time until it reaches the depth of the [`SearchLimits`] or runs
+//! out of time. A depth that is cut short by the time is thrown away, so the move played always
+//! comes from a depth that was searched in full.
+//!
+//! [`RuleSet`]: fort_builders::ruleset::RuleSet
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::eval::{evaluate, Weights, WIN_SCORE};
+use fort_builders::{
+    dice::DICE_FACES,
+    game::{Game, GameAction},
+    pieces::{Move, MoveKind},
+    player::Team,
+    rules::GameRules,
+    snapshot, Error,
+};
+use std::time::{Duration, Instant};
+
+/// The number of nodes searched between two checks of the time.
+const TIME_CHECK_NODES: u64 = 1_024_u64;
+/// The alpha and beta of a window that nothing is pruned from.
+const FULL_WINDOW: (i32, i32) = (i32::MIN, i32::MAX);
+
+/// The limits of a search.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SearchLimits {
+    /// The number of moves to look ahead.
+    pub depth: u32,
+    /// The time after which the search stops deepening.
+    pub time: Duration,
+}
+
+/// The move found by a search.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SearchResult {
+    /// The best move for the player whose turn it is.
+    pub step: Move,
+    /// The score of the move for the player.
+    pub score: i32,
+    /// The depth that was searched in full.
+    pub depth: u32,
+    /// The number of positions searched.
+    pub nodes: u64,
+}
+
+/// The state of a running search.
+struct Searcher<'a> {
+    /// The team the search plays for.
+    team: Team,
+    /// The weights of the evaluation.
+    weights: &'a Weights,
+    /// The time at which the search stops.
+    deadline: Instant,
+    /// The number of positions searched.
+    nodes: u64,
+    /// If the search ran out of time.
+    stopped: bool,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// To copy the game into a board to search on.
+///
+/// The search plays the moves and takes them back, so it gets a board of its own instead of the
+/// game being played. The board keeps no history of the moves before it.
+#[inline]
+pub fn search_board(game: &Game) -> Result<Game, Error> {
+    Ok(snapshot::from_str(&snapshot::to_string(game)?)?)
+}
+
+/// To find the best move for the player whose turn it is on the board.
+///
+/// The first depth is always searched in full so that there is a move to play. Returns a
+/// [`MoveKind::Skip`] if the game is already over.
+pub fn search(
+    mut board: Game,
+    limits: SearchLimits,
+    weights: &Weights,
+) -> Result<SearchResult, Error> {
+    let mut result = SearchResult {
+        step: Move::skip(),
+        score: evaluate(&board, board.current_player().team, weights),
+        depth: 0_u32,
+        nodes: 0_u64,
+    };
+    if !board.play {
+        return Ok(result);
+    }
+    let mut searcher = Searcher {
+        team: board.current_player().team,
+        weights,
+        deadline: Instant::now() + limits.time,
+        nodes: 0_u64,
+        stopped: false,
+    };
+    for depth in 1_u32..=limits.depth.max(1_u32) {
+        let found = searcher.root(&mut board, depth, result.step)?;
+        if searcher.stopped && depth > 1_u32 {
+            break;
+        }
+        result = SearchResult {
+            step: found.0,
+            score: found.1,
+            depth,
+            nodes: searcher.nodes,
+        };
+        if searcher.stopped || found.1.abs() >= WIN_SCORE {
+            break;
+        }
+    }
+    result.nodes = searcher.nodes;
+    Ok(result)
+}
+
+/// To order the moves so that the strongest are searched first, which prunes more of the rest.
+///
+/// The best move of the last depth comes first, then the dice rolls, then the captures.
+fn ordered_moves(board: &Game, best: Move) -> Vec<Move> {
+    let mut moves = board.all_legal_moves();
+    moves.sort_by_key(|step| match (*step == best, step.kind) {
+        (true, _) => 0_u8,
+        (false, MoveKind::FortDiceRoll) => 1_u8,
+        (false, MoveKind::Capture) => 2_u8,
+        (false, MoveKind::Step) => 3_u8,
+        (false, MoveKind::Skip) => 4_u8,
+    });
+    moves
+}
+
+/*████Searcher████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl<'a> Searcher<'a> {
+    /// To search the root of the board to the depth.
+    ///
+    /// Returns the best move and its score.
+    fn root(&mut self, board: &mut Game, depth: u32, best: Move) -> Result<(Move, i32), Error> {
+        let mut found = (best, i32::MIN);
+        let (mut alpha, beta) = FULL_WINDOW;
+        for step in ordered_moves(board, best) {
+            let score = self.child(board, step, depth, alpha, beta)?;
+            if score > found.1 {
+                found = (step, score);
+                alpha = alpha.max(score);
+            }
+            if self.stopped {
+                break;
+            }
+        }
+        Ok(found)
+    }
+    /// To get the value of the position after the move.
+    ///
+    /// A dice roll is a chance node and is searched with the full window, as its value is an
+    /// average of the outcomes.
+    fn child(
+        &mut self,
+        board: &mut Game,
+        step: Move,
+        depth: u32,
+        alpha: i32,
+        beta: i32,
+    ) -> Result<i32, Error> {
+        match step.kind {
+            MoveKind::FortDiceRoll => {
+                let won = self.played(board, step, Some(board.rules.winning_roll), depth)?;
+                let missed = self.played(board, step, Some(board.rules.missed_roll()), depth)?;
+                Ok((won + missed * (DICE_FACES as i32 - 1_i32)) / DICE_FACES as i32)
+            }
+            _ => {
+                board.replay_move(step, None)?;
+                let score = self.value(board, depth - 1_u32, alpha, beta);
+                board.undo()?;
+                score
+            }
+        }
+    }
+    /// To get the value of the position after the dice roll with the given face.
+    #[inline]
+    fn played(
+        &mut self,
+        board: &mut Game,
+        step: Move,
+        roll: Option<usize>,
+        depth: u32,
+    ) -> Result<i32, Error> {
+        board.replay_move(step, roll)?;
+        let score = self.value(board, depth - 1_u32, FULL_WINDOW.0, FULL_WINDOW.1);
+        board.undo()?;
+        score
+    }
+    /// To get the value of the position for the team of the search.
+    ///
+    /// The team picks the move with the highest value and the other players pick the lowest. A
+    /// game that is won sooner scores higher than one won later.
+    fn value(
+        &mut self,
+        board: &mut Game,
+        depth: u32,
+        mut alpha: i32,
+        mut beta: i32,
+    ) -> Result<i32, Error> {
+        self.nodes += 1_u64;
+        if self.nodes % TIME_CHECK_NODES == 0_u64 && Instant::now() >= self.deadline {
+            self.stopped = true;
+        }
+        if !board.play {
+            let score = evaluate(board, self.team, self.weights);
+            return Ok(score + score.signum() * depth as i32);
+        }
+        if depth == 0_u32 || self.stopped {
+            return Ok(evaluate(board, self.team, self.weights));
+        }
+        let maximizing = board.current_player().team == self.team;
+        let mut best = match maximizing {
+            true => i32::MIN,
+            false => i32::MAX,
+        };
+        for step in ordered_moves(board, Move::skip()) {
+            let score = self.child(board, step, depth, alpha, beta)?;
+            match maximizing {
+                true => {
+                    best = best.max(score);
+                    alpha = alpha.max(score);
+                }
+                false => {
+                    best = best.min(score);
+                    beta = beta.min(score);
+                }
+            }
+            if alpha >= beta || self.stopped {
+                break;
+            }
+        }
+        Ok(best)
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fort_builders::{dice::SeededDice, notation::parse_square, ruleset::RuleSet};
+
+    /// The blue attacker has a pawn on the fort wall that can roll the dice, and the red defender
+    /// a knight out of its reach. Blue plays next.
+    const PAWN_ON_WALL: &str = "RD=alice:N1c3/B1=bobby:P1f2 B 2";
+
+    #[test]
+    fn test_fort_roll_is_valued_by_its_chance() {
+        let game = Game::from_position_str(PAWN_ON_WALL).unwrap();
+        let weights = Weights::default();
+        let roll = Move::dice_roll(parse_square("1f2").unwrap());
+        let score_after = |face: usize| {
+            let mut board = search_board(&game).unwrap();
+            board.replay_move(roll, Some(face)).unwrap();
+            evaluate(&board, Team::Blue, &weights)
+        };
+        let won = score_after(game.rules.winning_roll);
+        let missed = score_after(game.rules.missed_roll());
+        assert_eq!(won, WIN_SCORE);
+        let limits = SearchLimits {
+            depth: 1_u32,
+            time: Duration::from_secs(60_u64),
+        };
+        let result = search(search_board(&game).unwrap(), limits, &weights).unwrap();
+        assert_eq!(result.step, roll);
+        assert_eq!(
+            result.score,
+            (won + missed * (DICE_FACES as i32 - 1_i32)) / DICE_FACES as i32
+        );
+        assert!(result.score < WIN_SCORE);
+    }
+
+    #[test]
+    fn test_search_stops_deepening_once_out_of_time() {
+        let names = ["alice", "bobby", "carol", "david"]
+            .map(String::from)
+            .to_vec();
+        let game =
+            Game::new_match(names, SeededDice::from_seed(7_u64), RuleSet::default()).unwrap();
+        let limits = SearchLimits {
+            depth: 64_u32,
+            time: Duration::ZERO,
+        };
+        let result = search(search_board(&game).unwrap(), limits, &Weights::default()).unwrap();
+        assert!((1_u32..64_u32).contains(&result.depth));
+        assert!(game.all_legal_moves().contains(&result.step));
+    }
+
+    #[test]
+    fn test_game_over_is_not_searched() {
+        let mut game = Game::from_position_str(PAWN_ON_WALL).unwrap();
+        game.set_play_false();
+        let limits = SearchLimits {
+            depth: 2_u32,
+            time: Duration::from_secs(60_u64),
+        };
+        let result = search(game, limits, &Weights::default()).unwrap();
+        assert_eq!(result.step, Move::skip());
+        assert_eq!(result.depth, 0_u32);
+    }
+}
@@ -0,0 +1,417 @@
+//! # client module
+//!
+//! Holds the [`Client`] that joins a [`Host`] and plays one of the seats of its game. The client
+//! keeps a copy of the game, which is only changed by the moves the host announces. The moves of
+//! the player are sent to the host as intents instead of being played on the copy.
+//!
+//! A client can also [`Client::enter`] the lobby of a game that has not started yet, and change
+//! its seat with [`Client::lobby`] until the host sends the game.
+//!
+//! The client takes its part in the commit-reveal of the dice rolls on its own. It answers the
+//! commitment of the host with a seed when its team rolls the dice, and checks every roll the host
+//! reveals against the commitment it saw. A roll that does not match ends the connection with
+//! [`Error::UnfairRoll`].
+//!
+//! [`Host`]: crate::host::Host
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::Error;
+use fort_builders::{
+    fairness::{self, Commitment},
+    game::Game,
+    lobby::LobbyRequest,
+    pieces::{Move, MoveOutcome},
+    player::Team,
+};
+use fort_protocol::{
+    codec::{read_frame, write_frame, Encoding},
+    handshake,
+    message::{ClientMessage, HostMessage},
+};
+use std::{
+    cell::Cell,
+    io::{BufReader, Read},
+    net::{Shutdown, TcpStream, ToSocketAddrs},
+    sync::{
+        mpsc::{self, Receiver, TryRecvError},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+/// A client seated at the game of a host.
+#[derive(Debug)]
+pub struct Client {
+    /// The team of the seat the host gave, which changes when another seat is claimed in the
+    /// lobby.
+    team: Cell<Team>,
+    /// The stream to write to the host, shared with the thread that sends the seeds of the dice
+    /// rolls.
+    stream: Arc<Mutex<TcpStream>>,
+    /// The encoding of the messages the host accepted.
+    encoding: Encoding,
+    /// The messages of the host read on a background thread. The thread stops after the first
+    /// error.
+    messages: Receiver<Result<HostMessage, Error>>,
+}
+
+/// The part of the client in the commit-reveal of the dice rolls.
+pub(crate) struct DiceWitness {
+    /// The team of the client, which a spectator does not have.
+    team: Option<Team>,
+    /// The team rolling the dice and the commitment of its roll waiting to be revealed, along
+    /// with the seed the client sent for it if its team rolls the dice.
+    pending: Option<(Team, Commitment, Option<u64>)>,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Client████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Client {
+    /// To join the game of the host at the given address, with the messages in the encoding.
+    ///
+    /// Waits for the host to give a seat and returns the client along with the game as the host
+    /// has it. A host still in its lobby is waited on until it starts the game. Returns
+    /// [`Error::Rejected`] if the host has no seat to give.
+    pub fn join(
+        address: impl ToSocketAddrs,
+        name: &str,
+        encoding: Encoding,
+    ) -> Result<(Self, Game), Error> {
+        let client = Client::enter(address, name, encoding)?;
+        loop {
+            match client.recv()? {
+                HostMessage::Snapshot { snapshot } => {
+                    let game = Game::try_from(snapshot)?;
+                    return Ok((client, game));
+                }
+                HostMessage::Rejected { reason } => return Err(Error::Rejected(reason)),
+                _ => {}
+            }
+        }
+    }
+    /// To ask the host at the given address for a seat, with the messages in the encoding.
+    ///
+    /// Returns the client as soon as the host gives a seat. The game follows as a
+    /// [`HostMessage::Snapshot`], right away if it has started or else once the host starts it.
+    pub fn enter(
+        address: impl ToSocketAddrs,
+        name: &str,
+        encoding: Encoding,
+    ) -> Result<Self, Error> {
+        let (mut stream, mut reader, encoding) = connect(address, encoding)?;
+        let join = ClientMessage::Join {
+            name: name.to_string(),
+        };
+        write_frame(&mut stream, encoding, &join)?;
+        let team = wait_for(&mut reader, encoding, |message| match message {
+            HostMessage::Welcome { team } => Some(team),
+            _ => None,
+        })?;
+        let stream = Arc::new(Mutex::new(stream));
+        let messages = read_messages(reader, encoding, &stream, DiceWitness::new(Some(team)));
+        Ok(Client {
+            team: Cell::new(team),
+            stream,
+            encoding,
+            messages,
+        })
+    }
+    /// To get the team the client plays.
+    #[inline]
+    pub fn team(&self) -> Team {
+        self.team.get()
+    }
+    /// To send the request to the lobby of the host.
+    ///
+    /// The seats only change once the host sends them back.
+    #[inline]
+    pub fn lobby(&mut self, request: LobbyRequest) -> Result<(), Error> {
+        self.write(&ClientMessage::Lobby(request))
+    }
+    /// To ask the host to play the move.
+    ///
+    /// The move is not played on the copy of the game until the host announces it.
+    #[inline]
+    pub fn send(&mut self, step: Move) -> Result<(), Error> {
+        self.write(&ClientMessage::from_move(step))
+    }
+    /// To give up the game. The team is only removed once the host announces it.
+    #[inline]
+    pub fn resign(&mut self) -> Result<(), Error> {
+        self.write(&ClientMessage::Resign)
+    }
+    /// To send the message to the host.
+    #[inline]
+    fn write(&mut self, message: &ClientMessage) -> Result<(), Error> {
+        write_to(&self.stream, self.encoding, message)
+    }
+    /// To get the next message of the host without waiting for it.
+    ///
+    /// Returns `None` if no message has arrived yet, and [`Error::Disconnected`] once the host is
+    /// gone.
+    #[inline]
+    pub fn poll(&self) -> Result<Option<HostMessage>, Error> {
+        match self.messages.try_recv() {
+            Ok(message) => message.map(|message| Some(self.track(message))),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(Error::Disconnected),
+        }
+    }
+    /// To wait for the next message of the host.
+    #[inline]
+    pub fn recv(&self) -> Result<HostMessage, Error> {
+        let message = self.messages.recv().map_err(|_| Error::Disconnected)??;
+        Ok(self.track(message))
+    }
+    /// To keep the team of the client up to date with the seat the host gives.
+    #[inline]
+    fn track(&self, message: HostMessage) -> HostMessage {
+        if let HostMessage::Welcome { team } = message {
+            self.team.set(team);
+        }
+        message
+    }
+}
+
+impl Drop for Client {
+    /// To give the seat back and close the connection.
+    fn drop(&mut self) {
+        let _result = self.write(&ClientMessage::Leave);
+        if let Ok(stream) = self.stream.lock() {
+            let _result = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████DiceWitness████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl DiceWitness {
+    /// To create the witness for the team of the client, if it has one.
+    #[inline]
+    pub(crate) fn new(team: Option<Team>) -> Self {
+        DiceWitness {
+            team,
+            pending: None,
+        }
+    }
+    /// To follow the dice roll the message is a part of.
+    ///
+    /// A commitment for the team of the client is answered with a new seed. A revealed roll is
+    /// checked against the commitment seen before it for the same team, which a client that
+    /// joined in between has not seen. Only the rolls of the computer players come without a
+    /// proof, so a roll of the team of the client, or of a team the host committed for, without
+    /// one is refused. The team follows the seat the host gives in the lobby.
+    fn see(
+        &mut self,
+        message: &HostMessage,
+        writer: &Mutex<TcpStream>,
+        encoding: Encoding,
+    ) -> Result<(), Error> {
+        match message {
+            HostMessage::Welcome { team } => self.team = Some(*team),
+            HostMessage::DiceCommit {
+                team, commitment, ..
+            } => {
+                let seed = match Some(*team) == self.team {
+                    true => {
+                        let seed = fairness::random_seed().map_err(fort_builders::Error::from)?;
+                        write_to(writer, encoding, &ClientMessage::DiceSeed { seed })?;
+                        Some(seed)
+                    }
+                    false => None,
+                };
+                self.pending = Some((*team, *commitment, seed));
+            }
+            HostMessage::DiceResult {
+                team,
+                roll,
+                proof: Some(proof),
+                ..
+            } => match self.pending.take() {
+                Some((pending, commitment, seed)) if pending == *team => {
+                    proof.witness(commitment, seed, *roll)?
+                }
+                _ => proof.check(Some(*roll))?,
+            },
+            HostMessage::DiceResult {
+                team, proof: None, ..
+            } => {
+                let committed = self.pending.take().map(|(pending, ..)| pending);
+                if Some(*team) == self.team || Some(*team) == committed {
+                    return Err(fairness::Error::MissingProof(*team).into());
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Messages████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To connect to the host at the given address and answer its handshake. Returns the stream to
+/// write to the host, the reader of its messages and the encoding the host accepted.
+pub(crate) fn connect(
+    address: impl ToSocketAddrs,
+    encoding: Encoding,
+) -> Result<(TcpStream, BufReader<TcpStream>, Encoding), Error> {
+    let mut stream = TcpStream::connect(address)?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let encoding = handshake::offer(&mut reader, &mut stream, encoding)?;
+    Ok((stream, reader, encoding))
+}
+
+/// To read the messages of the host on a background thread, which stops after the first error.
+///
+/// Each message is seen by the witness of the dice rolls before it is passed on.
+pub(crate) fn read_messages(
+    mut reader: BufReader<TcpStream>,
+    encoding: Encoding,
+    stream: &Arc<Mutex<TcpStream>>,
+    mut witness: DiceWitness,
+) -> Receiver<Result<HostMessage, Error>> {
+    let writer = Arc::clone(stream);
+    let (sender, messages) = mpsc::channel();
+    thread::spawn(move || loop {
+        let message = read_frame(&mut reader, encoding)
+            .map_err(Error::from)
+            .and_then(|message| message.ok_or(Error::Disconnected))
+            .and_then(|message| {
+                witness.see(&message, &writer, encoding)?;
+                Ok(message)
+            });
+        let failed = message.is_err();
+        if sender.send(message).is_err() || failed {
+            return;
+        }
+    });
+    messages
+}
+
+/// To send the message to the host on the shared stream.
+#[inline]
+fn write_to(
+    stream: &Mutex<TcpStream>,
+    encoding: Encoding,
+    message: &ClientMessage,
+) -> Result<(), Error> {
+    let mut stream = stream.lock().map_err(|_| Error::Disconnected)?;
+    Ok(write_frame(&mut *stream, encoding, message)?)
+}
+
+/// To skip the messages of the host until the one picked, while joining.
+fn wait_for<T>(
+    reader: &mut impl Read,
+    encoding: Encoding,
+    pick: impl Fn(HostMessage) -> Option<T>,
+) -> Result<T, Error> {
+    loop {
+        match read_frame(reader, encoding)? {
+            Some(HostMessage::Rejected { reason }) => return Err(Error::Rejected(reason)),
+            Some(message) => {
+                if let Some(value) = pick(message) {
+                    return Ok(value);
+                }
+            }
+            None => return Err(Error::Disconnected),
+        }
+    }
+}
+
+/// To play the move or the resignation announced by the host on the copy of the game.
+///
+/// The dice roll of the host is used instead of rolling the dice of the copy, and its proof is
+/// kept in the history. Only a computer player rolls without a proof, so a roll without one for a
+/// team played by a person returns [`Error::UnfairRoll`]. Returns the outcome of the move, or
+/// `None` if the message changes nothing on the game.
+#[inline]
+pub fn apply(game: &mut Game, message: &HostMessage) -> Result<Option<MoveOutcome>, Error> {
+    match message {
+        HostMessage::Resigned { team } => return Ok(game.resign(*team)),
+        HostMessage::DiceResult {
+            team, proof: None, ..
+        } if !game
+            .players
+            .iter()
+            .any(|player| player.team == *team && player.is_computer()) =>
+        {
+            return Err(fairness::Error::MissingProof(*team).into())
+        }
+        HostMessage::DiceResult {
+            at,
+            proof: Some(proof),
+            ..
+        } => return Ok(Some(game.roll_with_proof(*at, *proof)?)),
+        _ => {}
+    }
+    match message.to_move() {
+        Some((_, step, roll)) => Ok(Some(game.replay_move(step, roll)?)),
+        None => Ok(None),
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fort_builders::{
+        game::GameAction,
+        notation::parse_square,
+        pieces::{MoveKind, Position},
+        player::PlayerKind,
+    };
+
+    /// To read the square into a position.
+    fn at(square: &str) -> Position {
+        parse_square(square).unwrap()
+    }
+
+    /// To set up the game where the blue pawn reached the side of the red defender and can roll
+    /// the dice.
+    fn rolling() -> Game {
+        let mut game = Game::from_position_str("RD=alice:N1f4/B1=bobby:P1e2 B 2").unwrap();
+        game.apply_move(Move::new(at("1e2"), at("1f2"), MoveKind::Step))
+            .unwrap();
+        game.apply_move(Move::new(at("1f4"), at("1d3"), MoveKind::Step))
+            .unwrap();
+        game
+    }
+
+    /// To announce a roll of the blue pawn without a proof.
+    fn unproven_roll() -> HostMessage {
+        HostMessage::DiceResult {
+            team: Team::Blue,
+            at: at("1f2"),
+            roll: 5_usize,
+            proof: None,
+        }
+    }
+
+    #[test]
+    fn test_unproven_roll_of_person_is_rejected() {
+        let mut game = rolling();
+        let history = game.history.moves();
+        assert!(matches!(
+            apply(&mut game, &unproven_roll()),
+            Err(Error::UnfairRoll(fairness::Error::MissingProof(Team::Blue)))
+        ));
+        assert_eq!(game.history.moves(), history);
+    }
+
+    #[test]
+    fn test_unproven_roll_of_computer_is_played() {
+        let mut game = rolling();
+        game.players
+            .iter_mut()
+            .filter(|player| player.team == Team::Blue)
+            .for_each(|player| player.set_kind(PlayerKind::Computer));
+        let outcome = apply(&mut game, &unproven_roll()).unwrap().unwrap();
+        assert_eq!(outcome.roll, Some(5_usize));
+    }
+}
@@ -0,0 +1,843 @@
+//! # host module
+//!
+//! Holds the [`Host`] that owns the authoritative [`Game`] of a network match. The clients ask for
+//! a seat and send the moves they want to play. The host checks each move against the game and
+//! sends the moves it plays to every seated client, so that their copies of the game follow it.
+//!
+//! A host opened with [`Host::open`] starts with a [`Lobby`] instead of a game. The clients take a
+//! seat when they join, then claim their team, ask to play the defender and mark themselves as
+//! ready. The first client to join, which is the window of the host, starts the game once every
+//! player is ready.
+//!
+//! A client can also watch the game without a seat. The spectators get every message the players
+//! get, from the lobby to the end of the game, and any move they send is turned away. The host
+//! holds the messages of the spectators back for its spectator delay, so that the players cannot
+//! be told about the game by someone watching it.
+//!
+//! The dice rolls of the people are drawn by the commit-reveal scheme of the [`fairness`] module:
+//! the host commits to a seed of its own before the client rolling the dice sends its seed. No
+//! other move is played while the host waits for the seed, and the roll is dropped if the seed
+//! does not arrive within [`ROLL_DEADLINE`], so that the client can ask for it again.
+//!
+//! Each connection starts with the [`handshake`] of the protocol, and the messages are then written
+//! in the encoding the client asked for. The connections are read on a thread each and the game is
+//! kept on a thread of its own, which handles the messages in the order they arrive. The messages
+//! to a client are queued for a thread that writes them, so that a slow client never holds up the
+//! game. A client that falls [`OUTBOX_LEN`] messages behind is dropped.
+//!
+//! [`fairness`]: fort_builders::fairness
+//! [`Lobby`]: fort_builders::lobby::Lobby
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::Error;
+use fort_builders::{
+    ai::choose_move,
+    dice::SeededDice,
+    fairness::{random_seed, Commitment, DiceProof, Nonce},
+    game::{Game, GameAction},
+    lobby::{Lobby, LobbyRequest},
+    pieces::{Move, MoveKind, Position},
+    player::{Player, PlayerKind, Team},
+    rules::{GameRules, IllegalMoveReason},
+    ruleset::RuleSet,
+    snapshot::GameSnapshot,
+};
+use fort_protocol::{
+    codec::{read_frame, write_frame, Encoding},
+    handshake,
+    message::{ClientMessage, HostMessage},
+};
+use std::{
+    collections::BTreeMap,
+    io::BufReader,
+    net::{
+        IpAddr, Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs,
+    },
+    sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, SyncSender},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The number of messages queued for a client before it is dropped.
+pub const OUTBOX_LEN: usize = 1024_usize;
+/// How long the host waits for the seed of the client rolling the dice.
+pub const ROLL_DEADLINE: Duration = Duration::from_secs(30_u64);
+
+/// The host of a network match.
+///
+/// The game is played on background threads that keep running for as long as the program does.
+#[derive(Debug)]
+pub struct Host {
+    /// The address the host listens on.
+    address: SocketAddr,
+}
+
+/// What happened on the connections, in the order the game thread handles it.
+enum Event {
+    /// A client connected, with the stream to write to it and the encoding it asked for.
+    Connected(usize, TcpStream, Encoding),
+    /// A client sent a message.
+    Received(usize, ClientMessage),
+    /// A client closed its connection.
+    Disconnected(usize),
+}
+
+/// A connected client.
+struct Seat {
+    /// The stream of the client, kept to close the connection.
+    stream: TcpStream,
+    /// The queue of the thread writing the messages to the client, along with when each message
+    /// is due.
+    outbox: SyncSender<(Instant, HostMessage)>,
+    /// How long the messages to the client are held back.
+    delay: Duration,
+    /// The team the client plays, once it has joined.
+    team: Option<Team>,
+    /// If the client watches the game without a seat.
+    watching: bool,
+}
+
+/// The connected clients.
+struct Clients {
+    /// The clients by the order they connected in.
+    seats: BTreeMap<usize, Seat>,
+    /// How long the messages to the spectators are held back.
+    spectator_delay: Duration,
+}
+
+/// A dice roll the host committed to, waiting for the seed of the client rolling the dice.
+struct PendingRoll {
+    /// The team rolling the dice.
+    team: Team,
+    /// The position of the piece rolling the dice.
+    at: Position,
+    /// The nonce the seed of the host is committed under.
+    nonce: Nonce,
+    /// The seed of the host.
+    seed: u64,
+    /// When the roll is dropped if the seed of the client has not arrived.
+    deadline: Instant,
+}
+
+/// The lobby of the game before it starts, along with the clients in it.
+struct Room {
+    /// The seats of the players.
+    lobby: Lobby,
+    /// The rules the game is played with.
+    rules: RuleSet,
+    /// The connected clients.
+    clients: Clients,
+    /// The client that may start the game, which is the first one to join.
+    owner: Option<usize>,
+}
+
+/// The game along with the clients playing it.
+struct Table {
+    /// The authoritative game.
+    game: Game,
+    /// The connected clients.
+    clients: Clients,
+    /// The dice roll waiting for the seed of its client, if any.
+    pending: Option<PendingRoll>,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Host████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Host {
+    /// To start hosting the game on the given address.
+    ///
+    /// The port can be `0` to let the system pick a free one, which can then be read from
+    /// [`Host::local_addr`]. The turns of the computer players are played by the host with the
+    /// [`ai`] module. The messages to the spectators are held back for the spectator delay.
+    ///
+    /// [`ai`]: fort_builders::ai
+    pub fn start(
+        address: impl ToSocketAddrs,
+        game: Game,
+        spectator_delay: Duration,
+    ) -> Result<Self, Error> {
+        let listener = TcpListener::bind(address)?;
+        let address = listener.local_addr()?;
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || accept(listener, sender));
+        let mut table = Table {
+            game,
+            clients: Clients::new(spectator_delay),
+            pending: None,
+        };
+        table.play_computer_turns();
+        thread::spawn(move || table.run(receiver));
+        Ok(Host { address })
+    }
+    /// To open the lobby of a new game on the given address.
+    ///
+    /// The lobby can hold the seats of the computer players already, which the clients cannot
+    /// take. The game is created with the rules once the first client to join starts it. The
+    /// messages to the spectators are held back for the spectator delay.
+    pub fn open(
+        address: impl ToSocketAddrs,
+        lobby: Lobby,
+        rules: RuleSet,
+        spectator_delay: Duration,
+    ) -> Result<Self, Error> {
+        let listener = TcpListener::bind(address)?;
+        let address = listener.local_addr()?;
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || accept(listener, sender));
+        let room = Room {
+            lobby,
+            rules,
+            clients: Clients::new(spectator_delay),
+            owner: None,
+        };
+        thread::spawn(move || {
+            if let Some(mut table) = room.run(&receiver) {
+                table.play_computer_turns();
+                table.run(receiver);
+            }
+        });
+        Ok(Host { address })
+    }
+    /// To get the address the host listens on.
+    #[inline]
+    pub fn local_addr(&self) -> SocketAddr {
+        self.address
+    }
+    /// To get the address to join the host from the same machine. A host listening on every
+    /// interface is joined through the loopback one.
+    #[inline]
+    pub fn join_addr(&self) -> SocketAddr {
+        let mut address = self.address;
+        if address.ip().is_unspecified() {
+            address.set_ip(match address.is_ipv4() {
+                true => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                false => IpAddr::V6(Ipv6Addr::LOCALHOST),
+            });
+        }
+        address
+    }
+}
+
+/// To take the new connections and start reading each one on a thread of its own.
+fn accept(listener: TcpListener, sender: Sender<Event>) {
+    for (id, stream) in listener.incoming().enumerate() {
+        let (stream, reader) = match stream.and_then(|stream| Ok((stream.try_clone()?, stream))) {
+            Ok(streams) => streams,
+            Err(_) => continue,
+        };
+        let sender = sender.clone();
+        thread::spawn(move || listen(id, stream, reader, sender));
+    }
+}
+
+/// To answer the handshake of a client, then pass its messages to the game thread until the
+/// connection is closed or sends something that is not a message.
+///
+/// A client speaking another version of the protocol is dropped before it reaches the game.
+fn listen(id: usize, mut stream: TcpStream, reader: TcpStream, sender: Sender<Event>) {
+    let mut reader = BufReader::new(reader);
+    let encoding = match handshake::accept(&mut reader, &mut stream) {
+        Ok(encoding) => encoding,
+        Err(_) => return,
+    };
+    if sender.send(Event::Connected(id, stream, encoding)).is_err() {
+        return;
+    }
+    while let Ok(Some(message)) = read_frame(&mut reader, encoding) {
+        if sender.send(Event::Received(id, message)).is_err() {
+            return;
+        }
+    }
+    let _result = sender.send(Event::Disconnected(id));
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Clients████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Clients {
+    /// To create the list of clients, with the messages to the spectators held back for the
+    /// delay.
+    #[inline]
+    fn new(spectator_delay: Duration) -> Self {
+        Clients {
+            seats: BTreeMap::new(),
+            spectator_delay,
+        }
+    }
+    /// To add the client that connected, along with the thread writing its messages.
+    fn connect(&mut self, id: usize, stream: TcpStream, encoding: Encoding) {
+        let writer = match stream.try_clone() {
+            Ok(writer) => writer,
+            Err(_) => {
+                let _result = stream.shutdown(Shutdown::Both);
+                return;
+            }
+        };
+        let (outbox, messages) = mpsc::sync_channel(OUTBOX_LEN);
+        thread::spawn(move || write_messages(writer, encoding, messages));
+        let seat = Seat {
+            stream,
+            outbox,
+            delay: Duration::ZERO,
+            team: None,
+            watching: false,
+        };
+        self.seats.insert(id, seat);
+    }
+    /// To remove the client that disconnected, which stops the thread writing its messages.
+    #[inline]
+    fn remove(&mut self, id: usize) {
+        self.seats.remove(&id);
+    }
+    /// To get the team of the client, if it has joined.
+    #[inline]
+    fn team(&self, id: usize) -> Option<Team> {
+        self.seats.get(&id).and_then(|seat| seat.team)
+    }
+    /// To get the client playing the team, if any.
+    #[inline]
+    fn of_team(&self, team: Team) -> Option<usize> {
+        self.seats
+            .iter()
+            .find(|(_, seat)| seat.team == Some(team))
+            .map(|(&id, _)| id)
+    }
+    /// To send a message to a single client.
+    #[inline]
+    fn send(&mut self, id: usize, message: &HostMessage) {
+        if let Some(seat) = self.seats.get(&id) {
+            seat.post(message);
+        }
+    }
+    /// To send a message to every client that has joined or watches the game.
+    #[inline]
+    fn broadcast(&mut self, message: &HostMessage) {
+        self.seats
+            .values()
+            .filter(|seat| seat.team.is_some() || seat.watching)
+            .for_each(|seat| seat.post(message));
+    }
+    /// To turn the message of the client away.
+    #[inline]
+    fn reject(&mut self, id: usize, reason: impl ToString) {
+        self.send(
+            id,
+            &HostMessage::Rejected {
+                reason: reason.to_string(),
+            },
+        );
+    }
+    /// To get the teams of the clients that have joined.
+    #[inline]
+    fn teams(&self) -> Vec<Team> {
+        self.seats
+            .values()
+            .filter_map(|seat| seat.team)
+            .collect::<Vec<Team>>()
+    }
+    /// To let the client watch the game, with its messages held back for the spectator delay
+    /// from now on. Returns `false` if the client has a seat, or watches already, after turning it
+    /// away.
+    #[inline]
+    fn watch(&mut self, id: usize) -> bool {
+        let reason = match self.seats.get_mut(&id) {
+            Some(seat) if seat.team.is_some() => "you have already joined the game",
+            Some(seat) if seat.watching => "you are already watching the game",
+            Some(seat) => {
+                seat.watching = true;
+                seat.delay = self.spectator_delay;
+                return true;
+            }
+            None => return false,
+        };
+        self.reject(id, reason);
+        false
+    }
+    /// To set the team of the client. Returns the team it had before.
+    #[inline]
+    fn seat(&mut self, id: usize, team: Option<Team>) -> Option<Team> {
+        self.seats
+            .get_mut(&id)
+            .and_then(|seat| std::mem::replace(&mut seat.team, team))
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Seat████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Seat {
+    /// To queue the message for the client without waiting for it to be written. The message is
+    /// due once the delay of the client is over.
+    ///
+    /// A client whose queue is full, or whose writing thread stopped, has its connection closed.
+    /// It is then dropped once its reading thread sees the connection close.
+    #[inline]
+    fn post(&self, message: &HostMessage) {
+        let due = Instant::now() + self.delay;
+        if self.outbox.try_send((due, message.clone())).is_err() {
+            let _result = self.stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+/// To write the messages queued for the client once each one is due, until the queue is gone or
+/// the client cannot be written to, which closes the connection.
+fn write_messages(
+    mut stream: TcpStream,
+    encoding: Encoding,
+    messages: Receiver<(Instant, HostMessage)>,
+) {
+    for (due, message) in messages {
+        thread::sleep(due.saturating_duration_since(Instant::now()));
+        if write_frame(&mut stream, encoding, &message).is_err() {
+            break;
+        }
+    }
+    let _result = stream.shutdown(Shutdown::Both);
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Room████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Room {
+    /// To handle the events until the game starts. Returns the table of the game, or `None` if
+    /// the connections are gone before it starts.
+    fn run(mut self, events: &Receiver<Event>) -> Option<Table> {
+        for event in events.iter() {
+            match event {
+                Event::Connected(id, stream, encoding) => {
+                    self.clients.connect(id, stream, encoding)
+                }
+                Event::Received(id, message) => {
+                    if let Some(game) = self.receive(id, message) {
+                        return Some(Table {
+                            game,
+                            clients: self.clients,
+                            pending: None,
+                        });
+                    }
+                }
+                Event::Disconnected(id) => {
+                    self.leave(id);
+                    self.clients.remove(id);
+                }
+            }
+        }
+        None
+    }
+    /// To handle a message of the client. Returns the game once it starts.
+    fn receive(&mut self, id: usize, message: ClientMessage) -> Option<Game> {
+        match message {
+            ClientMessage::Join { name } => self.join(id, name),
+            ClientMessage::Watch => self.watch(id),
+            ClientMessage::Lobby(request) => return self.request(id, &request),
+            ClientMessage::Leave => self.leave(id),
+            _ => self.clients.reject(id, "the game has not started yet"),
+        }
+        None
+    }
+    /// To let every client know the seats of the lobby.
+    #[inline]
+    fn broadcast_seats(&mut self) {
+        let seats = self.lobby.seats().to_vec();
+        self.clients.broadcast(&HostMessage::Lobby { seats });
+    }
+    /// To let the client watch the lobby, starting with its seats.
+    fn watch(&mut self, id: usize) {
+        if self.clients.watch(id) {
+            let seats = self.lobby.seats().to_vec();
+            self.clients.send(id, &HostMessage::Lobby { seats });
+        }
+    }
+    /// To give the client the first free seat of the lobby.
+    fn join(&mut self, id: usize, name: String) {
+        if self.clients.team(id).is_some() {
+            return self.clients.reject(id, "you have already joined the game");
+        }
+        let team = match self.lobby.sit(name.clone(), PlayerKind::Human) {
+            Ok(team) => team,
+            Err(err) => return self.clients.reject(id, err),
+        };
+        self.clients.broadcast(&HostMessage::Joined { name, team });
+        self.clients.send(id, &HostMessage::Welcome { team });
+        self.clients.seat(id, Some(team));
+        self.owner.get_or_insert(id);
+        self.broadcast_seats();
+    }
+    /// To change the seat of the client, or to start the game if the client may start it.
+    ///
+    /// A client that claimed another seat is welcomed again with the team of its new seat.
+    fn request(&mut self, id: usize, request: &LobbyRequest) -> Option<Game> {
+        let team = match self.clients.team(id) {
+            Some(team) => team,
+            None => {
+                self.clients
+                    .reject(id, "join the game before taking a seat");
+                return None;
+            }
+        };
+        if *request == LobbyRequest::Start && self.owner != Some(id) {
+            self.clients.reject(id, "only the host can start the game");
+            return None;
+        }
+        match self.lobby.request(team, request) {
+            Ok(seat) if seat != team => {
+                self.clients.seat(id, Some(seat));
+                self.clients.send(id, &HostMessage::Welcome { team: seat });
+            }
+            Ok(_) => {}
+            Err(err) => {
+                self.clients.reject(id, err);
+                return None;
+            }
+        }
+        match request {
+            LobbyRequest::Start => self.start(id),
+            _ => {
+                self.broadcast_seats();
+                None
+            }
+        }
+    }
+    /// To create the game and send it to every client.
+    fn start(&mut self, id: usize) -> Option<Game> {
+        let game = self
+            .lobby
+            .start(SeededDice::from_entropy(), self.rules)
+            .map(|game| (GameSnapshot::from(&game), game));
+        match game {
+            Ok((snapshot, game)) => {
+                self.clients.broadcast(&HostMessage::Snapshot { snapshot });
+                Some(game)
+            }
+            Err(err) => {
+                self.clients.reject(id, err);
+                None
+            }
+        }
+    }
+    /// To free the seat of the client and let the others know.
+    fn leave(&mut self, id: usize) {
+        if let Some(team) = self.clients.seat(id, None) {
+            self.lobby.leave(team);
+            self.clients.broadcast(&HostMessage::Left { team });
+            self.broadcast_seats();
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Table████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Table {
+    /// To handle the events until every connection and the listener are gone.
+    ///
+    /// The dice roll waiting for a seed is dropped once its deadline is over.
+    fn run(mut self, events: Receiver<Event>) {
+        loop {
+            match self.next_event(&events) {
+                Ok(Event::Connected(id, stream, encoding)) => {
+                    self.clients.connect(id, stream, encoding)
+                }
+                Ok(Event::Received(id, message)) => self.receive(id, message),
+                Ok(Event::Disconnected(id)) => {
+                    self.leave(id);
+                    self.clients.remove(id);
+                }
+                Err(RecvTimeoutError::Timeout) => self.expire_roll(),
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+    }
+    /// To wait for the next event, for no longer than the deadline of the dice roll waiting for a
+    /// seed.
+    #[inline]
+    fn next_event(&self, events: &Receiver<Event>) -> Result<Event, RecvTimeoutError> {
+        match &self.pending {
+            Some(pending) => {
+                events.recv_timeout(pending.deadline.saturating_duration_since(Instant::now()))
+            }
+            None => events.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        }
+    }
+    /// To handle a message of the client.
+    fn receive(&mut self, id: usize, message: ClientMessage) {
+        match message {
+            ClientMessage::Join { name } => self.join(id, &name),
+            ClientMessage::Watch => self.watch(id),
+            ClientMessage::Lobby(_) => self.clients.reject(id, "the game has already started"),
+            ClientMessage::Move(step) => self.intent(id, step),
+            ClientMessage::DiceRoll { at } => self.commit_roll(id, at),
+            ClientMessage::DiceSeed { seed } => self.reveal_roll(id, seed),
+            ClientMessage::Skip => self.intent(id, Move::skip()),
+            ClientMessage::Resign => self.resign(id),
+            ClientMessage::Leave => self.leave(id),
+        }
+    }
+    /// To give the client a seat, then the game as a snapshot.
+    ///
+    /// The seat of the player with the same name is given if no one holds it, else the first seat
+    /// of a person that is free. The seats of the computer players are never given.
+    fn join(&mut self, id: usize, name: &str) {
+        if self.clients.team(id).is_some() {
+            return self.clients.reject(id, "you have already joined the game");
+        }
+        let claimed = self.clients.teams();
+        let free = |player: &&Player| !player.is_computer() && !claimed.contains(&player.team);
+        let team = match self
+            .game
+            .players
+            .iter()
+            .filter(free)
+            .find(|player| player.name == name)
+            .or_else(|| self.game.players.iter().find(free))
+        {
+            Some(player) => player.team,
+            None => return self.clients.reject(id, "there is no free seat in the game"),
+        };
+        let snapshot = GameSnapshot::from(&self.game);
+        self.clients.broadcast(&HostMessage::Joined {
+            name: name.to_string(),
+            team,
+        });
+        self.clients.send(id, &HostMessage::Welcome { team });
+        self.clients.send(id, &HostMessage::Snapshot { snapshot });
+        self.clients.seat(id, Some(team));
+    }
+    /// To let the client watch the game, starting with the game as a snapshot.
+    fn watch(&mut self, id: usize) {
+        if self.clients.watch(id) {
+            let snapshot = GameSnapshot::from(&self.game);
+            self.clients.send(id, &HostMessage::Snapshot { snapshot });
+        }
+    }
+    /// To get the team of the client if it can play now, or else to turn the client away.
+    fn playing_team(&mut self, id: usize) -> Option<Team> {
+        let reason = match self.clients.team(id) {
+            None => String::from("join the game before playing"),
+            Some(_) if !self.game.play => IllegalMoveReason::GameOver.to_string(),
+            Some(team) if self.game.current_player().team != team => {
+                String::from("it is not the turn of your team")
+            }
+            Some(_) if self.pending.is_some() => String::from("the dice is being rolled"),
+            Some(team) => return Some(team),
+        };
+        self.clients.reject(id, reason);
+        None
+    }
+    /// To play the move the client asked for if it is the turn of its team.
+    ///
+    /// The turns of the computer players that follow the move are played right after it. The dice
+    /// is only rolled by [`Table::commit_roll`].
+    fn intent(&mut self, id: usize, step: Move) {
+        let team = match self.playing_team(id) {
+            Some(team) => team,
+            None => return,
+        };
+        if step.kind == MoveKind::FortDiceRoll {
+            return self.clients.reject(id, "ask for the dice roll instead");
+        }
+        match self.game.apply_move(step) {
+            Ok(outcome) => {
+                self.clients
+                    .broadcast(&HostMessage::played(team, step, outcome.roll));
+                self.play_computer_turns();
+            }
+            Err(fort_builders::Error::IllegalMove(_, reason)) => self.clients.reject(id, reason),
+            Err(err) => self.clients.reject(id, err),
+        }
+    }
+    /// To commit to a seed for the dice roll the client asked for and wait for its seed.
+    fn commit_roll(&mut self, id: usize, at: Position) {
+        let team = match self.playing_team(id) {
+            Some(team) => team,
+            None => return,
+        };
+        if !self.game.all_legal_moves().contains(&Move::dice_roll(at)) {
+            return self.clients.reject(id, "the piece cannot roll the dice");
+        }
+        let (nonce, seed) = match Nonce::random().and_then(|nonce| Ok((nonce, random_seed()?))) {
+            Ok(drawn) => drawn,
+            Err(err) => return self.clients.reject(id, err),
+        };
+        self.pending = Some(PendingRoll {
+            team,
+            at,
+            nonce,
+            seed,
+            deadline: Instant::now() + ROLL_DEADLINE,
+        });
+        self.clients.broadcast(&HostMessage::DiceCommit {
+            team,
+            at,
+            commitment: Commitment::of(&nonce, seed),
+        });
+    }
+    /// To roll the dice from the seed of the host and the seed of the client, then reveal the
+    /// seed of the host along with the roll.
+    fn reveal_roll(&mut self, id: usize, seed: u64) {
+        let pending = match self.pending.take() {
+            Some(pending) if self.clients.team(id) == Some(pending.team) => pending,
+            pending => {
+                self.pending = pending;
+                return self
+                    .clients
+                    .reject(id, "there is no dice roll waiting for your seed");
+            }
+        };
+        let proof = DiceProof::new(pending.nonce, pending.seed, seed);
+        match self.game.roll_with_proof(pending.at, proof) {
+            Ok(_) => {
+                self.clients.broadcast(&HostMessage::DiceResult {
+                    team: pending.team,
+                    at: pending.at,
+                    roll: proof.roll(),
+                    proof: Some(proof),
+                });
+                self.play_computer_turns();
+            }
+            Err(err) => self.clients.reject(id, err),
+        }
+    }
+    /// To drop the dice roll of the team if it is waiting for a seed.
+    #[inline]
+    fn cancel_roll(&mut self, team: Team) {
+        if self.pending.as_ref().map(|pending| pending.team) == Some(team) {
+            self.pending = None;
+        }
+    }
+    /// To drop the dice roll whose seed did not arrive in time, and let its client know that it
+    /// can ask for the roll again.
+    fn expire_roll(&mut self) {
+        let team = match self.pending.take() {
+            Some(pending) => pending.team,
+            None => return,
+        };
+        if let Some(id) = self.clients.of_team(team) {
+            self.clients
+                .reject(id, "the seed of the dice roll came too late, roll again");
+        }
+    }
+    /// To remove the team of the client from the game.
+    fn resign(&mut self, id: usize) {
+        let team = match self.clients.team(id) {
+            Some(team) => team,
+            None => return self.clients.reject(id, "join the game before resigning"),
+        };
+        match self.game.resign(team) {
+            Some(_) => {
+                self.cancel_roll(team);
+                self.clients.broadcast(&HostMessage::Resigned { team });
+                self.play_computer_turns();
+            }
+            None => self
+                .clients
+                .reject(id, "the game can no longer be resigned"),
+        }
+    }
+    /// To play the turns of the computer players until it is the turn of a person.
+    ///
+    /// Lets every client know once the game is over.
+    fn play_computer_turns(&mut self) {
+        while self.game.play && self.game.current_player().is_computer() {
+            let team = self.game.current_player().team;
+            let step = choose_move(&self.game);
+            let roll = match self.game.apply_move(step) {
+                Ok(outcome) => outcome.roll,
+                Err(_) => return,
+            };
+            self.clients
+                .broadcast(&HostMessage::played(team, step, roll));
+        }
+        if !self.game.play {
+            let winner = self
+                .game
+                .players
+                .iter()
+                .find(|player| player.is_winner)
+                .map(|player| player.team);
+            self.clients.broadcast(&HostMessage::GameOver { winner });
+        }
+    }
+    /// To free the seat of the client and let the others know.
+    fn leave(&mut self, id: usize) {
+        let team = match self.clients.seat(id, None) {
+            Some(team) => team,
+            None => return,
+        };
+        self.cancel_roll(team);
+        self.clients.broadcast(&HostMessage::Left { team });
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fort_builders::notation::parse_square;
+
+    /// To set up the table of a game where the blue pawn waits for the seed of its dice roll,
+    /// with the deadline of the roll already over.
+    fn rolling() -> Table {
+        let mut game = Game::from_position_str("RD=alice:N1f4/B1=bobby:P1e2 B 2").unwrap();
+        for (from, to) in [("1e2", "1f2"), ("1f4", "1d3")] {
+            let step = Move::new(
+                parse_square(from).unwrap(),
+                parse_square(to).unwrap(),
+                MoveKind::Step,
+            );
+            game.apply_move(step).unwrap();
+        }
+        let pending = PendingRoll {
+            team: Team::Blue,
+            at: parse_square("1f2").unwrap(),
+            nonce: Nonce::from([7_u8; 32_usize]),
+            seed: 17_u64,
+            deadline: Instant::now(),
+        };
+        Table {
+            game,
+            clients: Clients::new(Duration::ZERO),
+            pending: Some(pending),
+        }
+    }
+
+    #[test]
+    fn test_client_not_reading_is_dropped() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut peer = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (stream, _) = listener.accept().unwrap();
+        let mut clients = Clients::new(Duration::ZERO);
+        clients.connect(0_usize, stream, Encoding::Json);
+        assert!(clients.watch(0_usize));
+        let message = HostMessage::Rejected {
+            reason: "x".repeat(16_384_usize),
+        };
+        for _ in 0_usize..OUTBOX_LEN * 4_usize {
+            clients.broadcast(&message);
+        }
+        assert!(std::io::copy(&mut peer, &mut std::io::sink()).is_ok());
+    }
+
+    #[test]
+    fn test_roll_without_seed_expires() {
+        let mut table = rolling();
+        let (sender, events) = mpsc::channel();
+        assert!(matches!(
+            table.next_event(&events),
+            Err(RecvTimeoutError::Timeout)
+        ));
+        table.expire_roll();
+        assert!(table.pending.is_none());
+        assert_eq!(table.game.history.moves().len(), 2_usize);
+        drop(sender);
+        assert!(matches!(
+            table.next_event(&events),
+            Err(RecvTimeoutError::Disconnected)
+        ));
+    }
+}
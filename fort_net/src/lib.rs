@@ -0,0 +1,306 @@
+//! # main fort net module.
+//!
+//! To play a game of fort chess over the local network. One program hosts the authoritative
+//! [`Game`] with a [`Host`] and the others join it by its address with a [`Client`]. The clients
+//! send the moves they want to play and the host answers with the moves it played, which the
+//! clients play on their copies of the game. A [`Spectator`] watches the game without a seat. The
+//! messages are the ones of the [`fort_protocol`] crate.
+//!
+//! [`Game`]: fort_builders::game::Game
+//! [`Host`]: host::Host
+//! [`Client`]: client::Client
+//! [`Spectator`]: spectator::Spectator
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+//--------------//
+pub mod client;
+pub mod host;
+pub mod spectator;
+//--------------//
+
+use fort_builders::{fairness, snapshot, RED, RST};
+use thiserror::Error;
+
+/// Error enum to handle the errors of the network play.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// To handle the errors of the connection.
+    #[error("{} Ran into a network error: {0} {}", RED, RST)]
+    NetworkError(#[from] std::io::Error),
+    /// If a message could not be read or written.
+    #[error("{} Error in the protocol: {0} {}", RED, RST)]
+    ProtocolError(#[from] fort_protocol::Error),
+    /// If the game sent by the host could not be read.
+    #[error("{} Error in the snapshot module: {0} {}", RED, RST)]
+    SnapshotModuleError(#[from] snapshot::Error),
+    /// If a move announced by the host could not be played on the copy of the game.
+    #[error("{} Error in the game: {0} {}", RED, RST)]
+    GameError(#[from] fort_builders::Error),
+    /// If a dice roll revealed by the host does not match its commitment or its seeds.
+    #[error("{} The host revealed an unfair dice roll: {0} {}", RED, RST)]
+    UnfairRoll(#[from] fairness::Error),
+    /// If the host turned the client away.
+    #[error("{} The host turned the request away: {0}. {}", RED, RST)]
+    Rejected(String),
+    /// If the other side closed the connection.
+    #[error("{} The connection was closed. {}", RED, RST)]
+    Disconnected,
+}
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::{self, Client},
+        host::Host,
+        spectator::Spectator,
+    };
+    use fort_builders::{
+        dice::SeededDice, game::Game, pieces::Move, player::Team, rules::GameRules,
+        ruleset::RuleSet, snapshot,
+    };
+    use fort_protocol::{codec::Encoding, message::HostMessage};
+    use std::time::{Duration, Instant};
+
+    /// To wait for the next move the host announces to the client and play it on the game.
+    fn follow(client: &Client, game: &mut Game) -> (Team, Move) {
+        loop {
+            let message = client.recv().unwrap();
+            if let Some((team, step, _)) = message.to_move() {
+                client::apply(game, &message).unwrap();
+                return (team, step);
+            }
+        }
+    }
+
+    #[test]
+    fn test_host_and_clients() {
+        let names = vec![String::from("alice"), String::from("bob")];
+        let game =
+            Game::new_match(names, SeededDice::from_seed(7_u64), RuleSet::default()).unwrap();
+        let host = Host::start("127.0.0.1:0", game, Duration::ZERO).unwrap();
+        let (mut alice, mut alice_game) =
+            Client::join(host.local_addr(), "alice", Encoding::Binary).unwrap();
+        let (mut bob, mut bob_game) =
+            Client::join(host.local_addr(), "bob", Encoding::Json).unwrap();
+        assert_eq!((alice.team(), bob.team()), (Team::Red, Team::Blue));
+        assert!(matches!(
+            alice.recv().unwrap(),
+            HostMessage::Joined {
+                team: Team::Blue,
+                ..
+            }
+        ));
+        // Every seat is taken.
+        assert!(Client::join(host.local_addr(), "carol", Encoding::Binary).is_err());
+
+        let step = alice_game.all_legal_moves()[0_usize];
+        let (mover, waiter) = match alice_game.current_player().team == alice.team() {
+            true => (&mut alice, &mut bob),
+            false => (&mut bob, &mut alice),
+        };
+        // Only the team whose turn it is can play.
+        waiter.send(step).unwrap();
+        assert!(matches!(
+            waiter.recv().unwrap(),
+            HostMessage::Rejected { .. }
+        ));
+        mover.send(step).unwrap();
+        let team = mover.team();
+        assert_eq!(follow(&alice, &mut alice_game), (team, step));
+        assert_eq!(follow(&bob, &mut bob_game), (team, step));
+        assert_eq!(
+            snapshot::to_string(&alice_game).unwrap(),
+            snapshot::to_string(&bob_game).unwrap()
+        );
+        assert_ne!(alice_game.current_player().team, team);
+
+        // Resigning leaves the other player the winner.
+        alice.resign().unwrap();
+        for (client, game) in [(&alice, &mut alice_game), (&bob, &mut bob_game)] {
+            let message = client.recv().unwrap();
+            assert_eq!(message, HostMessage::Resigned { team: Team::Red });
+            client::apply(game, &message).unwrap();
+            assert_eq!(
+                client.recv().unwrap(),
+                HostMessage::GameOver {
+                    winner: Some(Team::Blue)
+                }
+            );
+            assert!(!game.play);
+        }
+    }
+
+    #[test]
+    fn test_fair_dice_roll() {
+        use fort_builders::pieces::MoveKind;
+        let game = Game::from_position_str("RD=alice:N1f4/B1=bobby:P1e2 B 2").unwrap();
+        let host = Host::start("127.0.0.1:0", game, Duration::ZERO).unwrap();
+        let (mut alice, mut alice_game) =
+            Client::join(host.local_addr(), "alice", Encoding::Json).unwrap();
+        let (mut bobby, mut bobby_game) =
+            Client::join(host.local_addr(), "bobby", Encoding::Binary).unwrap();
+        assert_eq!(bobby.team(), Team::Blue);
+        // The pawn of bobby reaches the opposite side and the knight of alice steps away.
+        for team in [Team::Blue, Team::Red] {
+            let step = bobby_game.all_legal_moves()[0_usize];
+            assert_eq!(step.kind, MoveKind::Step);
+            match team {
+                Team::Blue => bobby.send(step).unwrap(),
+                _ => alice.send(step).unwrap(),
+            }
+            follow(&alice, &mut alice_game);
+            follow(&bobby, &mut bobby_game);
+        }
+
+        // The seed of bobby is sent by its client once the host has committed to its own.
+        let roll = bobby_game
+            .all_legal_moves()
+            .into_iter()
+            .find(|step| step.kind == MoveKind::FortDiceRoll)
+            .unwrap();
+        bobby.send(roll).unwrap();
+        for (client, game) in [(&alice, &mut alice_game), (&bobby, &mut bobby_game)] {
+            assert!(matches!(
+                client.recv().unwrap(),
+                HostMessage::DiceCommit {
+                    team: Team::Blue,
+                    ..
+                }
+            ));
+            let message = client.recv().unwrap();
+            let (roll, proof) = match message {
+                HostMessage::DiceResult {
+                    roll,
+                    proof: Some(proof),
+                    ..
+                } => (roll, proof),
+                message => panic!("{message:?}"),
+            };
+            assert!(proof.check(Some(roll)).is_ok());
+            assert_eq!(
+                client::apply(game, &message).unwrap().unwrap().roll,
+                Some(roll)
+            );
+        }
+        assert_eq!(
+            snapshot::to_string(&alice_game).unwrap(),
+            snapshot::to_string(&bobby_game).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lobby() {
+        use fort_builders::{
+            lobby::{Lobby, LobbyRequest},
+            player::PlayerKind,
+        };
+        let mut lobby = Lobby::default();
+        lobby
+            .sit(String::from("Computer1"), PlayerKind::Computer)
+            .unwrap();
+        let host = Host::open("127.0.0.1:0", lobby, RuleSet::default(), Duration::ZERO).unwrap();
+        let mut alice = Client::enter(host.local_addr(), "alice", Encoding::Binary).unwrap();
+        let mut bobby = Client::enter(host.local_addr(), "bobby", Encoding::Json).unwrap();
+        assert_eq!((alice.team(), bobby.team()), (Team::Blue, Team::Green));
+
+        /// To wait for the seats the host sends next.
+        fn seats(client: &Client) -> Lobby {
+            loop {
+                match client.recv().unwrap() {
+                    HostMessage::Lobby { seats } => return Lobby::from_seats(seats),
+                    HostMessage::Rejected { reason } => panic!("{reason}"),
+                    _ => {}
+                }
+            }
+        }
+        /// To wait for the next request the host turns away.
+        fn rejected(client: &Client) {
+            while !matches!(client.recv().unwrap(), HostMessage::Rejected { .. }) {}
+        }
+        // The computer seat cannot be claimed, a free one can.
+        bobby.lobby(LobbyRequest::Claim(Team::Red)).unwrap();
+        rejected(&bobby);
+        bobby.lobby(LobbyRequest::Claim(Team::Yellow)).unwrap();
+        let lobby = seats(&bobby);
+        assert_eq!(bobby.team(), Team::Yellow);
+        assert!(lobby.seat(Team::Green).is_free());
+        bobby.lobby(LobbyRequest::Defender(true)).unwrap();
+        assert!(seats(&bobby).seat(Team::Yellow).defender);
+
+        // Only the first client starts the game, once everyone is ready.
+        bobby.lobby(LobbyRequest::Ready(true)).unwrap();
+        seats(&bobby);
+        bobby.lobby(LobbyRequest::Start).unwrap();
+        rejected(&bobby);
+        alice.lobby(LobbyRequest::Start).unwrap();
+        rejected(&alice);
+        alice.lobby(LobbyRequest::Ready(true)).unwrap();
+        alice.lobby(LobbyRequest::Start).unwrap();
+        for client in [&alice, &bobby] {
+            let game = loop {
+                if let HostMessage::Snapshot { snapshot } = client.recv().unwrap() {
+                    break Game::try_from(snapshot).unwrap();
+                }
+            };
+            let players = game
+                .players
+                .iter()
+                .map(|player| (player.team, player.is_defender))
+                .collect::<Vec<(Team, bool)>>();
+            assert_eq!(
+                players,
+                vec![
+                    (Team::Red, false),
+                    (Team::Blue, false),
+                    (Team::Yellow, true)
+                ]
+            );
+        }
+        // The lobby is closed once the game has started.
+        alice.lobby(LobbyRequest::Ready(false)).unwrap();
+        rejected(&alice);
+    }
+
+    #[test]
+    fn test_spectator() {
+        let names = vec![String::from("alice"), String::from("bobby")];
+        let game =
+            Game::new_match(names, SeededDice::from_seed(3_u64), RuleSet::default()).unwrap();
+        let delay = Duration::from_millis(200_u64);
+        let host = Host::start("127.0.0.1:0", game, delay).unwrap();
+        let (mut alice, mut alice_game) =
+            Client::join(host.local_addr(), "alice", Encoding::Binary).unwrap();
+        let (mut bobby, _) = Client::join(host.local_addr(), "bobby", Encoding::Json).unwrap();
+        let (watcher, mut watched) = Spectator::follow(host.local_addr(), Encoding::Json).unwrap();
+        // The spectator takes no seat.
+        assert!(Client::join(host.local_addr(), "carol", Encoding::Binary).is_err());
+
+        let step = alice_game.all_legal_moves()[0_usize];
+        let mover = match alice_game.current_player().team == alice.team() {
+            true => &mut alice,
+            false => &mut bobby,
+        };
+        mover.send(step).unwrap();
+        let team = mover.team();
+        follow(&alice, &mut alice_game);
+
+        // The host only sends the move to the spectator once the delay is over.
+        let seen = Instant::now();
+        assert!(matches!(watcher.poll(), Ok(None)));
+        let message = loop {
+            let message = watcher.recv().unwrap();
+            if message.to_move().is_some() {
+                break message;
+            }
+        };
+        assert!(seen.elapsed() >= delay / 2_u32);
+        assert_eq!(message.to_move(), Some((team, step, None)));
+        client::apply(&mut watched, &message).unwrap();
+        assert_eq!(
+            snapshot::to_string(&watched).unwrap(),
+            snapshot::to_string(&alice_game).unwrap()
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! # spectator module
+//!
+//! Holds the [`Spectator`] that watches the game of a [`Host`] without a seat. The spectator gets
+//! the same messages as the players, from the lobby to the end of the game, but cannot play any
+//! move. The dice rolls the host reveals are checked like a player would.
+//!
+//! The messages arrive once the spectator delay of the host is over, as the host holds them back
+//! so that the players cannot be told about the game by someone watching it.
+//!
+//! [`Host`]: crate::host::Host
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    client::{connect, read_messages, DiceWitness},
+    Error,
+};
+use fort_builders::game::Game;
+use fort_protocol::{
+    codec::{write_frame, Encoding},
+    message::{ClientMessage, HostMessage},
+};
+use std::{
+    net::{Shutdown, TcpStream, ToSocketAddrs},
+    sync::{
+        mpsc::{Receiver, TryRecvError},
+        Arc, Mutex,
+    },
+};
+
+/// A spectator watching the game of a host.
+#[derive(Debug)]
+pub struct Spectator {
+    /// The stream to the host, shared with the thread reading its messages.
+    stream: Arc<Mutex<TcpStream>>,
+    /// The messages of the host read on a background thread. The thread stops after the first
+    /// error.
+    messages: Receiver<Result<HostMessage, Error>>,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Spectator████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Spectator {
+    /// To watch the game of the host at the given address, with the messages in the encoding.
+    ///
+    /// The seats of the lobby follow as a [`HostMessage::Lobby`] if the game has not started, or
+    /// else the game as a [`HostMessage::Snapshot`].
+    pub fn watch(address: impl ToSocketAddrs, encoding: Encoding) -> Result<Self, Error> {
+        let (mut stream, reader, encoding) = connect(address, encoding)?;
+        write_frame(&mut stream, encoding, &ClientMessage::Watch)?;
+        let stream = Arc::new(Mutex::new(stream));
+        let messages = read_messages(reader, encoding, &stream, DiceWitness::new(None));
+        Ok(Spectator { stream, messages })
+    }
+    /// To watch the game of the host at the given address and wait for the game as the host has
+    /// it. A host still in its lobby is waited on until it starts the game.
+    pub fn follow(address: impl ToSocketAddrs, encoding: Encoding) -> Result<(Self, Game), Error> {
+        let spectator = Spectator::watch(address, encoding)?;
+        loop {
+            match spectator.recv()? {
+                HostMessage::Snapshot { snapshot } => {
+                    let game = Game::try_from(snapshot)?;
+                    return Ok((spectator, game));
+                }
+                HostMessage::Rejected { reason } => return Err(Error::Rejected(reason)),
+                _ => {}
+            }
+        }
+    }
+    /// To get the next message of the host without waiting for it.
+    ///
+    /// Returns `None` if no message has arrived yet, and [`Error::Disconnected`] once the host is
+    /// gone.
+    #[inline]
+    pub fn poll(&self) -> Result<Option<HostMessage>, Error> {
+        match self.messages.try_recv() {
+            Ok(message) => message.map(Some),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(Error::Disconnected),
+        }
+    }
+    /// To wait for the next message of the host.
+    #[inline]
+    pub fn recv(&self) -> Result<HostMessage, Error> {
+        self.messages.recv().map_err(|_| Error::Disconnected)?
+    }
+}
+
+impl Drop for Spectator {
+    /// To close the connection.
+    fn drop(&mut self) {
+        if let Ok(stream) = self.stream.lock() {
+            let _result = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
@@ -0,0 +1,145 @@
+//! # codec module
+//!
+//! To write the messages to a stream and read them back. Each message is sent as a frame: the
+//! length of the encoded message as a big endian `u32` followed by the message itself. The message
+//! is encoded either as compact binary or as `JSON`, which is easier to read while debugging.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::Error;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::io::{ErrorKind, Read, Write};
+
+/// The largest message a frame may hold, in bytes. A snapshot of a game is a few kilobytes.
+pub const MAX_FRAME_LEN: usize = 1_usize << 20_usize;
+/// The number of bytes holding the length of a frame.
+const LEN_PREFIX: usize = 4_usize;
+
+/// How the messages of a connection are encoded.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum Encoding {
+    /// Compact binary.
+    #[default]
+    Binary,
+    /// `JSON` text.
+    Json,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Encoding████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Encoding {
+    /// To encode the message without the length of the frame.
+    #[inline]
+    pub fn encode<M: Serialize>(&self, message: &M) -> Result<Vec<u8>, Error> {
+        Ok(match self {
+            Encoding::Binary => bincode::serialize(message)?,
+            Encoding::Json => serde_json::to_vec(message)?,
+        })
+    }
+    /// To decode a message encoded by [`Encoding::encode`].
+    #[inline]
+    pub fn decode<M: DeserializeOwned>(&self, bytes: &[u8]) -> Result<M, Error> {
+        Ok(match self {
+            Encoding::Binary => bincode::deserialize(bytes)?,
+            Encoding::Json => serde_json::from_slice(bytes)?,
+        })
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Frames████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To write the message as a single frame.
+pub fn write_frame<M: Serialize>(
+    writer: &mut impl Write,
+    encoding: Encoding,
+    message: &M,
+) -> Result<(), Error> {
+    let payload = encoding.encode(message)?;
+    if payload.len() > MAX_FRAME_LEN {
+        return Err(Error::FrameTooLarge(payload.len()));
+    }
+    let mut frame = Vec::with_capacity(LEN_PREFIX + payload.len());
+    frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&payload);
+    writer.write_all(&frame)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// To fill the buffer from the reader, treating the end of the stream as a truncated frame.
+#[inline]
+fn read_all(reader: &mut impl Read, buffer: &mut [u8]) -> Result<(), Error> {
+    reader.read_exact(buffer).map_err(|err| match err.kind() {
+        ErrorKind::UnexpectedEof => Error::Truncated,
+        _ => Error::from(err),
+    })
+}
+
+/// To read the next frame and decode its message, waiting until the whole frame has arrived.
+///
+/// Returns `None` if the other side closed the connection before a new frame began. A connection
+/// closed in the middle of a frame is an error.
+pub fn read_frame<M: DeserializeOwned>(
+    reader: &mut impl Read,
+    encoding: Encoding,
+) -> Result<Option<M>, Error> {
+    let mut len = [0_u8; LEN_PREFIX];
+    if reader.read(&mut len[..1_usize])? == 0_usize {
+        return Ok(None);
+    }
+    read_all(reader, &mut len[1_usize..])?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(Error::FrameTooLarge(len));
+    }
+    let mut payload = vec![0_u8; len];
+    read_all(reader, &mut payload)?;
+    Ok(Some(encoding.decode(&payload)?))
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{ClientMessage, HostMessage};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_frame_cut_short_is_refused() {
+        let mut stream = Vec::new();
+        write_frame(&mut stream, Encoding::Binary, &ClientMessage::Resign).unwrap();
+        stream.pop();
+        assert!(matches!(
+            read_frame::<ClientMessage>(&mut Cursor::new(stream), Encoding::Binary),
+            Err(Error::Truncated)
+        ));
+    }
+
+    #[test]
+    fn test_frame_over_limit_is_not_read() {
+        let stream = ((MAX_FRAME_LEN + 1_usize) as u32).to_be_bytes().to_vec();
+        assert!(matches!(
+            read_frame::<ClientMessage>(&mut Cursor::new(stream), Encoding::Binary),
+            Err(Error::FrameTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn test_message_over_limit_is_not_written() {
+        let reason = "x".repeat(MAX_FRAME_LEN);
+        let mut stream = Vec::new();
+        assert!(matches!(
+            write_frame(
+                &mut stream,
+                Encoding::Json,
+                &HostMessage::Rejected { reason }
+            ),
+            Err(Error::FrameTooLarge(_))
+        ));
+        assert!(stream.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//! # handshake module
+//!
+//! The first frames of a connection. The client offers the version of the protocol it speaks and
+//! the encoding it wants, and the host answers with its own version and the encoding it accepted.
+//! Both sides drop the connection if the versions differ.
+//!
+//! The handshake frames are always encoded as `JSON`, so that a program can read the answer of a
+//! host whatever version and encoding the host uses.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    codec::{read_frame, write_frame, Encoding},
+    Error, PROTOCOL_VERSION,
+};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+/// The encoding of the handshake frames.
+const HANDSHAKE_ENCODING: Encoding = Encoding::Json;
+
+/// The handshake frame sent by each side.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Hello {
+    /// The version of the protocol of the side.
+    pub version: u16,
+    /// The encoding of the messages that follow the handshake.
+    pub encoding: Encoding,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Hello████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Hello {
+    /// To create the frame for the version of this crate.
+    #[inline]
+    pub fn new(encoding: Encoding) -> Self {
+        Hello {
+            version: PROTOCOL_VERSION,
+            encoding,
+        }
+    }
+    /// To check that the other side speaks the version of this crate.
+    #[inline]
+    fn check(&self) -> Result<(), Error> {
+        match self.version == PROTOCOL_VERSION {
+            true => Ok(()),
+            false => Err(Error::VersionMismatch(self.version, PROTOCOL_VERSION)),
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Handshake████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To read the handshake frame of the other side.
+#[inline]
+fn read_hello(reader: &mut impl Read) -> Result<Hello, Error> {
+    read_frame(reader, HANDSHAKE_ENCODING)?.ok_or(Error::Closed)
+}
+
+/// To offer the handshake as a client and wait for the answer of the host.
+///
+/// Returns the encoding of the messages that follow.
+pub fn offer(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+    encoding: Encoding,
+) -> Result<Encoding, Error> {
+    write_frame(writer, HANDSHAKE_ENCODING, &Hello::new(encoding))?;
+    let answer = read_hello(reader)?;
+    answer.check()?;
+    Ok(answer.encoding)
+}
+
+/// To wait for the handshake of a client and answer it as the host.
+///
+/// The host answers with its own version even if the versions differ, so that the client can tell
+/// why it is dropped. Returns the encoding the client asked for.
+pub fn accept(reader: &mut impl Read, writer: &mut impl Write) -> Result<Encoding, Error> {
+    let offer = read_hello(reader)?;
+    write_frame(writer, HANDSHAKE_ENCODING, &Hello::new(offer.encoding))?;
+    offer.check()?;
+    Ok(offer.encoding)
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// To write the hello of the client as the first frame of a connection.
+    fn offered(hello: &Hello) -> Cursor<Vec<u8>> {
+        let mut offer = Vec::new();
+        write_frame(&mut offer, HANDSHAKE_ENCODING, hello).unwrap();
+        Cursor::new(offer)
+    }
+
+    #[test]
+    fn test_both_sides_agree_on_encoding() {
+        let mut answer = Vec::new();
+        let accepted = accept(&mut offered(&Hello::new(Encoding::Binary)), &mut answer).unwrap();
+        assert_eq!(accepted, Encoding::Binary);
+        let offered = offer(&mut Cursor::new(answer), &mut Vec::new(), Encoding::Binary).unwrap();
+        assert_eq!(offered, Encoding::Binary);
+    }
+
+    #[test]
+    fn test_other_version_is_answered_then_rejected() {
+        let hello = Hello {
+            version: PROTOCOL_VERSION + 1_u16,
+            encoding: Encoding::Json,
+        };
+        let mut answer = Vec::new();
+        assert!(matches!(
+            accept(&mut offered(&hello), &mut answer),
+            Err(Error::VersionMismatch(_, PROTOCOL_VERSION))
+        ));
+        assert!(!answer.is_empty());
+    }
+
+    #[test]
+    fn test_connection_closed_before_hello() {
+        assert!(matches!(
+            accept(&mut Cursor::new(Vec::new()), &mut Vec::new()),
+            Err(Error::Closed)
+        ));
+    }
+}
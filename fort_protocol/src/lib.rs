@@ -0,0 +1,270 @@
+//! # main fort protocol module.
+//!
+//! The messages sent between the programs playing a game of fort chess together and how they are
+//! written to a stream. A connection starts with the [`handshake`], which checks that both sides
+//! speak the same [`PROTOCOL_VERSION`] and picks the [`Encoding`] of the messages. The
+//! [`message`]s then follow as length prefixed frames written by the [`codec`].
+//!
+//! [`Encoding`]: codec::Encoding
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+//--------------//
+pub mod codec;
+pub mod handshake;
+pub mod message;
+//--------------//
+
+use fort_builders::{RED, RST};
+use thiserror::Error;
+
+/// The version of the protocol. It is raised whenever a message changes shape, as the binary
+/// encoding cannot read the messages of another version.
+pub const PROTOCOL_VERSION: u16 = 1_u16;
+
+/// Error enum to handle the errors of the protocol.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// To handle the errors of the stream.
+    #[error("{} Ran into a network error: {0} {}", RED, RST)]
+    NetworkError(#[from] std::io::Error),
+    /// If a message could not be read or written as `JSON`.
+    #[error("{} The message is not valid JSON: {0} {}", RED, RST)]
+    InvalidJson(#[from] serde_json::Error),
+    /// If a message could not be read or written as binary.
+    #[error("{} The message is not valid binary: {0} {}", RED, RST)]
+    InvalidBinary(#[from] bincode::Error),
+    /// If a frame is longer than [`codec::MAX_FRAME_LEN`].
+    #[error("{} The frame of {0} bytes is too large. {}", RED, RST)]
+    FrameTooLarge(usize),
+    /// If the stream ended in the middle of a frame.
+    #[error("{} The stream ended in the middle of a frame. {}", RED, RST)]
+    Truncated,
+    /// If the other side speaks another version of the protocol.
+    #[error(
+        "{} The other side speaks version {0} of the protocol instead of version {1}. {}",
+        RED,
+        RST
+    )]
+    VersionMismatch(u16, u16),
+    /// If the other side closed the connection during the handshake.
+    #[error("{} The connection was closed during the handshake. {}", RED, RST)]
+    Closed,
+}
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        codec::{read_frame, write_frame, Encoding},
+        message::{ClientMessage, HostMessage, LobbyRequest, Seat},
+    };
+    use fort_builders::{
+        dice::SeededDice,
+        fairness::{Commitment, DiceProof, Nonce},
+        game::Game,
+        pieces::{Move, MoveKind, PieceType, Position},
+        player::{PlayerKind, Team},
+        ruleset::RuleSet,
+        snapshot::GameSnapshot,
+    };
+    use proptest::{option, prelude::*};
+    use serde::{de::DeserializeOwned, Serialize};
+    use std::{fmt::Debug, io::Cursor};
+
+    fn team() -> impl Strategy<Value = Team> {
+        prop_oneof![
+            Just(Team::Red),
+            Just(Team::Blue),
+            Just(Team::Green),
+            Just(Team::Yellow),
+        ]
+    }
+
+    fn position() -> impl Strategy<Value = Position> {
+        (-8_i32..16_i32, -8_i32..16_i32).prop_map(|(x, y)| Position { x, y })
+    }
+
+    fn piece_type() -> impl Strategy<Value = PieceType> {
+        prop_oneof![
+            Just(PieceType::Rook),
+            Just(PieceType::Minister),
+            Just(PieceType::Queen),
+            Just(PieceType::Pawn),
+            Just(PieceType::Knight),
+        ]
+    }
+
+    fn step() -> impl Strategy<Value = Move> {
+        let kind = prop_oneof![
+            Just(MoveKind::Step),
+            Just(MoveKind::Capture),
+            Just(MoveKind::FortDiceRoll),
+            Just(MoveKind::Skip),
+        ];
+        (position(), position(), kind, option::of(piece_type())).prop_map(
+            |(from, to, kind, promotion)| Move {
+                from,
+                to,
+                kind,
+                promotion,
+            },
+        )
+    }
+
+    fn name() -> impl Strategy<Value = String> {
+        ".{0,24}"
+    }
+
+    fn seat() -> impl Strategy<Value = Seat> {
+        let kind = prop_oneof![Just(PlayerKind::Human), Just(PlayerKind::Computer)];
+        (
+            team(),
+            option::of(name()),
+            kind,
+            any::<bool>(),
+            any::<bool>(),
+        )
+            .prop_map(|(team, name, kind, defender, ready)| Seat {
+                team,
+                name,
+                kind,
+                defender,
+                ready,
+            })
+    }
+
+    fn proof() -> impl Strategy<Value = DiceProof> {
+        (nonce(), any::<u64>(), any::<u64>())
+            .prop_map(|(nonce, host, player)| DiceProof::new(nonce, host, player))
+    }
+
+    fn snapshot() -> impl Strategy<Value = GameSnapshot> {
+        (2_usize..=4_usize, any::<u64>(), 0_usize..8_usize).prop_map(|(count, seed, rolls)| {
+            let names = ["alice", "bobby", "carol", "david"][..count]
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<String>>();
+            let mut game =
+                Game::new_match(names, SeededDice::from_seed(seed), RuleSet::default()).unwrap();
+            (0_usize..rolls).for_each(|_| {
+                game.roll_dice();
+            });
+            GameSnapshot::from(&game)
+        })
+    }
+
+    fn nonce() -> impl Strategy<Value = Nonce> {
+        any::<[u8; 32_usize]>().prop_map(Nonce::from)
+    }
+
+    fn client_message() -> impl Strategy<Value = ClientMessage> {
+        let lobby = prop_oneof![
+            team().prop_map(LobbyRequest::Claim),
+            any::<bool>().prop_map(LobbyRequest::Defender),
+            any::<bool>().prop_map(LobbyRequest::Ready),
+            Just(LobbyRequest::Start),
+        ];
+        prop_oneof![
+            name().prop_map(|name| ClientMessage::Join { name }),
+            Just(ClientMessage::Watch),
+            lobby.prop_map(ClientMessage::Lobby),
+            step().prop_map(ClientMessage::Move),
+            position().prop_map(|at| ClientMessage::DiceRoll { at }),
+            any::<u64>().prop_map(|seed| ClientMessage::DiceSeed { seed }),
+            Just(ClientMessage::Skip),
+            Just(ClientMessage::Resign),
+            Just(ClientMessage::Leave),
+        ]
+    }
+
+    fn host_message() -> impl Strategy<Value = HostMessage> {
+        prop_oneof![
+            team().prop_map(|team| HostMessage::Welcome { team }),
+            (name(), team()).prop_map(|(name, team)| HostMessage::Joined { name, team }),
+            team().prop_map(|team| HostMessage::Left { team }),
+            prop::collection::vec(seat(), 0_usize..4_usize)
+                .prop_map(|seats| HostMessage::Lobby { seats }),
+            snapshot().prop_map(|snapshot| HostMessage::Snapshot { snapshot }),
+            (team(), step()).prop_map(|(team, step)| HostMessage::Moved { team, step }),
+            (team(), position(), nonce(), any::<u64>()).prop_map(|(team, at, nonce, seed)| {
+                HostMessage::DiceCommit {
+                    team,
+                    at,
+                    commitment: Commitment::of(&nonce, seed),
+                }
+            }),
+            (team(), position(), 0_usize..6_usize, option::of(proof())).prop_map(
+                |(team, at, roll, proof)| HostMessage::DiceResult {
+                    team,
+                    at,
+                    roll,
+                    proof,
+                }
+            ),
+            team().prop_map(|team| HostMessage::Skipped { team }),
+            team().prop_map(|team| HostMessage::Resigned { team }),
+            option::of(team()).prop_map(|winner| HostMessage::GameOver { winner }),
+            name().prop_map(|reason| HostMessage::Rejected { reason }),
+        ]
+    }
+
+    fn encoding() -> impl Strategy<Value = Encoding> {
+        prop_oneof![Just(Encoding::Binary), Just(Encoding::Json)]
+    }
+
+    /// To write the messages as frames one after the other and read them back.
+    fn round_trip<M>(encoding: Encoding, messages: &[M])
+    where
+        M: Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        let mut stream = Vec::new();
+        messages
+            .iter()
+            .for_each(|message| write_frame(&mut stream, encoding, message).unwrap());
+        let mut reader = Cursor::new(stream);
+        messages.iter().for_each(|message| {
+            assert_eq!(
+                read_frame::<M>(&mut reader, encoding).unwrap().as_ref(),
+                Some(message)
+            )
+        });
+        assert!(read_frame::<M>(&mut reader, encoding).unwrap().is_none());
+    }
+
+    proptest! {
+        #[test]
+        fn test_client_message_round_trip(
+            encoding in encoding(),
+            messages in prop::collection::vec(client_message(), 1_usize..8_usize),
+        ) {
+            round_trip(encoding, &messages);
+        }
+
+        #[test]
+        fn test_host_message_round_trip(
+            encoding in encoding(),
+            messages in prop::collection::vec(host_message(), 1_usize..8_usize),
+        ) {
+            round_trip(encoding, &messages);
+        }
+
+        #[test]
+        fn test_played_move_round_trip(
+            team in team(),
+            step in step(),
+            roll in option::of(0_usize..6_usize),
+        ) {
+            // The dice roll and the skip only keep the positions the game reads.
+            let expected = match (step.kind, roll) {
+                (MoveKind::Step | MoveKind::Capture, _) => (step, None),
+                (MoveKind::FortDiceRoll, Some(roll)) => (Move::dice_roll(step.from), Some(roll)),
+                _ => (Move::skip(), None),
+            };
+            let message = HostMessage::played(team, step, roll);
+            prop_assert_eq!(message.to_move(), Some((team, expected.0, expected.1)));
+            let request = ClientMessage::from_move(expected.0);
+            prop_assert_eq!(request.to_move(), Some(expected.0));
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! # message module
+//!
+//! Holds the messages sent between a host and its clients. The messages are built on the types of
+//! [`fort_builders`], so a move on the wire is the same [`Move`] the [`Game`] plays.
+//!
+//! Before the game starts the players gather in the [`Lobby`] of the host. They change their
+//! seat with [`ClientMessage::Lobby`] and the host sends every seat with a [`HostMessage::Lobby`]
+//! whenever one changes. The game is sent as a [`HostMessage::Snapshot`] once it starts.
+//!
+//! A spectator follows the game without a seat by sending [`ClientMessage::Watch`] instead of
+//! [`ClientMessage::Join`]. It gets the same messages as the players and cannot send any move.
+//!
+//! A dice roll is drawn by the commit-reveal scheme of the [`fairness`] module. The client asks
+//! for the roll with [`ClientMessage::DiceRoll`], the host answers with a
+//! [`HostMessage::DiceCommit`], the client sends its seed with [`ClientMessage::DiceSeed`] and the
+//! host announces the roll along with its [`DiceProof`] in a [`HostMessage::DiceResult`].
+//!
+//! [`Game`]: fort_builders::game::Game
+//! [`Lobby`]: fort_builders::lobby::Lobby
+//! [`fairness`]: fort_builders::fairness
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use fort_builders::{
+    fairness::{Commitment, DiceProof},
+    pieces::{Move, MoveKind, Position},
+    player::Team,
+    snapshot::GameSnapshot,
+};
+use serde::{Deserialize, Serialize};
+
+pub use fort_builders::lobby::{LobbyRequest, Seat};
+
+/// A message sent by a client to the host.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub enum ClientMessage {
+    /// To ask for a seat in the game under the given name.
+    Join { name: String },
+    /// To follow the game without a seat, as a spectator.
+    Watch,
+    /// To change the seat of the client in the lobby.
+    Lobby(LobbyRequest),
+    /// To ask the host to play the step or the capture for the team of the client.
+    Move(Move),
+    /// To ask the host to roll the dice for the piece at the position.
+    DiceRoll { at: Position },
+    /// The seed of the client for the dice roll the host committed to.
+    DiceSeed { seed: u64 },
+    /// To ask the host to skip the turn of the team of the client.
+    Skip,
+    /// To give up the game.
+    Resign,
+    /// To give the seat back before closing the connection.
+    Leave,
+}
+
+/// A message sent by the host to its clients.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub enum HostMessage {
+    /// The answer to [`ClientMessage::Join`] with the team of the seat given to the client. Sent
+    /// again when the client claims another seat in the lobby.
+    Welcome { team: Team },
+    /// A client took the seat of the team.
+    Joined { name: String, team: Team },
+    /// The client holding the seat of the team left.
+    Left { team: Team },
+    /// The seats of the lobby, sent whenever one of them changes.
+    Lobby { seats: Vec<Seat> },
+    /// The whole game, taken as a [`GameSnapshot`].
+    Snapshot { snapshot: GameSnapshot },
+    /// A step or a capture played by the team.
+    Moved { team: Team, step: Move },
+    /// The host committed to its seed for the dice roll of the team, and waits for the seed of
+    /// the client of the team.
+    DiceCommit {
+        team: Team,
+        at: Position,
+        commitment: Commitment,
+    },
+    /// The dice rolled by the team for the piece at the position. The rolls of the computer
+    /// players are drawn by the host alone and have no proof.
+    DiceResult {
+        team: Team,
+        at: Position,
+        roll: usize,
+        proof: Option<DiceProof>,
+    },
+    /// The team skipped its turn.
+    Skipped { team: Team },
+    /// The team gave up the game.
+    Resigned { team: Team },
+    /// The game is over, with the team of the winner if there is one.
+    GameOver { winner: Option<Team> },
+    /// The answer to a message the host could not accept.
+    Rejected { reason: String },
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████ClientMessage████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl ClientMessage {
+    /// To get the message asking for the move to be played.
+    #[inline]
+    pub fn from_move(step: Move) -> Self {
+        match step.kind {
+            MoveKind::Step | MoveKind::Capture => ClientMessage::Move(step),
+            MoveKind::FortDiceRoll => ClientMessage::DiceRoll { at: step.from },
+            MoveKind::Skip => ClientMessage::Skip,
+        }
+    }
+    /// To get the move the message asks for, if it asks for one.
+    #[inline]
+    pub fn to_move(&self) -> Option<Move> {
+        match self {
+            ClientMessage::Move(step) => Some(*step),
+            ClientMessage::DiceRoll { at } => Some(Move::dice_roll(*at)),
+            ClientMessage::Skip => Some(Move::skip()),
+            _ => None,
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████HostMessage████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl HostMessage {
+    /// To get the message announcing the move played by the team, along with its dice roll.
+    ///
+    /// A dice roll without a roll value, which the game never plays, is announced as a skip. The
+    /// dice roll is announced without a proof.
+    #[inline]
+    pub fn played(team: Team, step: Move, roll: Option<usize>) -> Self {
+        match (step.kind, roll) {
+            (MoveKind::Step | MoveKind::Capture, _) => HostMessage::Moved { team, step },
+            (MoveKind::FortDiceRoll, Some(roll)) => HostMessage::DiceResult {
+                team,
+                at: step.from,
+                roll,
+                proof: None,
+            },
+            _ => HostMessage::Skipped { team },
+        }
+    }
+    /// To get the move the message announces, along with its dice roll, if it announces one.
+    #[inline]
+    pub fn to_move(&self) -> Option<(Team, Move, Option<usize>)> {
+        match self {
+            HostMessage::Moved { team, step } => Some((*team, *step, None)),
+            HostMessage::DiceResult { team, at, roll, .. } => {
+                Some((*team, Move::dice_roll(*at), Some(*roll)))
+            }
+            HostMessage::Skipped { team } => Some((*team, Move::skip(), None)),
+            _ => None,
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
@@ -0,0 +1,138 @@
+//! args module.
+//!
+//! Reads the command line arguments of the simulator. Only the standard library is used, the same
+//! as the command line runner.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::policy::Policy;
+use fort_builders::ruleset::Variant;
+use std::str::FromStr;
+
+/// The usage line printed on an invalid argument.
+pub(crate) const USAGE: &str = "fort_chess-sim [--games <count>] [--players <counts>] \
+[--seed <seed>] [--defender <policy>] [--attackers <policy>] [--max-moves <count>] \
+[--variant <variant>] [--format <csv|json>]";
+/// The number of games played for each player count when not given.
+const DEFAULT_GAMES: usize = 1_000_usize;
+/// The player counts simulated when not given.
+const DEFAULT_PLAYERS: [usize; 3_usize] = [2_usize, 3_usize, 4_usize];
+/// The number of moves after which a game is stopped without a winner when not given.
+const DEFAULT_MAX_MOVES: usize = 1_000_usize;
+/// The fewest players a game can have.
+const MIN_COUNT: usize = 2_usize;
+/// The most players a game can have.
+const MAX_COUNT: usize = 4_usize;
+
+/// The format of the summary.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub(crate) enum Format {
+    /// A header line followed by a line for each player count.
+    #[default]
+    Csv,
+    /// An array with an object for each player count.
+    Json,
+}
+
+/// The arguments of the simulator.
+#[derive(Debug)]
+pub(crate) struct Args {
+    /// The number of games played for each player count.
+    pub(crate) games: usize,
+    /// The player counts to simulate.
+    pub(crate) players: Vec<usize>,
+    /// The seed of the first game. A random seed is used if not given.
+    pub(crate) seed: Option<u64>,
+    /// The way the moves of the defender are picked.
+    pub(crate) defender: Policy,
+    /// The way the moves of the attackers are picked.
+    pub(crate) attackers: Policy,
+    /// The number of moves after which a game is stopped without a winner.
+    pub(crate) max_moves: usize,
+    /// The variant of the rules the games are played with.
+    pub(crate) variant: Variant,
+    /// The format of the summary.
+    pub(crate) format: Format,
+    /// If the usage should be printed instead of simulating.
+    pub(crate) help: bool,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Args████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Default for Args {
+    #[inline]
+    fn default() -> Self {
+        Args {
+            games: DEFAULT_GAMES,
+            players: DEFAULT_PLAYERS.to_vec(),
+            seed: None,
+            defender: Policy::default(),
+            attackers: Policy::default(),
+            max_moves: DEFAULT_MAX_MOVES,
+            variant: Variant::default(),
+            format: Format::default(),
+            help: false,
+        }
+    }
+}
+
+impl Args {
+    /// To read the arguments, without the name of the binary.
+    pub(crate) fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => parsed.help = true,
+                "-g" | "--games" => parsed.games = Args::value(&arg, args.next())?,
+                "-n" | "--players" => parsed.players = Args::counts(&arg, args.next())?,
+                "-s" | "--seed" => parsed.seed = Some(Args::value(&arg, args.next())?),
+                "-d" | "--defender" => parsed.defender = Args::value(&arg, args.next())?,
+                "-a" | "--attackers" => parsed.attackers = Args::value(&arg, args.next())?,
+                "-m" | "--max-moves" => parsed.max_moves = Args::value(&arg, args.next())?,
+                "-v" | "--variant" => parsed.variant = Args::value(&arg, args.next())?,
+                "-f" | "--format" => parsed.format = Args::value(&arg, args.next())?,
+                _ => return Err(format!("Unknown argument '{arg}'.")),
+            }
+        }
+        Ok(parsed)
+    }
+    /// To read the value given after an option.
+    #[inline]
+    fn value<T: FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+        let value = value.ok_or_else(|| format!("The option '{option}' needs a value."))?;
+        value
+            .parse::<T>()
+            .map_err(|_| format!("The value '{value}' of '{option}' is not valid."))
+    }
+    /// To read the player counts given as a comma separated list, like `2,3,4`.
+    fn counts(option: &str, value: Option<String>) -> Result<Vec<usize>, String> {
+        let value = value.ok_or_else(|| format!("The option '{option}' needs a value."))?;
+        value
+            .split(',')
+            .map(|count| match count.trim().parse::<usize>() {
+                Ok(count) if (MIN_COUNT..=MAX_COUNT).contains(&count) => Ok(count),
+                _ => Err(format!(
+                    "The player count '{count}' is not between {MIN_COUNT} and {MAX_COUNT}."
+                )),
+            })
+            .collect::<Result<Vec<usize>, String>>()
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Format████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl FromStr for Format {
+    type Err = String;
+
+    /// To read the format from `csv` or `json`.
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_lowercase().as_str() {
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format '{format}'.")),
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
@@ -0,0 +1,154 @@
+//! A headless simulator for fort chess that plays many games to gather statistics on the balance
+//! of the rules. For instructions to play please read the "README.md" file:
+//! [README](../../../README.md).
+//!
+//! main module.
+//! Plays the games for each player count with seeded dice and prints a summary of the win rates
+//! by role, the length of the games, the pieces captured and how the games ended.
+//!
+//! ## Usage:
+//! ```text
+//! fort_chess-sim [--games <count>] [--players <counts>] [--seed <seed>] [--defender <policy>]
+//!                [--attackers <policy>] [--max-moves <count>] [--variant <variant>]
+//!                [--format <csv|json>]
+//! ```
+//! The player counts are a comma separated list like `2,3,4`. A policy is one of `random`, `ai`,
+//! `easy`, `medium` or `hard`, the last three being the difficulties of the engine. The games of a
+//! player count use the seeds that follow the given seed, so a run can be repeated. The seed is
+//! printed to the standard error. The games are played with the classic rules unless one of the
+//! house variants (`classic`, `storm`, `sortie`, `cavalry` or `promotion`) is given.
+//!
+//! ## Exit status:
+//! | Status | Meaning                              |
+//! |--------|--------------------------------------|
+//! | 0      | The games were played.               |
+//! | 1      | A game could not be played.          |
+//! | 2      | The arguments are not valid.         |
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+/*------------*/
+mod args;
+mod policy;
+mod stats;
+/*------------*/
+
+use args::{Args, Format, USAGE};
+use fort_builders::{
+    dice::SeededDice,
+    game::{Game, GameAction},
+    pieces::MoveKind,
+    Error, RED, RST,
+};
+use stats::{Ending, GameResult, Summary, CSV_HEADER};
+
+/// The exit status when a game could not be played.
+const ERROR_STATUS: i32 = 1_i32;
+/// The exit status when the arguments are not valid.
+const USAGE_STATUS: i32 = 2_i32;
+/// Mixed into the seed of a game to get the seed of its random moves, so that the moves do not
+/// follow the rolls of the dice.
+const RANDOM_SEED_MIX: u64 = 0x5eed_f07c_4e55_d1ce_u64;
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Simulation████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To play a single game to its end, or until it runs out of moves.
+///
+/// The game ends the way the last move played ends it. A dice roll wins at the fort for an
+/// attacker and at the border for the defender. Any other move wins by elimination if the winner
+/// is the only player left, or else by reaching the fort without a roll.
+fn play_game(players: usize, seed: u64, args: &Args) -> Result<GameResult, Error> {
+    let names = (1_usize..=players)
+        .map(|index| format!("player{index}"))
+        .collect::<Vec<String>>();
+    let mut game = Game::new_match(names, SeededDice::from_seed(seed), args.variant.rules())?;
+    let mut random = SeededDice::from_seed(seed ^ RANDOM_SEED_MIX);
+    let mut result = GameResult::default();
+    let mut last_kind = MoveKind::Skip;
+    while game.play && result.moves < args.max_moves {
+        let policy = match game.current_player().is_defender {
+            true => args.defender,
+            false => args.attackers,
+        };
+        let step = policy.choose(&game, &mut random)?;
+        let outcome = game.apply_move(step)?;
+        result.moves += 1_usize;
+        last_kind = step.kind;
+        if let Some(piece) = outcome.captured {
+            result.captures.add(piece.piece_type);
+        }
+        if !game.hunt().is_empty() && game.players.len() == 1_usize {
+            game.set_play_false().current_player_mut().set_winner();
+        }
+    }
+    let winner = game.players.iter().find(|player| player.is_winner);
+    result.defender_won = winner.map(|player| player.is_defender);
+    result.ending = winner.map(|player| match (last_kind, player.is_defender) {
+        (MoveKind::FortDiceRoll, true) => Ending::BorderRoll,
+        (MoveKind::FortDiceRoll, false) => Ending::FortRoll,
+        _ if game.players.len() == 1_usize => Ending::Elimination,
+        _ => Ending::FortReached,
+    });
+    Ok(result)
+}
+
+/// To play the games of a player count and sum them up.
+fn simulate(players: usize, seed: u64, args: &Args) -> Result<Summary, Error> {
+    let mut summary = Summary::from(players, args.variant, args.defender, args.attackers);
+    for game in 0_u64..args.games as u64 {
+        summary.add(&play_game(players, seed.wrapping_add(game), args)?);
+    }
+    Ok(summary)
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Output████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To print the summaries in the format.
+fn print_summaries(summaries: &[Summary], format: Format) -> Result<(), Error> {
+    match format {
+        Format::Csv => {
+            println!("{CSV_HEADER}");
+            summaries
+                .iter()
+                .for_each(|summary| println!("{}", summary.csv_line()));
+        }
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(summaries).map_err(std::io::Error::from)?
+        ),
+    }
+    Ok(())
+}
+
+/// To print the error with the usage line and exit.
+fn usage_error(err: impl std::fmt::Display) -> ! {
+    eprintln!("{err}");
+    eprintln!("{RED}USAGE:{RST} {USAGE}");
+    std::process::exit(USAGE_STATUS);
+}
+
+/// Main entry function.
+fn main() {
+    let args = Args::parse(std::env::args().skip(1_usize)).unwrap_or_else(|err| usage_error(err));
+    if args.help {
+        println!("{USAGE}");
+        return;
+    }
+    let seed = args
+        .seed
+        .unwrap_or_else(|| SeededDice::from_entropy().seed());
+    eprintln!("Seed: {seed}");
+    let summaries = args
+        .players
+        .iter()
+        .map(|&players| simulate(players, seed, &args))
+        .collect::<Result<Vec<Summary>, Error>>()
+        .and_then(|summaries| print_summaries(&summaries, args.format));
+    if let Err(err) = summaries {
+        eprintln!("{err}");
+        std::process::exit(ERROR_STATUS);
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
@@ -0,0 +1,126 @@
+//! policy module.
+//!
+//! Holds the ways the simulator can pick the moves of a player. A random player picks any of its
+//! legal moves, the [`ai`] player picks the move with its heuristic and the engine searches at the
+//! given [`Difficulty`].
+//!
+//! [`ai`]: fort_builders::ai
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use fort_builders::{
+    ai::choose_move,
+    dice::SeededDice,
+    game::Game,
+    pieces::{Move, MoveKind},
+    rules::GameRules,
+    Error,
+};
+use fort_engine::{difficulty::Difficulty, search::search_board};
+use std::{fmt, str::FromStr};
+
+/// The way the moves of a player are picked.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub(crate) enum Policy {
+    /// Any legal move, each being equally likely. A skip is only played when there is no other
+    /// move.
+    #[default]
+    Random,
+    /// The move of the heuristic computer player.
+    Ai,
+    /// The move found by the engine at the difficulty.
+    Engine(Difficulty),
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Policy████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Policy {
+    /// To pick the move of the player whose turn it is.
+    ///
+    /// The random moves are picked with the given dice so that the same seed plays the same game.
+    pub(crate) fn choose(&self, game: &Game, random: &mut SeededDice) -> Result<Move, Error> {
+        match self {
+            Policy::Random => {
+                let moves = game
+                    .all_legal_moves()
+                    .into_iter()
+                    .filter(|step| step.kind != MoveKind::Skip)
+                    .collect::<Vec<Move>>();
+                Ok(match moves.is_empty() {
+                    true => Move::skip(),
+                    false => moves[random.pick(moves.len())],
+                })
+            }
+            Policy::Ai => Ok(choose_move(game)),
+            Policy::Engine(difficulty) => Ok(difficulty.search(search_board(game)?)?.step),
+        }
+    }
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    /// To read the policy from `random`, `ai`, `easy`, `medium` or `hard`.
+    fn from_str(policy: &str) -> Result<Self, Self::Err> {
+        match policy.to_lowercase().as_str() {
+            "random" => Ok(Policy::Random),
+            "ai" => Ok(Policy::Ai),
+            "easy" => Ok(Policy::Engine(Difficulty::Easy)),
+            "medium" => Ok(Policy::Engine(Difficulty::Medium)),
+            "hard" => Ok(Policy::Engine(Difficulty::Hard)),
+            _ => Err(format!("Unknown policy '{policy}'.")),
+        }
+    }
+}
+
+impl fmt::Display for Policy {
+    #[doc(hidden)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Policy::Random => write!(f, "random"),
+            Policy::Ai => write!(f, "ai"),
+            Policy::Engine(difficulty) => write!(f, "{}", difficulty.to_string().to_lowercase()),
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fort_builders::ruleset::RuleSet;
+
+    #[test]
+    fn test_policy_reads_back_its_name() {
+        let policies = [
+            Policy::Random,
+            Policy::Ai,
+            Policy::Engine(Difficulty::Easy),
+            Policy::Engine(Difficulty::Medium),
+            Policy::Engine(Difficulty::Hard),
+        ];
+        for policy in policies {
+            assert_eq!(policy.to_string().parse::<Policy>(), Ok(policy));
+        }
+        assert!("perfect".parse::<Policy>().is_err());
+    }
+
+    #[test]
+    fn test_random_move_is_legal_and_seeded() {
+        let names = vec![String::from("alice"), String::from("bobby")];
+        let game =
+            Game::new_match(names, SeededDice::from_seed(7_u64), RuleSet::default()).unwrap();
+        let step = Policy::Random
+            .choose(&game, &mut SeededDice::from_seed(3_u64))
+            .unwrap();
+        assert!(game.all_legal_moves().contains(&step));
+        assert_ne!(step.kind, MoveKind::Skip);
+        let again = Policy::Random
+            .choose(&game, &mut SeededDice::from_seed(3_u64))
+            .unwrap();
+        assert_eq!(step, again);
+    }
+}
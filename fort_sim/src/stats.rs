@@ -0,0 +1,293 @@
+//! stats module.
+//!
+//! Holds the result of a single simulated game and the [`Summary`] of all the games played with
+//! the same number of players. The summary is written as a line of CSV or as a JSON object.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::policy::Policy;
+use fort_builders::{pieces::PieceType, ruleset::Variant};
+use serde::Serialize;
+
+/// The header of the CSV summary, in the order of the fields of [`Summary::csv_line`].
+pub(crate) const CSV_HEADER: &str = "players,variant,games,defender_policy,attackers_policy,\
+defender_wins,attacker_wins,unfinished,defender_win_rate,attacker_win_rate,average_moves,\
+fort_rolls,border_rolls,eliminations,fort_reached,captured_rook,captured_minister,captured_queen,\
+captured_pawn,captured_knight";
+
+/// How a game ended.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum Ending {
+    /// An attacker rolled the winning roll at the fort.
+    FortRoll,
+    /// The defender rolled the winning roll at the border of an attacker.
+    BorderRoll,
+    /// Every other player lost all of its pieces.
+    Elimination,
+    /// An attacker reached the fort with rules that do not make it roll.
+    FortReached,
+}
+
+/// The number of pieces captured of each [`PieceType`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Serialize)]
+pub(crate) struct Captures {
+    /// The number of rooks captured.
+    pub(crate) rook: usize,
+    /// The number of ministers captured.
+    pub(crate) minister: usize,
+    /// The number of queens captured.
+    pub(crate) queen: usize,
+    /// The number of pawns captured.
+    pub(crate) pawn: usize,
+    /// The number of knights captured.
+    pub(crate) knight: usize,
+}
+
+/// The result of a single game.
+#[derive(Debug, Default)]
+pub(crate) struct GameResult {
+    /// If the defender won, or `None` if the game was stopped without a winner.
+    pub(crate) defender_won: Option<bool>,
+    /// How the game ended, or `None` if it was stopped without a winner.
+    pub(crate) ending: Option<Ending>,
+    /// The number of moves played.
+    pub(crate) moves: usize,
+    /// The pieces captured during the game.
+    pub(crate) captures: Captures,
+}
+
+/// The summary of the games played with the same number of players.
+#[derive(Debug, Serialize)]
+pub(crate) struct Summary {
+    /// The number of players of each game.
+    players: usize,
+    /// The variant of the rules the games were played with.
+    variant: String,
+    /// The number of games played.
+    games: usize,
+    /// The way the moves of the defender were picked.
+    defender_policy: String,
+    /// The way the moves of the attackers were picked.
+    attackers_policy: String,
+    /// The number of games won by the defender.
+    defender_wins: usize,
+    /// The number of games won by an attacker.
+    attacker_wins: usize,
+    /// The number of games stopped without a winner.
+    unfinished: usize,
+    /// The share of the games won by the defender.
+    defender_win_rate: f64,
+    /// The share of the games won by an attacker.
+    attacker_win_rate: f64,
+    /// The average number of moves of a game.
+    average_moves: f64,
+    /// The number of games won by a roll at the fort.
+    fort_rolls: usize,
+    /// The number of games won by a roll at the border.
+    border_rolls: usize,
+    /// The number of games won by eliminating every other player.
+    eliminations: usize,
+    /// The number of games won by reaching the fort without a roll.
+    fort_reached: usize,
+    /// The pieces captured over all the games.
+    captures: Captures,
+    /// The moves played over all the games.
+    #[serde(skip)]
+    total_moves: usize,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Captures████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Captures {
+    /// To count a captured piece.
+    #[inline]
+    pub(crate) fn add(&mut self, piece_type: PieceType) {
+        match piece_type {
+            PieceType::Rook => self.rook += 1_usize,
+            PieceType::Minister => self.minister += 1_usize,
+            PieceType::Queen => self.queen += 1_usize,
+            PieceType::Pawn => self.pawn += 1_usize,
+            PieceType::Knight => self.knight += 1_usize,
+        }
+    }
+    /// To add the captures of another game.
+    #[inline]
+    fn merge(&mut self, other: &Captures) {
+        self.rook += other.rook;
+        self.minister += other.minister;
+        self.queen += other.queen;
+        self.pawn += other.pawn;
+        self.knight += other.knight;
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Summary████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Summary {
+    /// To create an empty summary for the player count, the variant and the policies.
+    #[inline]
+    pub(crate) fn from(
+        players: usize,
+        variant: Variant,
+        defender: Policy,
+        attackers: Policy,
+    ) -> Self {
+        Summary {
+            players,
+            variant: variant.to_string().to_lowercase(),
+            games: 0_usize,
+            defender_policy: defender.to_string(),
+            attackers_policy: attackers.to_string(),
+            defender_wins: 0_usize,
+            attacker_wins: 0_usize,
+            unfinished: 0_usize,
+            defender_win_rate: 0_f64,
+            attacker_win_rate: 0_f64,
+            average_moves: 0_f64,
+            fort_rolls: 0_usize,
+            border_rolls: 0_usize,
+            eliminations: 0_usize,
+            fort_reached: 0_usize,
+            captures: Captures::default(),
+            total_moves: 0_usize,
+        }
+    }
+    /// To add the result of a game, updating the rates and the average.
+    pub(crate) fn add(&mut self, result: &GameResult) {
+        self.games += 1_usize;
+        match result.defender_won {
+            Some(true) => self.defender_wins += 1_usize,
+            Some(false) => self.attacker_wins += 1_usize,
+            None => self.unfinished += 1_usize,
+        }
+        match result.ending {
+            Some(Ending::FortRoll) => self.fort_rolls += 1_usize,
+            Some(Ending::BorderRoll) => self.border_rolls += 1_usize,
+            Some(Ending::Elimination) => self.eliminations += 1_usize,
+            Some(Ending::FortReached) => self.fort_reached += 1_usize,
+            None => {}
+        }
+        self.captures.merge(&result.captures);
+        self.total_moves += result.moves;
+        let games = self.games as f64;
+        self.defender_win_rate = self.defender_wins as f64 / games;
+        self.attacker_win_rate = self.attacker_wins as f64 / games;
+        self.average_moves = self.total_moves as f64 / games;
+    }
+    /// To write the summary as a line of CSV, in the order of the [`CSV_HEADER`].
+    pub(crate) fn csv_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{:.4},{:.4},{:.2},{},{},{},{},{},{},{},{},{}",
+            self.players,
+            self.variant,
+            self.games,
+            self.defender_policy,
+            self.attackers_policy,
+            self.defender_wins,
+            self.attacker_wins,
+            self.unfinished,
+            self.defender_win_rate,
+            self.attacker_win_rate,
+            self.average_moves,
+            self.fort_rolls,
+            self.border_rolls,
+            self.eliminations,
+            self.fort_reached,
+            self.captures.rook,
+            self.captures.minister,
+            self.captures.queen,
+            self.captures.pawn,
+            self.captures.knight,
+        )
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{args::Args, simulate};
+
+    /// To sum up a defender win at the border, an attacker win at the fort and a stopped game.
+    fn summary() -> Summary {
+        let mut summary = Summary::from(2_usize, Variant::Classic, Policy::Random, Policy::Ai);
+        let mut captures = Captures::default();
+        captures.add(PieceType::Pawn);
+        summary.add(&GameResult {
+            defender_won: Some(true),
+            ending: Some(Ending::BorderRoll),
+            moves: 10_usize,
+            captures,
+        });
+        let mut captures = Captures::default();
+        captures.add(PieceType::Knight);
+        captures.add(PieceType::Rook);
+        summary.add(&GameResult {
+            defender_won: Some(false),
+            ending: Some(Ending::FortRoll),
+            moves: 20_usize,
+            captures,
+        });
+        summary.add(&GameResult {
+            moves: 30_usize,
+            ..GameResult::default()
+        });
+        summary
+    }
+
+    #[test]
+    fn test_csv_line_follows_header() {
+        let line = summary().csv_line();
+        assert_eq!(
+            line,
+            "2,classic,3,random,ai,1,1,1,0.3333,0.3333,20.00,1,1,0,0,1,0,0,1,1"
+        );
+        assert_eq!(line.split(',').count(), CSV_HEADER.split(',').count());
+    }
+
+    #[test]
+    fn test_json_row_leaves_out_total_moves() {
+        let row = serde_json::to_value(summary()).unwrap();
+        assert_eq!(row["variant"], "classic");
+        assert_eq!(row["unfinished"], 1_usize);
+        assert_eq!(row["average_moves"], 20_f64);
+        assert_eq!(row["captures"]["knight"], 1_usize);
+        assert!(row.get("total_moves").is_none());
+    }
+
+    #[test]
+    fn test_seeded_batch_adds_up() {
+        let args = [
+            "--games",
+            "4",
+            "--players",
+            "2",
+            "--seed",
+            "7",
+            "--max-moves",
+            "200",
+        ];
+        let args = Args::parse(args.into_iter().map(String::from)).unwrap();
+        let summary = simulate(2_usize, 7_u64, &args).unwrap();
+        assert_eq!(summary.games, 4_usize);
+        assert_eq!(
+            summary.defender_wins + summary.attacker_wins + summary.unfinished,
+            summary.games
+        );
+        assert_eq!(
+            summary.fort_rolls + summary.border_rolls + summary.eliminations + summary.fort_reached,
+            summary.defender_wins + summary.attacker_wins
+        );
+        assert!(summary.total_moves <= summary.games * args.max_moves);
+        assert_eq!(
+            summary.average_moves,
+            summary.total_moves as f64 / summary.games as f64
+        );
+        let again = simulate(2_usize, 7_u64, &args).unwrap();
+        assert_eq!(summary.csv_line(), again.csv_line());
+    }
+}
@@ -0,0 +1,446 @@
+//! app module.
+//!
+//! Holds the state of the terminal game and turns the key presses into moves on the [`Game`].
+//!
+//! ## Keys:
+//! | Key               | Action                                                  |
+//! |-------------------|---------------------------------------------------------|
+//! | Arrow keys        | Move the cursor.                                        |
+//! | Enter / Space     | Pick the piece under the cursor or move the picked one. |
+//! | `d`               | Roll the dice with the picked piece at the fort.        |
+//! | `s`               | Skip the turn.                                          |
+//! | `u` / `r`         | Undo / redo the last move. Not in a network game.       |
+//! | `:`               | Type a square (`1c4`) or a move (`P1b4-1c4`).           |
+//! | `q` / Esc         | Quit.                                                   |
+//!
+//! A pawn that is promoted by moving it with the cursor becomes a queen. To promote it to another
+//! type, type the move with the type at the end, like `P1e4-1f4=R`.
+//!
+//! In a network game the moves are sent to the host, and the board only changes once the host
+//! announces the moves it played. The pieces can only be picked on the turn of the local team.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use fort_builders::{
+    board::{position_in_board_bounds, BOARD_LAYOUT},
+    game::{Game, GameAction},
+    notation::{parse_square, RecordedMove},
+    pieces::{Move, MoveKind, MoveOutcome, Position},
+    player::{PlayerAction, Team},
+    rules::GameRules,
+};
+use fort_net::client::{self, Client};
+use fort_protocol::message::HostMessage;
+
+/// The state of the terminal game.
+pub(crate) struct App {
+    /// The game being played.
+    pub(crate) game: Game,
+    /// The position of the cursor on the board.
+    pub(crate) cursor: Position,
+    /// The moves the picked piece can make. Empty if no piece is picked.
+    pub(crate) paths: Vec<Move>,
+    /// The text typed after `:`, if the players are typing.
+    pub(crate) typed: Option<String>,
+    /// The message shown under the board.
+    pub(crate) message: String,
+    /// If the game loop should keep running.
+    pub(crate) running: bool,
+    /// The connection to the host, if the game is played over the network.
+    client: Option<Client>,
+}
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████App████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl App {
+    /// To create the app state for a new game.
+    ///
+    /// The cursor starts on the first piece of the player whose turn it is. The moves are sent to
+    /// the host if there is a client.
+    pub(crate) fn new(game: Game, client: Option<Client>) -> Self {
+        let cursor = game
+            .current_player()
+            .pieces
+            .first()
+            .map(|piece| piece.position)
+            .unwrap_or_else(|| {
+                let bounds = BOARD_LAYOUT.bounds();
+                Position {
+                    x: bounds.x_min,
+                    y: bounds.y_min,
+                }
+            });
+        App {
+            game,
+            cursor,
+            paths: Vec::new(),
+            typed: None,
+            message: String::from("Press ':' to type a square or a move, 'q' to quit."),
+            running: true,
+            client,
+        }
+    }
+    /// To get the team played at this terminal in a network game.
+    #[inline]
+    pub(crate) fn local_team(&self) -> Option<Team> {
+        self.client.as_ref().map(Client::team)
+    }
+    /// To check if the player at the terminal can play the current turn.
+    #[inline]
+    fn is_local_turn(&self) -> bool {
+        self.local_team()
+            .map_or(true, |team| team == self.game.current_player().team)
+    }
+    /// To play the moves announced by the host and show the other messages of the host.
+    pub(crate) fn receive(&mut self) {
+        loop {
+            let message = match self.client.as_ref().map(|client| client.poll()) {
+                Some(Ok(Some(message))) => message,
+                Some(Err(err)) => {
+                    self.message = err.to_string();
+                    return;
+                }
+                _ => return,
+            };
+            let player = self.game.current_player().name.clone();
+            self.message = match client::apply(&mut self.game, &message) {
+                Ok(Some(outcome)) => {
+                    self.unpick();
+                    let _dead = self.game.hunt();
+                    match message {
+                        HostMessage::Resigned { team } => self.describe_resignation(team, &outcome),
+                        _ => self.describe(&player, &outcome),
+                    }
+                }
+                Err(err) => err.to_string(),
+                Ok(None) => match message {
+                    HostMessage::Joined { name, team } => {
+                        format!("{name} joined as {}.", Team::teamstr_from_team(team))
+                    }
+                    HostMessage::Left { team } => {
+                        format!("{} left the game.", Team::teamstr_from_team(team))
+                    }
+                    HostMessage::Rejected { reason } => format!("The host refused: {reason}."),
+                    _ => continue,
+                },
+            };
+        }
+    }
+    /// To get the position of the picked piece, if any.
+    #[inline]
+    pub(crate) fn picked(&self) -> Option<Position> {
+        match self.game.picked {
+            true => self
+                .game
+                .current_player()
+                .current_chosen_piece()
+                .ok()
+                .map(|piece| piece.position),
+            false => None,
+        }
+    }
+    /// To handle a single key press.
+    pub(crate) fn key_press(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.running = false;
+            return;
+        }
+        if self.typed.is_some() {
+            self.type_key(key.code);
+            return;
+        }
+        match key.code {
+            KeyCode::Up => self.move_cursor(0_i32, 1_i32),
+            KeyCode::Down => self.move_cursor(0_i32, -1_i32),
+            KeyCode::Left => self.move_cursor(-1_i32, 0_i32),
+            KeyCode::Right => self.move_cursor(1_i32, 0_i32),
+            KeyCode::Enter | KeyCode::Char(' ') => self.click(self.cursor),
+            KeyCode::Char('d') => self.dice_roll(),
+            KeyCode::Char('s') => self.play(Move::skip()),
+            KeyCode::Char('u') => self.undo(),
+            KeyCode::Char('r') => self.redo(),
+            KeyCode::Char(':') => self.typed = Some(String::new()),
+            KeyCode::Char('q') | KeyCode::Esc => self.running = false,
+            _ => {}
+        }
+    }
+    /// To move the cursor, skipping over the squares that are not on the board.
+    fn move_cursor(&mut self, dx: i32, dy: i32) {
+        let (mut x, mut y) = (self.cursor.x + dx, self.cursor.y + dy);
+        let bounds = BOARD_LAYOUT.bounds();
+        while bounds.contains(x, y) {
+            if position_in_board_bounds(x, y) {
+                self.cursor = Position { x, y };
+                return;
+            }
+            x += dx;
+            y += dy;
+        }
+    }
+    /// To handle a key press while the players are typing.
+    fn type_key(&mut self, code: KeyCode) {
+        let typed = match self.typed.as_mut() {
+            Some(typed) => typed,
+            None => return,
+        };
+        match code {
+            KeyCode::Char(ch) => typed.push(ch),
+            KeyCode::Backspace => {
+                typed.pop();
+            }
+            KeyCode::Esc => self.typed = None,
+            KeyCode::Enter => {
+                if let Some(typed) = self.typed.take() {
+                    self.submit(typed.trim());
+                }
+            }
+            _ => {}
+        }
+    }
+    /// To handle the typed text as a square to click or as a move to play.
+    fn submit(&mut self, typed: &str) {
+        if let Ok(position) = parse_square(typed) {
+            self.cursor = position;
+            self.click(position);
+            return;
+        }
+        match typed.parse::<RecordedMove>() {
+            Ok(recorded) => {
+                self.unpick();
+                self.play(recorded.step);
+            }
+            Err(err) => self.message = err.to_string(),
+        }
+    }
+    /// To pick the piece at the position or to move the picked piece to it.
+    ///
+    /// Works the same as clicking on the board in the game window, except that a promoted pawn
+    /// becomes a queen without asking.
+    fn click(&mut self, position: Position) {
+        if !self.is_local_turn() {
+            self.message = format!("Waiting for {} to play.", self.game.current_player().name);
+            return;
+        }
+        if self.game.picked {
+            let step = self.paths.iter().find(|step| step.to == position).copied();
+            self.unpick();
+            if let Some(step) = step {
+                self.play(step);
+            }
+            return;
+        }
+        let index = match self
+            .game
+            .current_player()
+            .piece_index_from_xy_i32(position.x, position.y)
+        {
+            Ok(index) => index,
+            Err(_) => return,
+        };
+        self.game.current_player_mut().set_chosen_piece_index(index);
+        self.game.set_picked_true();
+        self.paths = self
+            .game
+            .legal_moves(self.game.turn, index)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|step| matches!(step.kind, MoveKind::Step | MoveKind::Capture))
+            .collect::<Vec<Move>>();
+    }
+    /// To drop the picked piece.
+    #[inline]
+    fn unpick(&mut self) {
+        self.game.set_picked_false();
+        self.paths.clear();
+    }
+    /// To roll the dice with the picked piece if it has reached the opposite side.
+    fn dice_roll(&mut self) {
+        let at = match self.picked() {
+            Some(at) if self.game.current_player().in_opposite_side() => at,
+            _ => {
+                self.message = String::from("Pick a piece on the opposite side to roll the dice.");
+                return;
+            }
+        };
+        self.unpick();
+        self.play(Move::dice_roll(at));
+    }
+    /// To play a move for the current player and show what happened.
+    ///
+    /// In a network game the move is sent to the host instead.
+    fn play(&mut self, step: Move) {
+        if let Some(client) = self.client.as_mut() {
+            self.message = match client.send(step) {
+                Ok(()) => String::from("Sent the move to the host."),
+                Err(err) => err.to_string(),
+            };
+            return;
+        }
+        let player = self.game.current_player().name.clone();
+        match self.game.apply_move(step) {
+            Ok(outcome) => {
+                let _dead = self.game.hunt();
+                self.message = self.describe(&player, &outcome);
+            }
+            Err(err) => self.message = err.to_string(),
+        }
+    }
+    /// To take back the last move.
+    fn undo(&mut self) {
+        if self.client.is_some() {
+            self.message = String::from("Moves cannot be taken back in a network game.");
+            return;
+        }
+        self.unpick();
+        self.message = match self.game.undo() {
+            Ok(Some(_)) => String::from("Undid the last move."),
+            Ok(None) => String::from("There is nothing to undo."),
+            Err(err) => err.to_string(),
+        };
+    }
+    /// To play again the last move that was undone.
+    fn redo(&mut self) {
+        if self.client.is_some() {
+            self.message = String::from("Moves cannot be taken back in a network game.");
+            return;
+        }
+        self.unpick();
+        let player = self.game.current_player().name.clone();
+        self.message = match self.game.redo() {
+            Ok(Some(outcome)) => self.describe(&player, &outcome),
+            Ok(None) => String::from("There is nothing to redo."),
+            Err(err) => err.to_string(),
+        };
+    }
+    /// To describe the outcome of a move played by the player.
+    fn describe(&self, player: &str, outcome: &MoveOutcome) -> String {
+        let mut message = match outcome.roll {
+            Some(roll) => format!("{player} rolled {}.", roll + 1_usize),
+            None => format!("{player} moved."),
+        };
+        if let Some(piece) = outcome.captured {
+            let piece_type = format!("{:?}", piece.piece_type);
+            message.push_str(&format!(" Captured a {}.", piece_type.trim_end()));
+        }
+        if let Some(team) = outcome.eliminated {
+            message.push_str(&format!(" {} is out.", Team::teamstr_from_team(team)));
+        }
+        if outcome.winner {
+            message.push_str(&format!(" {player} wins!"));
+        } else if outcome.roll.is_some() {
+            message.push_str(&format!(
+                " A {} is needed to win.",
+                self.game.rules.winning_roll + 1_usize
+            ));
+        }
+        message
+    }
+    /// To describe the resignation of the team for the message line.
+    fn describe_resignation(&self, team: Team, outcome: &MoveOutcome) -> String {
+        let mut message = format!("{} resigned.", Team::teamstr_from_team(team));
+        if outcome.winner {
+            let winner = &self.game.current_player().name;
+            message.push_str(&format!(" {winner} wins!"));
+        }
+        message
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Tests██████████████████████████████████████████████████████████████████████████████████████*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// To start the app at the position, where the blue pawn on `1e2` plays next.
+    fn app() -> App {
+        App::new(
+            Game::from_position_str("RD=alice:N1f4/B1=bobby:P1e2 B 2").unwrap(),
+            None,
+        )
+    }
+
+    /// To press the keys one after the other.
+    fn press(app: &mut App, codes: &[KeyCode]) {
+        codes
+            .iter()
+            .for_each(|&code| app.key_press(KeyEvent::new(code, KeyModifiers::NONE)));
+    }
+
+    /// To type the text after `:` and submit it.
+    fn type_text(app: &mut App, text: &str) {
+        press(app, &[KeyCode::Char(':')]);
+        press(
+            app,
+            &text.chars().map(KeyCode::Char).collect::<Vec<KeyCode>>(),
+        );
+        press(app, &[KeyCode::Enter]);
+    }
+
+    /// To read the square into a position.
+    fn at(square: &str) -> Position {
+        parse_square(square).unwrap()
+    }
+
+    #[test]
+    fn test_cursor_starts_on_piece_to_play() {
+        assert_eq!(app().cursor, at("1e2"));
+    }
+
+    #[test]
+    fn test_cursor_skips_over_fort() {
+        let mut app = app();
+        press(&mut app, &[KeyCode::Right]);
+        assert_eq!(app.cursor, at("1f2"));
+        press(&mut app, &[KeyCode::Right]);
+        assert_eq!(app.cursor, at("3a2"));
+    }
+
+    #[test]
+    fn test_cursor_stays_at_edge_of_board() {
+        let mut app = app();
+        press(&mut app, &[KeyCode::Down, KeyCode::Down]);
+        assert_eq!(app.cursor, at("1e1"));
+    }
+
+    #[test]
+    fn test_picking_piece_shows_its_moves() {
+        let mut app = app();
+        press(&mut app, &[KeyCode::Enter]);
+        assert_eq!(app.picked(), Some(at("1e2")));
+        assert!(app
+            .paths
+            .contains(&Move::new(at("1e2"), at("1f2"), MoveKind::Step)));
+        press(&mut app, &[KeyCode::Char('q')]);
+        assert!(!app.running);
+    }
+
+    #[test]
+    fn test_moving_picked_piece_plays_it() {
+        let mut app = app();
+        press(&mut app, &[KeyCode::Enter, KeyCode::Right, KeyCode::Enter]);
+        assert_eq!(app.picked(), None);
+        assert!(app.paths.is_empty());
+        let (from, to) = (at("1e2"), at("1f2"));
+        assert!(!app.game.check_piece_in_pos(from.x, from.y));
+        assert!(app.game.check_piece_in_pos(to.x, to.y));
+        assert_eq!(app.game.current_player().team, Team::Red);
+        assert_eq!(app.message, "bobby moved.");
+    }
+
+    #[test]
+    fn test_typed_move_is_played_and_undone() {
+        let mut app = app();
+        type_text(&mut app, "P1e2-1f2");
+        assert_eq!(app.game.current_player().team, Team::Red);
+        press(&mut app, &[KeyCode::Char('u')]);
+        assert_eq!(app.game.current_player().team, Team::Blue);
+        assert_eq!(
+            app.game.current_player().pieces[0_usize].position,
+            at("1e2")
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! A terminal version of fort chess that does not need a GPU. For instructions to play please read
+//! the "README.md" file: [README](../../../README.md).
+//!
+//! main module.
+//! Sets up the terminal, reads the player names from the arguments and runs the game loop.
+//!
+//! ## Usage:
+//! ```text
+//! fort_chess_tui <name> <name> [name] [name]
+//! fort_chess_tui --host <address> <name> <name> [name] [name]
+//! fort_chess_tui --join <address> <name>
+//! ```
+//! With `--host` the game is hosted on the address, like `0.0.0.0:7878`, and the first name is
+//! played at this terminal. The other players join with `--join` and the address of the host,
+//! getting the seat of their name or else the first free one. The record of a network game is
+//! printed once the terminal is given back, along with the proofs of its dice rolls.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+/*------------*/
+mod app;
+mod render;
+/*------------*/
+
+use app::App;
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{poll, read, Event, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use fort_builders::{
+    dice::SeededDice, game::Game, notation::GameRecord, ruleset::RuleSet, Error, RED, RST,
+};
+use fort_net::{client::Client, host::Host};
+use fort_protocol::codec::Encoding;
+use std::{
+    io::{self, Stdout},
+    time::Duration,
+};
+
+/// How often the game loop looks for the messages of the host while no key is pressed.
+const POLL_INTERVAL: Duration = Duration::from_millis(100_u64);
+/// The usage printed when the arguments are not valid.
+const USAGE: &str = "fort_chess_tui <name> <name> [name] [name]\n       \
+                     fort_chess_tui --host <address> <name> <name> [name] [name]\n       \
+                     fort_chess_tui --join <address> <name>";
+
+/// To hold the terminal while the game is running and give it back once dropped.
+///
+/// Dropping the guard also restores the terminal when the game panics.
+struct TerminalGuard(Stdout);
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████TerminalGuard████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl TerminalGuard {
+    /// To switch the terminal to raw mode on the alternate screen.
+    fn enter() -> io::Result<Self> {
+        let mut stdout = io::stdout();
+        enable_raw_mode()?;
+        execute!(stdout, EnterAlternateScreen, Hide)?;
+        Ok(TerminalGuard(stdout))
+    }
+    /// To get the terminal output.
+    #[inline]
+    fn out(&mut self) -> &mut Stdout {
+        &mut self.0
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _result = execute!(self.0, Show, LeaveAlternateScreen);
+        let _result = disable_raw_mode();
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Game Loop████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To draw the game and handle the key presses and the messages of the host until the players
+/// quit. Returns the game as it was left.
+fn run(game: Game, client: Option<Client>) -> io::Result<Game> {
+    let mut terminal = TerminalGuard::enter()?;
+    let mut app = App::new(game, client);
+    while app.running {
+        render::draw(terminal.out(), &app)?;
+        if poll(POLL_INTERVAL)? {
+            if let Event::Key(key) = read()? {
+                if key.kind != KeyEventKind::Release {
+                    app.key_press(key);
+                }
+            }
+        }
+        app.receive();
+    }
+    Ok(app.game)
+}
+
+/// To print the record of the game so that its dice rolls can be checked afterwards.
+fn print_record(game: &Game) {
+    match GameRecord::from_game(game) {
+        Ok(record) => print!("{record}"),
+        Err(err) => eprintln!("{err}"),
+    }
+}
+
+/// To print the error along with the usage and exit.
+fn usage_error(err: impl std::fmt::Display) -> ! {
+    eprintln!("{err}");
+    eprintln!("{RED}USAGE:{RST} {USAGE}");
+    std::process::exit(2_i32);
+}
+
+/// To create the game for the player names, exiting if the names are not valid.
+fn new_match(names: Vec<String>) -> Game {
+    match Game::new_match(names, SeededDice::from_entropy(), RuleSet::default()) {
+        Ok(game) => game,
+        Err(err @ Error::TooFewPlayers(_)) => usage_error(err),
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(2_i32);
+        }
+    }
+}
+
+/// To start the game the arguments ask for.
+///
+/// A hosted game is joined by the first player like any other client, so that the terminal plays
+/// the same way whether it hosts or joins.
+fn start(mut args: Vec<String>) -> Result<(Game, Option<Client>), fort_net::Error> {
+    match args.first().map(String::as_str) {
+        Some("--host") if args.len() > 2_usize => {
+            let names = args.split_off(2_usize);
+            let name = names[0_usize].clone();
+            let host = Host::start(args[1_usize].as_str(), new_match(names), Duration::ZERO)?;
+            let (client, game) = Client::join(host.join_addr(), &name, Encoding::default())?;
+            Ok((game, Some(client)))
+        }
+        Some("--join") if args.len() == 3_usize => {
+            let (client, game) =
+                Client::join(args[1_usize].as_str(), &args[2_usize], Encoding::default())?;
+            Ok((game, Some(client)))
+        }
+        Some("--host" | "--join") => usage_error("Missing the address or the names."),
+        _ => Ok((new_match(args), None)),
+    }
+}
+
+/// Main entry function.
+fn main() {
+    let (game, client) = match start(std::env::args().skip(1_usize).collect::<Vec<String>>()) {
+        Ok(started) => started,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1_i32);
+        }
+    };
+    let networked = client.is_some();
+    match run(game, client) {
+        Ok(game) if networked => print_record(&game),
+        Ok(_) => {}
+        Err(err) => {
+            eprintln!("{RED}ERROR:{RST} {err}");
+            std::process::exit(1_i32);
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
@@ -0,0 +1,156 @@
+//! render module.
+//!
+//! Draws the board, the pieces in their team colours and the status of the game in the terminal.
+//!
+//! Each square is three characters wide. The cursor is drawn as `[ ]`, the picked piece as `< >`,
+//! the squares the picked piece can step to as ` * ` and the pieces it can capture as `( )`. The
+//! fort in the middle of the board is drawn as `###`.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::app::App;
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    terminal::{Clear, ClearType},
+};
+use fort_builders::{
+    board::{position_in_board_bounds, BOARD_LAYOUT},
+    notation::square,
+    pieces::{MoveKind, Position},
+    player::Team,
+    RED, RST,
+};
+use std::io::{self, Write};
+
+/// ANSI code for the blue team.
+const BLUE: &str = "\x1b[34;1m";
+/// ANSI code for the green team.
+const GREEN: &str = "\x1b[32;1m";
+/// ANSI code for the yellow team.
+const YELLOW: &str = "\x1b[33;1m";
+/// ANSI code for the board squares and the fort.
+const DIM: &str = "\x1b[2m";
+/// The line break in raw mode.
+const NEWLINE: &str = "\r\n";
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/// To get the ANSI colour code of the team.
+#[inline]
+fn team_color<'a>(team: Team) -> &'a str {
+    match team {
+        Team::Red => RED,
+        Team::Blue => BLUE,
+        Team::Green => GREEN,
+        Team::Yellow => YELLOW,
+    }
+}
+
+/// To check if the position is inside the fort in the middle of the board.
+#[inline]
+fn in_fort(x: i32, y: i32) -> bool {
+    BOARD_LAYOUT.fort.contains(x, y)
+}
+
+/// To get the piece letter and the colour of the team holding the piece at the position.
+#[inline]
+fn piece_at<'a>(app: &App, position: Position) -> Option<(char, &'a str)> {
+    app.game.players.iter().find_map(|player| {
+        player
+            .pieces
+            .iter()
+            .find(|piece| piece.position == position)
+            .map(|piece| (piece.piece_type.as_char(), team_color(player.team)))
+    })
+}
+
+/// To draw a single square of the board.
+fn draw_square(line: &mut String, app: &App, position: Position) {
+    let (x, y) = (position.x, position.y);
+    if !position_in_board_bounds(x, y) {
+        match in_fort(x, y) {
+            true => line.push_str(&format!("{DIM}###{RST}")),
+            false => line.push_str("   "),
+        }
+        return;
+    }
+    let path = app.paths.iter().find(|step| step.to == position);
+    let (left, right) = match (
+        app.cursor == position,
+        app.picked() == Some(position),
+        path.map(|step| step.kind),
+    ) {
+        (true, _, _) => ('[', ']'),
+        (_, true, _) => ('<', '>'),
+        (_, _, Some(MoveKind::Capture)) => ('(', ')'),
+        _ => (' ', ' '),
+    };
+    let middle = match (piece_at(app, position), path) {
+        (Some((letter, color)), _) => format!("{color}{letter}{RST}"),
+        (None, Some(_)) => String::from("*"),
+        (None, None) => format!("{DIM}.{RST}"),
+    };
+    line.push(left);
+    line.push_str(&middle);
+    line.push(right);
+}
+
+/// To draw the names of the players, marking the one whose turn it is and the one played at this
+/// terminal in a network game.
+fn draw_players(screen: &mut String, app: &App) {
+    for (index, player) in app.game.players.iter().enumerate() {
+        screen.push_str(&format!(
+            "{} {}{}{RST} ({}){}{}{}",
+            match index == app.game.turn {
+                true => '>',
+                false => ' ',
+            },
+            team_color(player.team),
+            player.name,
+            Team::teamstr_from_team(player.team),
+            match player.is_defender {
+                true => " defender",
+                false => "",
+            },
+            match app.local_team() == Some(player.team) {
+                true => " you",
+                false => "",
+            },
+            NEWLINE
+        ));
+    }
+}
+
+/// To draw the whole screen.
+pub(crate) fn draw(out: &mut impl Write, app: &App) -> io::Result<()> {
+    let mut screen = String::new();
+    let bounds = BOARD_LAYOUT.bounds();
+    for y in (bounds.y_min..=bounds.y_max).rev() {
+        for x in bounds.x_min..=bounds.x_max {
+            draw_square(&mut screen, app, Position { x, y });
+        }
+        screen.push_str(NEWLINE);
+    }
+    screen.push_str(NEWLINE);
+    draw_players(&mut screen, app);
+    screen.push_str(NEWLINE);
+    screen.push_str(&format!(
+        "Cursor: {}{}",
+        square(&app.cursor).unwrap_or_default(),
+        NEWLINE
+    ));
+    if !app.game.play {
+        screen.push_str(&format!("The game is over. Press 'q' to quit.{NEWLINE}"));
+    }
+    screen.push_str(&app.message);
+    screen.push_str(NEWLINE);
+    screen.push_str(
+        "arrows: move  enter: pick/move  d: dice  s: skip  u/r: undo/redo  :: type  q: quit",
+    );
+    if let Some(typed) = app.typed.as_ref() {
+        screen.push_str(&format!("{NEWLINE}:{typed}"));
+    }
+    queue!(out, MoveTo(0_u16, 0_u16), Clear(ClearType::All))?;
+    out.write_all(screen.as_bytes())?;
+    out.flush()
+}
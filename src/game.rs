@@ -6,10 +6,13 @@
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
 //-----------//
+pub(crate) mod computer;
 pub(crate) mod draw_piece;
 pub(crate) mod game_end;
 pub(crate) mod highlight;
+pub(crate) mod network;
 pub(crate) mod player_name;
+pub(crate) mod save;
 //-----------//
 
 use crate::{font::BoldFontHandle, startscreen::NameEntryValue, state::FortChessState, SPRITESIZE};
@@ -17,27 +20,29 @@ use bevy::prelude::{
     App, AssetServer, Assets, Commands, Component, Entity, Handle, Plugin, Query, Res, ResMut,
     StartupStage, State, SystemSet, TextureAtlas, Vec2, With,
 };
+use computer::{ComputerLevels, ComputerPlugin};
 use draw_piece::{draw_pieces, Piece};
 use fort_builders::{
-    board::{q1_outer_bound_pos, q2_outer_bound_pos, q3_outer_bound_pos, Quadrant},
-    dice_roll,
+    board::BOARD_LAYOUT,
     game::{Game, GameAction},
-    player::{Player, Team},
+    player::Team,
 };
 use game_end::GameEndPlugin;
 use highlight::{highlight_active_pieces, Highlight};
+use network::{NetworkMode, NetworkPlugin};
 use player_name::{
     display_player_names, highlight_player_name, PlayerName, PlayerNameBoxVec, PlayerNameOutline,
 };
+use save::{SavePlugin, SavedGame};
 
 /// To hold the number of types of pieces.
 const PIECE_TYPE_COUNT: usize = 5_usize;
-/// To hold the number of types of teams.
-const TEAM_TYPE_COUNT: usize = 3_usize;
 
 /// The game Plugin that holds piece drawing information.
 pub(crate) struct GamePlugin;
 /// To handle [`Player`] texture.
+///
+/// [`Player`]: fort_builders::player::Player
 #[derive(Component)]
 pub(crate) struct PlayerSheet(Handle<TextureAtlas>);
 /// To hold [`Game`] resource.
@@ -67,7 +72,10 @@ impl Plugin for GamePlugin {
                 SystemSet::on_exit(FortChessState::BoardScreen)
                     .with_system(dealloc_player_name_box_vec),
             )
-            .add_plugin(GameEndPlugin);
+            .add_plugin(GameEndPlugin)
+            .add_plugin(SavePlugin)
+            .add_plugin(ComputerPlugin)
+            .add_plugin(NetworkPlugin);
     }
 }
 /*-----------------------------------------------------------------------------------------------*/
@@ -75,16 +83,12 @@ impl Plugin for GamePlugin {
 /*████Player Name Box████*/
 /*-----------------------------------------------------------------------------------------------*/
 /// Simple function to initialize player name struct vec.
+///
+/// The name box is placed outside the quadrant that the player started from.
 fn init_player_name_box_vec(mut commands: Commands, game: Res<GameAsset>) {
     let mut player_name = PlayerNameBoxVec::new();
-    let mut outer_check_fn_iter =
-        [q1_outer_bound_pos, q2_outer_bound_pos, q3_outer_bound_pos].into_iter();
     game.get().players.iter().for_each(|player| {
-        let (x, y) = if player.is_defender {
-            (-1_i32, 0_i32)
-        } else {
-            (outer_check_fn_iter.next().unwrap())()
-        };
+        let (x, y) = BOARD_LAYOUT.name_position(player.quadrant);
         player_name.push(player.name.clone(), player.team, x, y);
     });
     commands.insert_resource(player_name);
@@ -114,35 +118,32 @@ impl GameAsset {
 
 /*████Game████*/
 /*-----------------------------------------------------------------------------------------------*/
-/// Initial game creation. In future, this will be handled a bit differently to facilitate variable
-/// game players.
-fn init_game(mut commands: Commands, name_entry_value_res: Res<NameEntryValue>) {
-    let count = name_entry_value_res.count();
-    if count < 2_usize {
-        panic!("Less than two players")
-    }
-    let dice_roll = (dice_roll() % TEAM_TYPE_COUNT) % count;
-    let mut quadrant = [Quadrant::Q1, Quadrant::Q2, Quadrant::Q3].into_iter();
-    commands.insert_resource(GameAsset(Game::init(
-        (usize::MIN..count)
-            .into_iter()
-            .map(|index| {
-                let is_defender = dice_roll == index;
-                Player::from(
-                    name_entry_value_res.as_string(index).unwrap(),
-                    Team::from_index(index).unwrap(),
-                    is_defender,
-                    count,
-                    if is_defender {
-                        Quadrant::NoQuad
-                    } else {
-                        quadrant.next().unwrap()
-                    },
-                )
-                .unwrap()
-            })
-            .collect::<Vec<Player>>(),
-    )));
+/// To resume a saved game.
+///
+/// The game of a new match is created in the [`lobby`], with the players seated there and the
+/// computers at the difficulty picked on the start screen. A [`SavedGame`] loaded from the start
+/// screen skips the lobby and is resumed here instead, with its computers at the default
+/// difficulty and its own rules.
+///
+/// With the [`NetworkMode`] picked from the arguments the saved game is hosted instead, and played
+/// on this window by the first name typed on the start screen. A joined or watched game is the one
+/// of the host, so the saved game is not used.
+///
+/// [`lobby`]: crate::lobby
+fn init_game(
+    mut commands: Commands,
+    name_entry_value_res: Res<NameEntryValue>,
+    saved_game: Option<ResMut<SavedGame>>,
+    network_mode: Res<NetworkMode>,
+) {
+    let Some(mut saved_game) = saved_game else { return };
+    commands.insert_resource(ComputerLevels::default());
+    let (game, session) = network_mode
+        .start(&name_entry_value_res.local_name(), || saved_game.take())
+        .unwrap_or_else(|err| panic!("{err}"));
+    commands.remove_resource::<SavedGame>();
+    commands.insert_resource(GameAsset(game));
+    commands.insert_resource(session);
 }
 
 /// To set the state to [`BoardScreen`].
@@ -189,12 +190,22 @@ fn game_update_tick(
 }
 
 /// Looks for players and kills them at every iteration.
+///
+/// Players eliminated by a move are already removed from the game, so the name boxes are synced
+/// with the teams that are left.
 fn clean_up_lost_players(game: &mut Game, pname: &mut ResMut<PlayerNameBoxVec>) {
     let _dead = game.hunt();
     if !_dead.is_empty() {
         dbg!(&_dead);
     }
-    _dead.into_iter().for_each(|player| pname.pop(player.team))
+    _dead.into_iter().for_each(|player| pname.pop(player.team));
+    pname.sync(
+        &game
+            .players
+            .iter()
+            .map(|player| player.team)
+            .collect::<Vec<Team>>(),
+    );
 }
 /*-----------------------------------------------------------------------------------------------*/
 
@@ -205,6 +216,8 @@ fn clean_up_lost_players(game: &mut Game, pname: &mut ResMut<PlayerNameBoxVec>)
 /// To insert the chess piece sprite resource. There needs to be an asset folder at runtime for
 /// the binary to load the player pieces asset. Otherwise it won't be possible for the pieces to
 /// load.
+///
+/// [`Player`]: fort_builders::player::Player
 fn load_sprite(
     mut commands: Commands,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
@@ -0,0 +1,178 @@
+//! computer module.
+//!
+//! Plays the turns of the players that are played by the computer. The move is searched by the
+//! [`fort_engine`] at the difficulty picked on the start screen. The search runs on a background
+//! task so the frames keep drawing while it thinks, and the move is played after a short delay so
+//! that the other players can follow the game. If the search fails the move is picked by the
+//! [`ai`] module instead. In a network game the turns of the computer are played by the host.
+//!
+//! [`ai`]: fort_builders::ai
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    game::{network::NetworkSession, GameAsset},
+    listener::button::dice_roll::DiceRollValue,
+    state::FortChessState,
+};
+use bevy::{
+    prelude::{App, Commands, Plugin, Res, ResMut, SystemSet, Time, Timer},
+    tasks::{AsyncComputeTaskPool, Task},
+};
+use fort_builders::{
+    ai::choose_move,
+    game::{Game, GameAction},
+    pieces::Move,
+    player::Team,
+    Error,
+};
+use fort_engine::{difficulty::Difficulty, search::search_board};
+use futures_lite::future::{block_on, poll_once};
+
+/// The seconds the computer waits before playing its move.
+const COMPUTER_DELAY: f32 = 0.75_f32;
+/// Timer repeat.
+const TIMER_REPEAT: bool = false;
+
+/// To hold the delay before the computer plays its move.
+struct ComputerTimer(Timer);
+/// To hold the search running on a background task for the move of the computer.
+struct ComputerSearch(Option<Task<Result<Move, Error>>>);
+/// To hold the difficulty of the computer for each team, in the order of the teams.
+#[derive(Default)]
+pub(crate) struct ComputerLevels([Difficulty; 4_usize]);
+/// Plugin to play the turns of the computer.
+pub(crate) struct ComputerPlugin;
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Plugin for ComputerPlugin████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Plugin for ComputerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_enter(FortChessState::BoardScreen).with_system(init_computer),
+        )
+        .add_system_set(
+            SystemSet::on_update(FortChessState::BoardScreen).with_system(computer_turn),
+        );
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Computer Timer████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl ComputerTimer {
+    /// To create a timer.
+    #[inline]
+    fn init() -> Self {
+        ComputerTimer(Timer::from_seconds(COMPUTER_DELAY, TIMER_REPEAT))
+    }
+    /// To get a mutable reference to [`ComputerTimer`] timer.
+    #[inline]
+    fn get_mut(&mut self) -> &mut Timer {
+        &mut self.0
+    }
+}
+
+/// To insert the [`ComputerTimer`] and an idle [`ComputerSearch`] when the board is shown.
+#[inline]
+fn init_computer(mut commands: Commands) {
+    commands.insert_resource(ComputerTimer::init());
+    commands.insert_resource(ComputerSearch(None));
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Computer Levels████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl ComputerLevels {
+    /// To create the levels from the difficulties in the order of the teams.
+    ///
+    /// The teams without a difficulty are played at the default one.
+    #[inline]
+    pub(crate) fn from(difficulties: impl Iterator<Item = Option<Difficulty>>) -> Self {
+        let mut levels = ComputerLevels::default();
+        levels
+            .0
+            .iter_mut()
+            .zip(difficulties)
+            .for_each(|(level, difficulty)| *level = difficulty.unwrap_or_default());
+        levels
+    }
+    /// To get the difficulty of the computer playing the team.
+    #[inline]
+    fn get(&self, team: Team) -> Difficulty {
+        self.0[team.as_usize()]
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Computer Search████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl ComputerSearch {
+    /// To start searching a copy of the game on a background task.
+    #[inline]
+    fn start(&mut self, game: &Game, difficulty: Difficulty) {
+        let board = search_board(game);
+        self.0 = Some(AsyncComputeTaskPool::get().spawn(async move {
+            board
+                .and_then(|board| difficulty.search(board))
+                .map(|found| found.step)
+        }));
+    }
+    /// To drop the running search, which cancels it.
+    #[inline]
+    fn cancel(&mut self) {
+        self.0 = None;
+    }
+    /// To get the move of the search if it is done, without waiting for it.
+    #[inline]
+    fn poll(&mut self) -> Option<Result<Move, Error>> {
+        let found = block_on(poll_once(self.0.as_mut()?))?;
+        self.cancel();
+        Some(found)
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Computer Turn████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To play the move of the computer once the search and the delay are over.
+///
+/// The search starts on the first frame of the turn of the computer. The delay starts again and
+/// the search is dropped whenever it is not the turn of the computer. A move that cannot be played
+/// anymore, like after an undo, is replaced by the move of the [`ai`] module.
+///
+/// [`ai`]: fort_builders::ai
+fn computer_turn(
+    time: Res<Time>,
+    mut timer: ResMut<ComputerTimer>,
+    mut search: ResMut<ComputerSearch>,
+    levels: Res<ComputerLevels>,
+    mut game: ResMut<GameAsset>,
+    mut dice_roll_val: ResMut<DiceRollValue>,
+    session: Res<NetworkSession>,
+) {
+    let game = game.get_mut();
+    if !game.play || !game.current_player().is_computer() || !session.is_local() {
+        timer.get_mut().reset();
+        search.cancel();
+        return;
+    }
+    if search.0.is_none() {
+        search.start(game, levels.get(game.current_player().team));
+    }
+    if !timer.get_mut().tick(time.delta()).finished() {
+        return;
+    }
+    let Some(found) = search.poll() else { return };
+    timer.get_mut().reset();
+    let outcome = match found.and_then(|step| game.apply_move(step)) {
+        Ok(outcome) => outcome,
+        Err(_) => game.apply_move(choose_move(game)).unwrap(),
+    };
+    if let Some(roll) = outcome.roll {
+        dice_roll_val.set(roll);
+    }
+    game.set_picked_false();
+}
+/*-----------------------------------------------------------------------------------------------*/
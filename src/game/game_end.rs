@@ -14,12 +14,7 @@ use bevy::{
     },
     text::Text2dBounds,
 };
-use fort_builders::{
-    board::{XMINF, YMAXF},
-    exit,
-    player::Player,
-    Error,
-};
+use fort_builders::{board::BOARD_LAYOUT, exit, player::Player, Error};
 
 /// Fade out speed.
 const FADEOUT_SPEED: f32 = 2_f32;
@@ -164,8 +159,8 @@ fn display_winner(
                 )
                 .with_alignment(TextAlignment::CENTER_LEFT),
                 transform: Transform::from_xyz(
-                    XMINF * RESOLUTION,
-                    (YMAXF / 2_f32) * RESOLUTION,
+                    BOARD_LAYOUT.bounds().x_min as f32 * RESOLUTION,
+                    (BOARD_LAYOUT.bounds().y_max as f32 / 2_f32) * RESOLUTION,
                     21_f32,
                 ),
                 ..default()
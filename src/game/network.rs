@@ -0,0 +1,315 @@
+//! network module.
+//!
+//! To play the board screen over the local network. The board and the buttons send the moves
+//! they want to play as [`MoveIntent`] events instead of changing the [`GameAsset`]. In a local
+//! game the intents are played right away. In a network game they are sent to the host, and the
+//! game only changes once the host announces the moves it played.
+//!
+//! The game is hosted with `--host <address>` and joined with `--join <address>`. The players meet
+//! in the [`lobby`] of the host first, under the first name they typed on the start screen, and
+//! the host starts the game from there. A saved game is hosted right away instead, and the player
+//! joining it plays the seat of its name, or else the first free one.
+//!
+//! A game is watched with `--watch <address>`, which follows the board without a seat and without
+//! the listeners and the buttons of the players. Adding `--delay <seconds>` after the address of
+//! `--host` holds every message to the spectators back for that long.
+//!
+//! [`lobby`]: crate::lobby
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{game::GameAsset, listener::button::dice_roll::DiceRollValue, state::FortChessState};
+use bevy::prelude::{App, EventReader, Plugin, Res, ResMut, SystemSet};
+use fort_builders::{
+    game::{Game, GameAction},
+    lobby::{Lobby, LobbyRequest},
+    pieces::Move,
+    player::Team,
+    ruleset::RuleSet,
+};
+use fort_net::{
+    client::{self, Client},
+    host::Host,
+    spectator::Spectator,
+    Error,
+};
+use fort_protocol::{codec::Encoding, message::HostMessage};
+use std::{sync::Mutex, time::Duration};
+
+/// Event to ask for a move to be played for the current player.
+pub(crate) struct MoveIntent(pub(crate) Move);
+/// How the game is played, read from the arguments of the program.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub(crate) enum NetworkMode {
+    /// Every player plays on this window.
+    Local,
+    /// The game is hosted on the address, with the messages to the spectators held back for the
+    /// delay.
+    Host(String, Duration),
+    /// The game hosted at the address is joined.
+    Join(String),
+    /// The game hosted at the address is watched.
+    Watch(String),
+}
+/// To hold the connection to the host in a network game.
+///
+/// The client is kept behind a [`Mutex`] as its messages are read from a channel that cannot be
+/// shared between the systems otherwise.
+#[derive(Default)]
+pub(crate) struct NetworkSession {
+    /// The connection to the host, if the game is played over the network.
+    client: Option<Mutex<Client>>,
+    /// The connection to the host, if the game is watched over the network.
+    spectator: Option<Mutex<Spectator>>,
+}
+/// Plugin to play the move intents and the moves announced by the host.
+pub(crate) struct NetworkPlugin;
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Plugin for NetworkPlugin████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Plugin for NetworkPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(NetworkMode::from_args(std::env::args().skip(1_usize)))
+            .add_event::<MoveIntent>()
+            .add_system_set(
+                SystemSet::on_update(FortChessState::BoardScreen)
+                    .with_system(play_move_intents)
+                    .with_system(receive_host_moves),
+            )
+            .add_system_set(
+                SystemSet::on_exit(FortChessState::BoardScreen).with_system(close_session),
+            );
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Network Mode████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl NetworkMode {
+    /// To read the mode from the arguments. Any other argument plays a local game.
+    #[inline]
+    fn from_args(mut args: impl Iterator<Item = String>) -> Self {
+        match (args.next().as_deref(), args.next()) {
+            (Some("--host"), Some(address)) => {
+                let delay = match (args.next().as_deref(), args.next()) {
+                    (Some("--delay"), Some(seconds)) => seconds.parse::<u64>().unwrap_or_default(),
+                    _ => u64::MIN,
+                };
+                NetworkMode::Host(address, Duration::from_secs(delay))
+            }
+            (Some("--join"), Some(address)) => NetworkMode::Join(address),
+            (Some("--watch"), Some(address)) => NetworkMode::Watch(address),
+            _ => NetworkMode::Local,
+        }
+    }
+    /// To check if the game can be started from this window, which is not the case when joining
+    /// or watching.
+    #[inline]
+    pub(crate) fn can_start(&self) -> bool {
+        matches!(self, NetworkMode::Local | NetworkMode::Host(..))
+    }
+    /// To check if the game is only watched on this window.
+    #[inline]
+    pub(crate) fn is_watching(&self) -> bool {
+        matches!(self, NetworkMode::Watch(_))
+    }
+    /// To check if every player is played on this window.
+    #[inline]
+    pub(crate) fn is_local(&self) -> bool {
+        *self == NetworkMode::Local
+    }
+    /// To start the session for the player with the given name on a game that already exists,
+    /// like a saved one.
+    ///
+    /// The game is only created when it is played locally or hosted. A hosted game is joined from
+    /// this window like any other client. Returns the game to show on the board.
+    pub(crate) fn start(
+        &self,
+        name: &str,
+        game: impl FnOnce() -> Game,
+    ) -> Result<(Game, NetworkSession), Error> {
+        let (client, game) = match self {
+            NetworkMode::Local => return Ok((game(), NetworkSession::default())),
+            NetworkMode::Watch(address) => {
+                let (spectator, game) = Spectator::follow(address.as_str(), Encoding::default())?;
+                return Ok((game, NetworkSession::watching(spectator)));
+            }
+            NetworkMode::Host(address, delay) => {
+                let host = Host::start(address.as_str(), game(), *delay)?;
+                Client::join(host.join_addr(), name, Encoding::default())?
+            }
+            NetworkMode::Join(address) => {
+                Client::join(address.as_str(), name, Encoding::default())?
+            }
+        };
+        Ok((game, NetworkSession::playing(client)))
+    }
+    /// To enter the lobby of a new game as the player with the given name.
+    ///
+    /// A hosted lobby holds the seats of the lobby given, and the game is played with the rules
+    /// once it starts. The lobby of a local game is kept on this window, so no session is needed.
+    pub(crate) fn enter(
+        &self,
+        name: &str,
+        lobby: Lobby,
+        rules: RuleSet,
+    ) -> Result<NetworkSession, Error> {
+        let client = match self {
+            NetworkMode::Local => return Ok(NetworkSession::default()),
+            NetworkMode::Host(address, delay) => {
+                let host = Host::open(address.as_str(), lobby, rules, *delay)?;
+                Client::enter(host.join_addr(), name, Encoding::default())?
+            }
+            NetworkMode::Join(address) => {
+                Client::enter(address.as_str(), name, Encoding::default())?
+            }
+            NetworkMode::Watch(address) => {
+                let spectator = Spectator::watch(address.as_str(), Encoding::default())?;
+                return Ok(NetworkSession::watching(spectator));
+            }
+        };
+        Ok(NetworkSession::playing(client))
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Network Session████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl NetworkSession {
+    /// To create the session of a player connected to the host.
+    #[inline]
+    fn playing(client: Client) -> Self {
+        NetworkSession {
+            client: Some(Mutex::new(client)),
+            spectator: None,
+        }
+    }
+    /// To create the session of a spectator connected to the host.
+    #[inline]
+    fn watching(spectator: Spectator) -> Self {
+        NetworkSession {
+            client: None,
+            spectator: Some(Mutex::new(spectator)),
+        }
+    }
+    /// To check if every player is played on this window.
+    #[inline]
+    pub(crate) fn is_local(&self) -> bool {
+        self.client.is_none() && self.spectator.is_none()
+    }
+    /// To check if the game is only watched on this window.
+    #[inline]
+    pub(crate) fn is_spectator(&self) -> bool {
+        self.spectator.is_some()
+    }
+    /// To get the team played on this window in a network game.
+    #[inline]
+    pub(crate) fn team(&self) -> Option<Team> {
+        self.client
+            .as_ref()
+            .map(|connection| connection.lock().unwrap().team())
+    }
+    /// To check if the turn of the team can be played on this window. A spectator plays none.
+    #[inline]
+    pub(crate) fn controls(&self, team: Team) -> bool {
+        !self.is_spectator() && self.team().map_or(true, |local| local == team)
+    }
+    /// To send the request to the lobby of the host. A spectator has no seat to change.
+    #[inline]
+    pub(crate) fn request(&self, request: LobbyRequest) -> Result<(), Error> {
+        match self.client.as_ref() {
+            Some(connection) => connection.lock().unwrap().lobby(request),
+            None => Ok(()),
+        }
+    }
+    /// To get the next message of the host without waiting for it. There are none in a local
+    /// game.
+    #[inline]
+    pub(crate) fn poll(&self) -> Result<Option<HostMessage>, Error> {
+        match (self.client.as_ref(), self.spectator.as_ref()) {
+            (Some(connection), _) => connection.lock().unwrap().poll(),
+            (None, Some(connection)) => connection.lock().unwrap().poll(),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// To drop the connection to the host when leaving the board, which gives the seat back.
+#[inline]
+fn close_session(mut session: ResMut<NetworkSession>) {
+    session.client = None;
+    session.spectator = None;
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Move Intents████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To play the intents on the game in a local game, or to send them to the host. A spectator
+/// plays none. An intent the game refuses is reported and dropped.
+fn play_move_intents(
+    mut intents: EventReader<MoveIntent>,
+    session: Res<NetworkSession>,
+    mut game: ResMut<GameAsset>,
+    mut dice_roll_val: ResMut<DiceRollValue>,
+) {
+    intents.iter().for_each(|&MoveIntent(step)| {
+        if session.is_spectator() {
+            return;
+        }
+        if let Some(connection) = session.client.as_ref() {
+            if let Err(err) = connection.lock().unwrap().send(step) {
+                eprintln!("{err}");
+            }
+            return;
+        }
+        match game.get_mut().apply_move(step) {
+            Ok(outcome) => {
+                if let Some(roll) = outcome.roll {
+                    dice_roll_val.set(roll);
+                }
+            }
+            Err(err) => eprintln!("{err}"),
+        }
+    });
+}
+
+/// To play the moves announced by the host on the game, for a player or a spectator alike.
+///
+/// A lost connection ends the game without a winner, and so does a move that cannot be played on
+/// the game, as the board no longer matches the game of the host.
+fn receive_host_moves(
+    session: Res<NetworkSession>,
+    mut game: ResMut<GameAsset>,
+    mut dice_roll_val: ResMut<DiceRollValue>,
+) {
+    loop {
+        let message = match session.poll() {
+            Ok(Some(message)) => message,
+            Ok(None) => return,
+            Err(err) => {
+                eprintln!("{err}");
+                game.get_mut().set_play_false().set_update_true();
+                return;
+            }
+        };
+        match client::apply(game.get_mut(), &message) {
+            Ok(Some(outcome)) => {
+                if let Some(roll) = outcome.roll {
+                    dice_roll_val.set(roll);
+                }
+            }
+            Ok(None) => {
+                if let HostMessage::Rejected { reason } = message {
+                    eprintln!("The host refused the move: {reason}.");
+                }
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                game.get_mut().set_play_false().set_update_true();
+                return;
+            }
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
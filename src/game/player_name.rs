@@ -29,6 +29,8 @@ pub(crate) struct PlayerNameTextBox {
 #[derive(Debug)]
 pub(crate) struct PlayerNameBoxVec {
     boxes: Vec<PlayerNameTextBox>,
+    /// Boxes of the players that are out of the game. Kept to put them back on undo.
+    hidden: Vec<PlayerNameTextBox>,
 }
 /// To denote a player name box entity.
 #[derive(Component)]
@@ -38,7 +40,7 @@ pub(crate) struct PlayerName;
 
 /// Function to convert [`Team`] to [`Color`].
 #[inline]
-fn color_from_team(team: Team) -> Color {
+pub(crate) fn color_from_team(team: Team) -> Color {
     match team {
         Team::Red => Color::RED,
         Team::Green => Color::GREEN,
@@ -68,7 +70,10 @@ impl PlayerNameBoxVec {
     /// To initialize the [`PlayerNameBoxVec`] object.
     #[inline]
     pub(crate) fn new() -> Self {
-        PlayerNameBoxVec { boxes: Vec::new() }
+        PlayerNameBoxVec {
+            boxes: Vec::new(),
+            hidden: Vec::new(),
+        }
     }
     /// To push a [`PlayerNameTextBox`] to the vec.
     #[inline]
@@ -82,6 +87,18 @@ impl PlayerNameBoxVec {
             self.boxes.remove(pos);
         }
     }
+    /// To keep only the [`PlayerNameTextBox`] of the teams that are still in the game.
+    ///
+    /// Boxes of teams that are out of the game are hidden and are shown again if the team comes
+    /// back, like when a move is undone.
+    pub(crate) fn sync(&mut self, teams: &[Team]) {
+        (self.boxes, self.hidden) = self
+            .boxes
+            .drain(..)
+            .chain(self.hidden.drain(..))
+            .partition(|pname| teams.contains(&pname.team));
+        self.boxes.sort_by(|a, b| a.team.cmp(&b.team));
+    }
     /// To search the player with the given team using `binary search`.
     #[inline]
     pub(crate) fn search(&self, team: Team) -> Result<usize, usize> {
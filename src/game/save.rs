@@ -0,0 +1,192 @@
+//! save module.
+//!
+//! To save the game on the board screen and load it back from the start screen. There are two
+//! save slots: the `autosave` slot written after every turn and when the game is quit with
+//! `Ctrl + q`, and the `load` slot written with `Ctrl + s`.
+//!
+//! The latest state of the game is also kept in memory so that the panic hook can flush it to
+//! the `autosave` slot before the game dies.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{game::GameAsset, state::FortChessState};
+use bevy::prelude::{App, Input, KeyCode, Local, Plugin, Res, SystemSet};
+use fort_builders::{game::Game, snapshot, Error};
+use std::{fs, path::PathBuf, sync::Mutex};
+
+/// The directory that holds the save files if the user's data directory is not found.
+const SAVE_DIR: &str = "saves";
+/// The name of the directory inside the user's data directory that holds the save files.
+const DATA_DIR_NAME: &str = "fort_chess";
+/// The file name of the game saved automatically.
+const AUTOSAVE_FILE: &str = "autosave.json";
+/// The file name of the game saved manually.
+const LOAD_FILE: &str = "load.json";
+
+/// The latest snapshot of the game on the board, flushed to the `autosave` slot on panic.
+static LATEST_SNAPSHOT: Mutex<Option<String>> = Mutex::new(None);
+
+/// Plugin to handle saving the game.
+pub(crate) struct SavePlugin;
+/// The save slots that a game can be saved to.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum SaveSlot {
+    /// Saved after every turn and when quitting the game.
+    Autosave,
+    /// Saved manually during the game.
+    Load,
+}
+/// To hold a loaded game until it is built on the board.
+pub(crate) struct SavedGame(Game);
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Plugin for SavePlugin████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_update(FortChessState::BoardScreen)
+                .with_system(save_key_listener)
+                .with_system(autosave),
+        )
+        .add_system_set(
+            SystemSet::on_enter(FortChessState::ResultScreen).with_system(clear_autosave),
+        );
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████SaveSlot████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl SaveSlot {
+    /// To get the path of the save file of the slot.
+    ///
+    /// The save files are kept in the user's data directory, falling back to the [`SAVE_DIR`] in
+    /// the working directory.
+    #[inline]
+    fn path(&self) -> PathBuf {
+        dirs::data_dir()
+            .map(|dir| dir.join(DATA_DIR_NAME))
+            .unwrap_or_else(|| PathBuf::from(SAVE_DIR))
+            .join(match self {
+                SaveSlot::Autosave => AUTOSAVE_FILE,
+                SaveSlot::Load => LOAD_FILE,
+            })
+    }
+    /// To check if there is a game saved in the slot.
+    #[inline]
+    pub(crate) fn exists(&self) -> bool {
+        self.path().is_file()
+    }
+    /// To save the game to the slot.
+    #[inline]
+    pub(crate) fn save(&self, game: &Game) -> Result<(), Error> {
+        snapshot::save(game, &self.path())
+    }
+    /// To write an already serialized snapshot to the slot.
+    fn write(&self, snapshot: &str) -> Result<(), Error> {
+        let path = self.path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, snapshot)?;
+        Ok(())
+    }
+    /// To load the game saved in the slot.
+    #[inline]
+    pub(crate) fn load(&self) -> Result<SavedGame, Error> {
+        Ok(SavedGame(snapshot::load(&self.path())?))
+    }
+    /// To delete the game saved in the slot.
+    #[inline]
+    fn remove(&self) -> Result<(), Error> {
+        Ok(fs::remove_file(self.path())?)
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████SavedGame████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl SavedGame {
+    /// To take the loaded game out of the resource.
+    #[inline]
+    pub(crate) fn take(&mut self) -> Game {
+        std::mem::take(&mut self.0)
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Save Key Listener████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To save the game when `Ctrl + s` is pressed or when the game is quit with `Ctrl + q`.
+///
+/// Errors are only printed as a failed save should not stop the game.
+fn save_key_listener(input: Res<Input<KeyCode>>, game: Res<GameAsset>) {
+    if !input.pressed(KeyCode::LControl) {
+        return;
+    }
+    let slot = match (
+        input.just_pressed(KeyCode::S),
+        input.just_pressed(KeyCode::Q),
+    ) {
+        (true, _) => SaveSlot::Load,
+        (_, true) => SaveSlot::Autosave,
+        _ => return,
+    };
+    if let Err(err) = slot.save(game.get()) {
+        eprintln!("{err}");
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Autosave████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To keep the latest snapshot of the game in memory and write it to the `autosave` slot
+/// whenever the turn changes.
+fn autosave(game: Res<GameAsset>, mut last_turn: Local<Option<usize>>) {
+    if !game.is_changed() {
+        return;
+    }
+    let game = game.get();
+    let snapshot = match snapshot::to_string(game) {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            eprintln!("{err}");
+            return;
+        }
+    };
+    if last_turn.replace(game.turn) != Some(game.turn) {
+        if let Err(err) = SaveSlot::Autosave.write(&snapshot) {
+            eprintln!("{err}");
+        }
+    }
+    if let Ok(mut latest) = LATEST_SNAPSHOT.lock() {
+        *latest = Some(snapshot);
+    }
+}
+
+/// To write the latest snapshot of the game to the `autosave` slot.
+///
+/// Called from the panic hook, so the lock is only tried to avoid blocking on a lock held by
+/// the panicking thread.
+pub(crate) fn flush_autosave() {
+    let Ok(latest) = LATEST_SNAPSHOT.try_lock() else {
+        return
+    };
+    if let Some(snapshot) = latest.as_deref() {
+        let _result = SaveSlot::Autosave.write(snapshot);
+    }
+}
+
+/// To delete the `autosave` slot once the game is over as there is nothing left to resume.
+fn clear_autosave() {
+    if let Ok(mut latest) = LATEST_SNAPSHOT.lock() {
+        *latest = None;
+    }
+    if SaveSlot::Autosave.exists() {
+        if let Err(err) = SaveSlot::Autosave.remove() {
+            eprintln!("{err}");
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
@@ -15,17 +15,17 @@ use bevy::prelude::{
     default, App, Color, Commands, Component, CursorMoved, Entity, EventReader, Plugin, Res,
     ResMut, Sprite, SpriteBundle, SystemSet, Transform, Vec2, Vec3, Windows,
 };
-use button::FortButtonPlugin;
+use button::{promotion::PendingPromotion, FortButtonPlugin};
 use click::click_listener;
 use fort_builders::board::cursor_in_window;
 use hover::{clear_picker, hover_listener};
 use possible_paths::PossiblePaths;
 
-/// To hold the current cursor position.
+/// To hold the current cursor position on the board.
 #[derive(Component)]
 pub(crate) struct CursorPosition {
-    x: f32,
-    y: f32,
+    x: i32,
+    y: i32,
 }
 /// Plugin to handle the hover and click listener systems.
 pub(crate) struct ListenerPlugin;
@@ -58,7 +58,7 @@ impl Plugin for ListenerPlugin {
 
 /*████ListenerPlugin Objects████*/
 /*-----------------------------------------------------------------------------------------------*/
-/// To initialize [`CursorPosition`] and [`PossiblePaths`] structs.
+/// To initialize [`CursorPosition`], [`PossiblePaths`] and [`PendingPromotion`] structs.
 fn initialize_listener_objects(mut commands: Commands) {
     commands.insert_resource(CursorPosition {
         x: default(),
@@ -67,12 +67,14 @@ fn initialize_listener_objects(mut commands: Commands) {
     commands.insert_resource(PossiblePaths {
         paths: Vec::default(),
     });
+    commands.insert_resource(PendingPromotion::default());
 }
 
 /// To remove the board resources.
 fn deallocate_listener_objects(mut commands: Commands) {
     commands.remove_resource::<CursorPosition>();
     commands.remove_resource::<PossiblePaths>();
+    commands.remove_resource::<PendingPromotion>();
 }
 /*-----------------------------------------------------------------------------------------------*/
 
@@ -4,8 +4,10 @@
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
 pub(crate) mod dice_roll;
+pub(crate) mod promotion;
 pub(crate) mod return_main;
 pub(crate) mod skip_turn;
+pub(crate) mod undo;
 
 mod style {
     use bevy::prelude::Color;
@@ -37,8 +39,10 @@ use bevy::{
     },
 };
 use dice_roll::DiceRollButtonPlugin;
+use promotion::PromotionPickerPlugin;
 use return_main::ReturnButtonPlugin;
 use skip_turn::SkipButtonPlugin;
+use undo::UndoButtonPlugin;
 
 /// Object to create instances of button.
 pub(crate) struct BtnContainer {
@@ -76,7 +80,9 @@ impl Plugin for FortButtonPlugin {
             SystemSet::on_exit(FortChessState::BoardScreen).with_system(despawn_buttons),
         )
         .add_plugin(SkipButtonPlugin)
+        .add_plugin(UndoButtonPlugin)
         .add_plugin(DiceRollButtonPlugin)
+        .add_plugin(PromotionPickerPlugin)
         .add_plugin(ReturnButtonPlugin);
     }
 }
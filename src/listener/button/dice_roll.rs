@@ -6,7 +6,10 @@
 use crate::{
     despawn_entity::DespawnEntity,
     font::{RegFontHandle, DEFAULT_FONT_CLR},
-    game::GameAsset,
+    game::{
+        network::{MoveIntent, NetworkMode},
+        GameAsset,
+    },
     listener::{
         button::{btn_spawn, style, BtnColorQuery, BtnContainer},
         click::Click,
@@ -17,13 +20,13 @@ use crate::{
 };
 use bevy::{
     prelude::{
-        default, App, Button, Changed, Commands, Component, Entity, Interaction, Plugin, Query,
-        Res, ResMut, SystemSet, Text, Text2dBundle, TextAlignment, TextStyle, Time, Timer,
-        Transform, UiColor, Vec2, Visibility, With,
+        default, App, Button, Changed, Commands, Component, Entity, EventWriter, Interaction,
+        Plugin, Query, Res, ResMut, SystemSet, Text, Text2dBundle, TextAlignment, TextStyle, Time,
+        Timer, Transform, UiColor, Vec2, Visibility, With,
     },
     text::Text2dBounds,
 };
-use fort_builders::{dice_roll, game::GameAction, player::PlayerAction};
+use fort_builders::{pieces::Move, player::PlayerAction};
 
 /// To hold the button text.
 const DICE_ROLL_BTN_TEXT: &str = "Dice Roll";
@@ -35,7 +38,7 @@ const FADEOUT_SPEED: f32 = 2_f32;
 const TIMER_REPEAT: bool = false;
 
 /// To hold dice roll value.
-struct DiceRollValue {
+pub(crate) struct DiceRollValue {
     value: usize,
     display: bool,
 }
@@ -131,7 +134,7 @@ impl DiceRollValue {
     }
     /// To set the value of dice roll.
     #[inline]
-    fn set(&mut self, value: usize) {
+    pub(crate) fn set(&mut self, value: usize) {
         self.value = value;
         self.display = true;
     }
@@ -216,12 +219,14 @@ fn dice_roll_ui_text(
 /*████Dice Roll Button Clicked████*/
 /*-----------------------------------------------------------------------------------------------*/
 /// To handle the button click interface.
+///
+/// The roll is sent as a [`MoveIntent`], and its value is shown once the move is played.
 fn dice_roll_btn_clicked(
     mut commands: Commands,
     mut dice_roll_query: Query<BtnColorQuery, DiceRollBtnQuery>,
     mut game: ResMut<GameAsset>,
     mut paths: ResMut<PossiblePaths>,
-    mut dice_roll_val: ResMut<DiceRollValue>,
+    mut intents: EventWriter<MoveIntent>,
     paths_query: Query<Entity, With<Paths>>,
     click_query: Query<Entity, With<Click>>,
 ) {
@@ -230,16 +235,14 @@ fn dice_roll_btn_clicked(
         .for_each(|(&interaction, mut color)| match interaction {
             Interaction::Clicked => {
                 *color = UiColor::from(style::BTN_CLICKD_COLOR);
-                let roll = dice_roll();
-                dice_roll_val.set(roll);
-                if roll == 5_usize {
-                    game.get_mut().current_player_mut().set_winner();
-                    game.get_mut().set_play_false();
-                }
-                game.get_mut()
-                    .next_player()
-                    .set_update_true()
-                    .set_picked_false();
+                let at = game
+                    .get()
+                    .current_player()
+                    .current_chosen_piece()
+                    .unwrap()
+                    .position;
+                intents.send(MoveIntent(Move::dice_roll(at)));
+                game.get_mut().set_picked_false();
                 paths.clear();
                 commands.despawn_entity(&click_query);
                 commands.despawn_entity(&paths_query);
@@ -252,9 +255,16 @@ fn dice_roll_btn_clicked(
 
 /*████Dice Roll Button Setup████*/
 /*-----------------------------------------------------------------------------------------------*/
-/// To spawn a button.
+/// To spawn a button. A spectator has no dice to roll.
 #[inline]
-fn dice_roll_btn_spawn(mut commands: Commands, button: Res<BtnContainer>) {
+fn dice_roll_btn_spawn(
+    mut commands: Commands,
+    button: Res<BtnContainer>,
+    network_mode: Res<NetworkMode>,
+) {
+    if network_mode.is_watching() {
+        return;
+    }
     btn_spawn(&mut commands, &button, DICE_ROLL_BTN_TEXT, DiceRollButton);
 }
 /*-----------------------------------------------------------------------------------------------*/
@@ -0,0 +1,176 @@
+//! promotion module.
+//!
+//! To handle the picker that shows up when a pawn is promoted. The move is held back until one of
+//! the types is picked, and the board does not take clicks in the meantime.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    despawn_entity::DespawnEntity,
+    game::{network::MoveIntent, GameAsset},
+    listener::{
+        button::{btn_bg_spawn, style, BoardButton, BtnColorQuery, BtnContainer},
+        click::Click,
+        possible_paths::{Paths, PossiblePaths},
+    },
+    state::FortChessState,
+};
+use bevy::prelude::{
+    default, AlignItems, App, BuildChildren, Button, Changed, Color, Commands, Component, Entity,
+    EventWriter, Interaction, JustifyContent, NodeBundle, Plugin, PositionType, Query, Res, ResMut,
+    Size, Style, SystemSet, UiColor, UiRect, Val, With,
+};
+use fort_builders::pieces::{Move, PieceType};
+
+/// Color behind the picker buttons.
+const PICKER_NODE_COLOR: Color = Color::rgba(0.1_f32, 0.1_f32, 0.1_f32, 0.6_f32);
+
+/// Plugin to handle the promotion picker.
+pub(crate) struct PromotionPickerPlugin;
+/// A resource to hold the move that waits for the promoted type to be picked.
+#[derive(Debug, Default)]
+pub(crate) struct PendingPromotion {
+    step: Option<Move>,
+}
+/// To signify the node holding the promotion picker.
+#[derive(Component)]
+pub(crate) struct PromotionPicker;
+/// To signify a button of the promotion picker, with the type it promotes to.
+#[derive(Component)]
+pub(crate) struct PromotionButton(PieceType);
+
+/// Type alias for promotion button query.
+type PromotionBtnQuery = (Changed<Interaction>, With<Button>);
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Plugin for PromotionPickerPlugin████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Plugin for PromotionPickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_update(FortChessState::BoardScreen)
+                .with_system(promotion_picker_update)
+                .with_system(promotion_btn_clicked),
+        );
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████PendingPromotion████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl PendingPromotion {
+    /// To hold back the move until the promoted type is picked.
+    #[inline]
+    pub(crate) fn set(&mut self, step: Move) {
+        self.step = Some(step);
+    }
+    /// To drop the held back move.
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.step = None;
+    }
+    /// To check if a move is waiting for the promoted type to be picked.
+    #[inline]
+    pub(crate) fn is_pending(&self) -> bool {
+        self.step.is_some()
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Promotion Picker████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To get the text of the button for the type.
+#[inline]
+fn promotion_btn_text(piece_type: PieceType) -> &'static str {
+    match piece_type {
+        PieceType::Rook => "Rook",
+        PieceType::Minister => "Minister",
+        PieceType::Queen => "Queen",
+        PieceType::Pawn => "Pawn",
+        PieceType::Knight => "Knight",
+    }
+}
+
+/// To spawn the picker in the middle of the screen whenever a move is held back, and to despawn
+/// it once the move is played or dropped.
+fn promotion_picker_update(
+    mut commands: Commands,
+    pending: Res<PendingPromotion>,
+    button: Res<BtnContainer>,
+    picker_query: Query<Entity, With<PromotionPicker>>,
+) {
+    if !pending.is_changed() {
+        return;
+    }
+    commands.despawn_entity(&picker_query);
+    if !pending.is_pending() {
+        return;
+    }
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100_f32), Val::Percent(100_f32)),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: UiColor::from(style::BTN_NODE_COLOR),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        padding: UiRect::all(Val::Percent(1_f32)),
+                        ..default()
+                    },
+                    color: UiColor::from(PICKER_NODE_COLOR),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    PieceType::PROMOTIONS.into_iter().for_each(|piece_type| {
+                        btn_bg_spawn(
+                            parent,
+                            &button,
+                            promotion_btn_text(piece_type),
+                            PromotionButton(piece_type),
+                        )
+                    });
+                });
+        })
+        .insert(PromotionPicker)
+        .insert(BoardButton);
+}
+
+/// To send the held back move with the picked type as a [`MoveIntent`], and to clear the chosen
+/// piece along with its paths.
+#[allow(clippy::too_many_arguments)]
+fn promotion_btn_clicked(
+    mut commands: Commands,
+    mut interaction_query: Query<(BtnColorQuery, &PromotionButton), PromotionBtnQuery>,
+    mut game: ResMut<GameAsset>,
+    mut pending: ResMut<PendingPromotion>,
+    mut paths: ResMut<PossiblePaths>,
+    mut intents: EventWriter<MoveIntent>,
+    paths_query: Query<Entity, With<Paths>>,
+    click_query: Query<Entity, With<Click>>,
+) {
+    interaction_query.iter_mut().for_each(
+        |((&interaction, mut color), &PromotionButton(piece_type))| match interaction {
+            Interaction::Clicked => {
+                *color = UiColor::from(style::BTN_CLICKD_COLOR);
+                let Some(step) = pending.step else { return };
+                intents.send(MoveIntent(step.with_promotion(piece_type)));
+                game.get_mut().set_picked_false();
+                pending.clear();
+                paths.clear();
+                commands.despawn_entity(&click_query);
+                commands.despawn_entity(&paths_query);
+            }
+            Interaction::Hovered => *color = UiColor::from(style::BTN_HOVERD_COLOR),
+            Interaction::None => *color = UiColor::from(style::BTN_BKGRND_COLOR),
+        },
+    );
+}
+/*-----------------------------------------------------------------------------------------------*/
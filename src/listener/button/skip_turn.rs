@@ -5,19 +5,22 @@
 
 use crate::{
     despawn_entity::DespawnEntity,
-    game::GameAsset,
+    game::{
+        network::{MoveIntent, NetworkMode, NetworkSession},
+        GameAsset,
+    },
     listener::{
-        button::{btn_spawn, style, BtnColorQuery, BtnContainer},
+        button::{btn_spawn, promotion::PendingPromotion, style, BtnColorQuery, BtnContainer},
         click::Click,
         possible_paths::{Paths, PossiblePaths},
     },
     state::FortChessState,
 };
 use bevy::prelude::{
-    App, Button, Changed, Commands, Component, Entity, Interaction, Plugin, Query, Res, ResMut,
-    SystemSet, UiColor, With,
+    App, Button, Changed, Commands, Component, Entity, EventWriter, Interaction, Plugin, Query,
+    Res, ResMut, SystemSet, UiColor, With,
 };
-use fort_builders::game::GameAction;
+use fort_builders::pieces::Move;
 
 /// To hold the button text.
 const SKIP_TURN_BTN_TEXT: &str = "Skip Turn";
@@ -49,11 +52,19 @@ impl Plugin for SkipButtonPlugin {
 /*████Skip Turn Button Click████*/
 /*-----------------------------------------------------------------------------------------------*/
 /// To handle the 'skip turn' button being clicked.
+///
+/// The turn of the computer cannot be skipped, and neither can the turn of a player on another
+/// window of a network game. The skip is sent as a [`MoveIntent`]. A move that waits for a
+/// promotion is dropped.
+#[allow(clippy::too_many_arguments)]
 fn skip_turn_btn_clicked(
     mut commands: Commands,
     mut interaction_query: Query<BtnColorQuery, SkipTurnBtnQuery>,
     mut game: ResMut<GameAsset>,
     mut paths: ResMut<PossiblePaths>,
+    mut promotion: ResMut<PendingPromotion>,
+    mut intents: EventWriter<MoveIntent>,
+    session: Res<NetworkSession>,
     paths_query: Query<Entity, With<Paths>>,
     click_query: Query<Entity, With<Click>>,
 ) {
@@ -62,11 +73,14 @@ fn skip_turn_btn_clicked(
         .for_each(|(&interaction, mut color)| match interaction {
             Interaction::Clicked => {
                 *color = UiColor::from(style::BTN_CLICKD_COLOR);
-                game.get_mut()
-                    .next_player()
-                    .set_update_true()
-                    .set_picked_false();
+                let player = game.get().current_player();
+                if player.is_computer() || !session.controls(player.team) {
+                    return;
+                }
+                intents.send(MoveIntent(Move::skip()));
+                game.get_mut().set_picked_false();
                 paths.clear();
+                promotion.clear();
                 commands.despawn_entity(&click_query);
                 commands.despawn_entity(&paths_query);
             }
@@ -78,9 +92,16 @@ fn skip_turn_btn_clicked(
 
 /*████Skip Turn Button Setup████*/
 /*-----------------------------------------------------------------------------------------------*/
-/// To setup the `skip turn` button.
+/// To setup the `skip turn` button. A spectator has no turn to skip.
 #[inline]
-fn skip_turn_btn_spawn(mut commands: Commands, button: Res<BtnContainer>) {
+fn skip_turn_btn_spawn(
+    mut commands: Commands,
+    button: Res<BtnContainer>,
+    network_mode: Res<NetworkMode>,
+) {
+    if network_mode.is_watching() {
+        return;
+    }
     btn_spawn(&mut commands, &button, SKIP_TURN_BTN_TEXT, SkipTurnButton);
 }
 /*-----------------------------------------------------------------------------------------------*/
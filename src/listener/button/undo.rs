@@ -0,0 +1,165 @@
+//! undo module.
+//!
+//! To handle the `undo` button plugin and the `Ctrl + Z` key binding.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+use crate::{
+    despawn_entity::DespawnEntity,
+    game::{
+        network::{NetworkMode, NetworkSession},
+        GameAsset,
+    },
+    listener::{
+        button::{btn_spawn, promotion::PendingPromotion, style, BtnColorQuery, BtnContainer},
+        click::Click,
+        possible_paths::{Paths, PossiblePaths},
+    },
+    state::FortChessState,
+};
+use bevy::prelude::{
+    App, Button, Changed, Commands, Component, Entity, Input, Interaction, KeyCode, Plugin, Query,
+    Res, ResMut, SystemSet, UiColor, With,
+};
+use fort_builders::game::{Game, GameAction};
+
+/// To hold the button text.
+const UNDO_BTN_TEXT: &str = "Undo";
+
+/// Plugin to handle `undo` button.
+pub(crate) struct UndoButtonPlugin;
+/// To signify an Undo Button.
+#[derive(Component)]
+pub(crate) struct UndoButton;
+
+/// Type alias for undo button query.
+type UndoBtnQuery = (Changed<Interaction>, With<Button>, With<UndoButton>);
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Plugin for UndoButtonPlugin████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Plugin for UndoButtonPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_enter(FortChessState::GameBuild).with_system(undo_btn_spawn),
+        )
+        .add_system_set(
+            SystemSet::on_update(FortChessState::BoardScreen)
+                .with_system(undo_btn_clicked)
+                .with_system(undo_key_pressed),
+        );
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Undo████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To take back the last move and clear the chosen piece along with its paths, dropping a move
+/// that waits for a promotion.
+///
+/// The moves of the computer are taken back as well until it is the turn of a human, else the
+/// computer would play its move again. A move that cannot be taken back is reported and stops the
+/// undo. The moves of a network game are kept by the host and cannot be taken back.
+fn undo_last_move(
+    commands: &mut Commands,
+    session: &NetworkSession,
+    game: &mut Game,
+    paths: &mut ResMut<PossiblePaths>,
+    promotion: &mut ResMut<PendingPromotion>,
+    paths_query: &Query<Entity, With<Paths>>,
+    click_query: &Query<Entity, With<Click>>,
+) {
+    if !session.is_local() {
+        return;
+    }
+    loop {
+        match game.undo() {
+            Ok(Some(_)) if game.current_player().is_computer() => {}
+            Ok(_) => break,
+            Err(err) => {
+                eprintln!("{err}");
+                break;
+            }
+        }
+    }
+    game.set_update_true().set_picked_false();
+    paths.clear();
+    promotion.clear();
+    commands.despawn_entity(click_query);
+    commands.despawn_entity(paths_query);
+}
+
+/// To handle the 'undo' button being clicked.
+#[allow(clippy::too_many_arguments)]
+fn undo_btn_clicked(
+    mut commands: Commands,
+    mut interaction_query: Query<BtnColorQuery, UndoBtnQuery>,
+    mut game: ResMut<GameAsset>,
+    mut paths: ResMut<PossiblePaths>,
+    mut promotion: ResMut<PendingPromotion>,
+    session: Res<NetworkSession>,
+    paths_query: Query<Entity, With<Paths>>,
+    click_query: Query<Entity, With<Click>>,
+) {
+    interaction_query
+        .iter_mut()
+        .for_each(|(&interaction, mut color)| match interaction {
+            Interaction::Clicked => {
+                *color = UiColor::from(style::BTN_CLICKD_COLOR);
+                undo_last_move(
+                    &mut commands,
+                    &session,
+                    game.get_mut(),
+                    &mut paths,
+                    &mut promotion,
+                    &paths_query,
+                    &click_query,
+                );
+            }
+            Interaction::Hovered => *color = UiColor::from(style::BTN_HOVERD_COLOR),
+            Interaction::None => *color = UiColor::from(style::BTN_BKGRND_COLOR),
+        });
+}
+
+/// To undo the last move when `Ctrl + Z` is pressed.
+#[allow(clippy::too_many_arguments)]
+fn undo_key_pressed(
+    mut commands: Commands,
+    mut game: ResMut<GameAsset>,
+    mut paths: ResMut<PossiblePaths>,
+    mut promotion: ResMut<PendingPromotion>,
+    session: Res<NetworkSession>,
+    input: Res<Input<KeyCode>>,
+    paths_query: Query<Entity, With<Paths>>,
+    click_query: Query<Entity, With<Click>>,
+) {
+    if !(input.pressed(KeyCode::LControl) && input.just_pressed(KeyCode::Z)) {
+        return;
+    }
+    undo_last_move(
+        &mut commands,
+        &session,
+        game.get_mut(),
+        &mut paths,
+        &mut promotion,
+        &paths_query,
+        &click_query,
+    );
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Undo Button Setup████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To setup the `undo` button. A spectator has no moves to take back.
+#[inline]
+fn undo_btn_spawn(
+    mut commands: Commands,
+    button: Res<BtnContainer>,
+    network_mode: Res<NetworkMode>,
+) {
+    if network_mode.is_watching() {
+        return;
+    }
+    btn_spawn(&mut commands, &button, UNDO_BTN_TEXT, UndoButton);
+}
+/*-----------------------------------------------------------------------------------------------*/
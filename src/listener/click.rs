@@ -4,8 +4,12 @@
 
 use crate::{
     despawn_entity::DespawnEntity,
-    game::GameAsset,
+    game::{
+        network::{MoveIntent, NetworkSession},
+        GameAsset,
+    },
     listener::{
+        button::promotion::PendingPromotion,
         possible_paths::{
             draw_possible_piece_paths, update_possible_piece_paths, Paths, PossiblePaths,
         },
@@ -15,9 +19,12 @@ use crate::{
 };
 use bevy::{
     input::Input,
-    prelude::{Color, Commands, Component, Entity, MouseButton, Query, Res, ResMut, Vec3, With},
+    prelude::{
+        Button, Color, Commands, Component, Entity, EventWriter, Interaction, MouseButton, Query,
+        Res, ResMut, Vec3, With,
+    },
 };
-use fort_builders::{board::position_in_board_bounds, game::GameAction, player::PlayerAction};
+use fort_builders::{board::position_in_board_bounds, player::PlayerAction};
 
 /// Displays the clicked piece color.
 const CLICKS_COLOR: Color = Color::DARK_GRAY;
@@ -31,44 +38,66 @@ pub(crate) struct Click;
 ///
 /// Capturing the cursor position and checking if the mouse is within the board bounds. Only
 /// then do we start checking for the accurate position inside the player pieces. Doesn't
-/// proceed if left mouse button is not not clicked.
+/// proceed if left mouse button is not not clicked, if the click is on a button, if a promotion
+/// is waiting to be picked, if the computer is playing the turn or if the turn belongs to a player
+/// on another window of a network game, which is every turn for a spectator.
+///
+/// The move is sent as a [`MoveIntent`]. A move that promotes a pawn is held back in the
+/// [`PendingPromotion`] until the type is picked.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn click_listener(
     mut commands: Commands,
     mut game: ResMut<GameAsset>,
     mut paths: ResMut<PossiblePaths>,
+    mut promotion: ResMut<PendingPromotion>,
+    mut intents: EventWriter<MoveIntent>,
+    session: Res<NetworkSession>,
     click: Res<Input<MouseButton>>,
     clicks: Query<Entity, With<Click>>,
     cursor: Res<CursorPosition>,
     paths_query: Query<Entity, With<Paths>>,
+    buttons: Query<&Interaction, With<Button>>,
 ) {
     let (m_x, m_y) = (cursor.x, cursor.y);
     if !position_in_board_bounds(m_x, m_y) || !click.just_pressed(MouseButton::Left) {
         return;
     }
+    if promotion.is_pending()
+        || buttons
+            .iter()
+            .any(|interaction| interaction != &Interaction::None)
+    {
+        return;
+    }
     let game = game.get_mut();
+    if game.current_player().is_computer() || !session.controls(game.current_player().team) {
+        return;
+    }
     commands.despawn_entity(&clicks);
     match game.picked {
         true => {
-            if paths.contains(m_x, m_y) {
-                let _killed_piece = game.remove_piece_in_pos(m_x, m_y).unwrap();
-                game.update_position(m_x as i32, m_y as i32)
-                    .unwrap()
-                    .next_player();
+            match paths.find(m_x, m_y) {
+                Some(step) if step.promotion.is_some() => {
+                    promotion.set(step);
+                    return;
+                }
+                Some(step) => intents.send(MoveIntent(step)),
+                None => {}
             }
             game.set_picked_false();
             commands.despawn_entity(&paths_query);
             paths.clear();
         }
         false => {
-            let Ok(index) = game.current_player().piece_index_from_xy_f32(m_x, m_y) else {
+            let Ok(index) = game.current_player().piece_index_from_xy_i32(m_x, m_y) else {
                 return
             };
             let click = spawn_square_sprite(
                 &mut commands,
                 CLICKS_COLOR,
                 Vec3::new(
-                    m_x * RESOLUTION,
-                    m_y * RESOLUTION,
+                    m_x as f32 * RESOLUTION,
+                    m_y as f32 * RESOLUTION,
                     ZAxisLevel::Seventh.as_f32(),
                 ),
             );
@@ -76,7 +105,7 @@ pub(crate) fn click_listener(
             game.current_player_mut().set_chosen_piece_index(index);
             game.set_picked_true();
             update_possible_piece_paths(game, &mut paths);
-            draw_possible_piece_paths(&mut commands, &paths, &paths_query, game);
+            draw_possible_piece_paths(&mut commands, &paths, &paths_query);
         }
     }
 }
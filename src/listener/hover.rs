@@ -5,7 +5,7 @@
 
 use crate::{
     despawn_entity::DespawnEntity,
-    game::GameAsset,
+    game::{network::NetworkMode, GameAsset},
     listener::{spawn_square_sprite, CursorPosition},
     ZAxisLevel, RESOLUTION,
 };
@@ -36,21 +36,25 @@ pub(crate) fn clear_picker(mut commands: Commands, pickers: Query<Entity, With<P
 /// Return a bool value that is checked at each [`CursorMoved`] event. If the cursor position is
 /// not a piece position then there won't be a light grey block displayed.
 #[inline]
-fn hovered_position_in_player_pieces(x: f32, y: f32, game: &ResMut<GameAsset>) -> bool {
+fn hovered_position_in_player_pieces(x: i32, y: i32, game: &ResMut<GameAsset>) -> bool {
     game.get()
         .current_player()
-        .piece_index_from_xy_f32(x, y)
+        .piece_index_from_xy_i32(x, y)
         .is_ok()
 }
 
 /// To display a light gray block over the piece where the mouse is hovering.
 ///
-/// Early return if not in player pieces or inside board bounds.
+/// Early return if not in player pieces or inside board bounds. Nothing is shown to a spectator.
 pub(crate) fn hover_listener(
     mut commands: Commands,
     game: ResMut<GameAsset>,
     cursor: Res<CursorPosition>,
+    network_mode: Res<NetworkMode>,
 ) {
+    if network_mode.is_watching() {
+        return;
+    }
     let (m_x, m_y) = (cursor.x, cursor.y);
     if !position_in_board_bounds(m_x, m_y) || !hovered_position_in_player_pieces(m_x, m_y, &game) {
         return;
@@ -59,8 +63,8 @@ pub(crate) fn hover_listener(
         &mut commands,
         PICKER_COLOR,
         Vec3::new(
-            m_x * RESOLUTION,
-            m_y * RESOLUTION,
+            m_x as f32 * RESOLUTION,
+            m_y as f32 * RESOLUTION,
             ZAxisLevel::Sixth.as_f32(),
         ),
     );
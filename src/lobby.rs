@@ -0,0 +1,411 @@
+//! lobby module.
+//!
+//! Module to handle the lobby between the start screen and the board. Every player typed on the
+//! start screen gets a seat with the team it plays. The players can claim another free team, ask
+//! to play the defender and mark themselves as ready, and the game is started once every player is
+//! ready.
+//!
+//! On a single window every seat is changed from here. In a network game only the seat of this
+//! window can be changed, the seats shown are the ones sent by the host and only the window of the
+//! host can start the game. A spectator sees the seats without changing any of them.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+//-----------//
+mod seat_btn;
+//-----------//
+
+use crate::{
+    despawn_entity::DespawnEntity,
+    font::{BoldFontHandle, RegFontHandle, DEFAULT_FONT_CLR},
+    game::{
+        computer::ComputerLevels,
+        network::{NetworkMode, NetworkSession},
+        player_name::color_from_team,
+        GameAsset,
+    },
+    startscreen::NameEntryValue,
+    state::FortChessState,
+    ZAxisLevel, RESOLUTION,
+};
+use bevy::prelude::{
+    default, AlignItems, AlignSelf, App, BuildChildren, ChildBuilder, Color, Commands, Component,
+    Entity, FlexDirection, JustifyContent, NodeBundle, Plugin, Query, Res, ResMut, Size, State,
+    Style, SystemSet, Text, Text2dBundle, TextBundle, TextStyle, Transform, UiColor, UiRect, Val,
+    With,
+};
+use fort_builders::{
+    dice::SeededDice,
+    game::Game,
+    lobby::{self, Lobby, Seat},
+    player::{PlayerKind, Team},
+};
+use fort_protocol::message::HostMessage;
+use seat_btn::{spawn_seat_btn, ButtonType, SeatBtnPlugin};
+
+/// Color of the lobby UI node.
+const LOBBY_UI_COLOR: Color = Color::rgba(0.2_f32, 0.3_f32, 0.1_f32, 0.25_f32);
+/// Lobby title font size.
+const LOBBY_TITLE_SIZE: f32 = 96_f32;
+/// Size of the text of the seats.
+const SEAT_TEXT_SIZE: f32 = 36_f32;
+/// Size of the main lobby UI node.
+const LOBBY_NODE: (f32, f32) = (900_f32, 500_f32);
+/// The text of a seat no one holds.
+const FREE_SEAT_TEXT: &str = "Free";
+
+/// [`Plugin`] to handle the lobby.
+pub(crate) struct LobbyPlugin;
+/// To hold the seats of the lobby shown on the screen.
+pub(crate) struct LobbyScreen {
+    /// The seats of the players.
+    lobby: Lobby,
+    /// If the seats changed since they were last drawn.
+    update: bool,
+    /// The message shown under the seats, like why the game cannot start yet.
+    status: String,
+}
+/// [`Component`] to identify the lobby UI node.
+#[derive(Component)]
+struct LobbyNode;
+/// [`Component`] to identify the lobby title.
+#[derive(Component)]
+struct LobbyTitle;
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Plugin for LobbyPlugin████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Plugin for LobbyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_enter(FortChessState::Lobby)
+                .with_system(open_lobby)
+                .with_system(title_text),
+        )
+        .add_system_set(
+            SystemSet::on_update(FortChessState::Lobby)
+                .with_system(receive_lobby_messages)
+                .with_system(draw_lobby),
+        )
+        .add_system_set(SystemSet::on_exit(FortChessState::Lobby).with_system(close_lobby))
+        .add_plugin(SeatBtnPlugin);
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████LobbyScreen████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl LobbyScreen {
+    /// To create the screen for the seats.
+    #[inline]
+    fn new(lobby: Lobby) -> Self {
+        LobbyScreen {
+            lobby,
+            update: true,
+            status: String::new(),
+        }
+    }
+    /// To get the seats of the players.
+    #[inline]
+    pub(crate) fn lobby(&self) -> &Lobby {
+        &self.lobby
+    }
+    /// To get the seats of the players to change them, which draws them again.
+    #[inline]
+    pub(crate) fn lobby_mut(&mut self) -> &mut Lobby {
+        self.update = true;
+        &mut self.lobby
+    }
+    /// To show the message under the seats.
+    #[inline]
+    pub(crate) fn set_status(&mut self, status: impl ToString) {
+        self.status = status.to_string();
+        self.update = true;
+    }
+}
+
+/// To describe why the lobby turned a request away, without the colors of the terminal.
+pub(crate) fn describe_error(err: &lobby::Error) -> String {
+    match err {
+        lobby::Error::NoFreeSeat => String::from("There is no free seat left"),
+        lobby::Error::SeatTaken(team) => format!("The {team:?} seat is already taken"),
+        lobby::Error::EmptySeat(team) => format!("No one holds the {team:?} seat"),
+        lobby::Error::NotReady(name) => format!("{name} is not ready yet"),
+        lobby::Error::TooFewPlayers(_) => String::from("There needs to be a minimum of 2 players"),
+    }
+}
+
+/// To seat the players of the start screen and open the session of the network game, if any.
+///
+/// A hosted lobby only holds the players of the computer to begin with, the others take their seat
+/// when they join. The players of a local game are all seated on this window.
+fn open_lobby(
+    mut commands: Commands,
+    name_entry_value: Res<NameEntryValue>,
+    network_mode: Res<NetworkMode>,
+) {
+    let mut lobby = Lobby::default();
+    name_entry_value
+        .entries()
+        .filter(|(_, kind)| network_mode.is_local() || *kind == PlayerKind::Computer)
+        .for_each(|(name, kind)| {
+            lobby.sit(name, kind).unwrap_or_else(|err| panic!("{err}"));
+        });
+    let session = network_mode
+        .enter(
+            &name_entry_value.local_name(),
+            lobby.clone(),
+            name_entry_value.rules(),
+        )
+        .unwrap_or_else(|err| panic!("{err}"));
+    commands.insert_resource(LobbyScreen::new(lobby));
+    commands.insert_resource(session);
+}
+
+/// To remove the lobby when the game starts.
+fn close_lobby(
+    mut commands: Commands,
+    node: Query<Entity, With<LobbyNode>>,
+    title: Query<Entity, With<LobbyTitle>>,
+) {
+    commands.despawn_entity(&node);
+    commands.despawn_entity(&title);
+    commands.remove_resource::<LobbyScreen>();
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Start████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To start the local game with the seated players, or to ask the host to start it.
+///
+/// The computers of a local game play at the difficulty picked for them on the start screen.
+pub(crate) fn start_game(
+    commands: &mut Commands,
+    screen: &mut LobbyScreen,
+    session: &NetworkSession,
+    name_entry_value: &NameEntryValue,
+    state: &mut State<FortChessState>,
+) {
+    if !session.is_local() {
+        if let Err(err) = session.request(lobby::LobbyRequest::Start) {
+            eprintln!("{err}");
+        }
+        return;
+    }
+    let game = match screen.lobby.check() {
+        Ok(()) => screen
+            .lobby
+            .start(SeededDice::from_entropy(), name_entry_value.rules())
+            .unwrap_or_else(|err| panic!("{err}")),
+        Err(err) => {
+            screen.set_status(describe_error(&err));
+            return;
+        }
+    };
+    commands.insert_resource(ComputerLevels::from(screen.lobby.seats().iter().map(
+        |seat| {
+            seat.name
+                .as_deref()
+                .and_then(|name| name_entry_value.difficulty_of(name))
+        },
+    )));
+    commands.insert_resource(GameAsset(game));
+    state.set(FortChessState::GameBuild).unwrap_or_default();
+}
+
+/// To follow the seats sent by the host and to start the game once the host sends it.
+///
+/// The turns of the computers of a network game are played by the host.
+fn receive_lobby_messages(
+    mut commands: Commands,
+    session: Res<NetworkSession>,
+    mut screen: ResMut<LobbyScreen>,
+    mut state: ResMut<State<FortChessState>>,
+) {
+    loop {
+        let message = match session.poll() {
+            Ok(Some(message)) => message,
+            Ok(None) => return,
+            Err(err) => {
+                eprintln!("{err}");
+                screen.set_status("The connection to the host was lost");
+                return;
+            }
+        };
+        match message {
+            HostMessage::Lobby { seats } => *screen.lobby_mut() = Lobby::from_seats(seats),
+            HostMessage::Welcome { .. } => screen.update = true,
+            HostMessage::Rejected { reason } => {
+                eprintln!("The host refused the request: {reason}");
+                screen.set_status("The host refused the request");
+            }
+            HostMessage::Snapshot { snapshot } => match Game::try_from(snapshot) {
+                Ok(game) => {
+                    commands.insert_resource(ComputerLevels::default());
+                    commands.insert_resource(GameAsset(game));
+                    state.set(FortChessState::GameBuild).unwrap_or_default();
+                    return;
+                }
+                Err(err) => eprintln!("{err}"),
+            },
+            _ => {}
+        }
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Lobby UI████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// Lobby title text.
+fn title_text(mut commands: Commands, font: Res<BoldFontHandle>) {
+    commands
+        .spawn_bundle(Text2dBundle {
+            text: Text::from_section(
+                "Lobby",
+                TextStyle {
+                    font: font.get().clone(),
+                    font_size: LOBBY_TITLE_SIZE,
+                    color: DEFAULT_FONT_CLR,
+                },
+            ),
+            transform: Transform::from_xyz(
+                -2_f32 * RESOLUTION,
+                7_f32 * RESOLUTION,
+                ZAxisLevel::First.as_f32(),
+            ),
+            ..default()
+        })
+        .insert(LobbyTitle);
+}
+
+/// To draw the seats again whenever they change.
+///
+/// The buttons of a seat are only shown if the seat is played on this window, and the start button
+/// only if the game can be started from this window.
+fn draw_lobby(
+    mut commands: Commands,
+    mut screen: ResMut<LobbyScreen>,
+    session: Res<NetworkSession>,
+    network_mode: Res<NetworkMode>,
+    font: Res<RegFontHandle>,
+    node: Query<Entity, With<LobbyNode>>,
+) {
+    if !screen.update {
+        return;
+    }
+    screen.update = false;
+    commands.despawn_entity(&node);
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100_f32), Val::Percent(100_f32)),
+                align_self: AlignSelf::Center,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            color: UiColor::from(Color::NONE),
+            ..default()
+        })
+        .insert(LobbyNode)
+        .with_children(|commands| {
+            commands
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Px(LOBBY_NODE.0), Val::Px(LOBBY_NODE.1)),
+                        justify_content: JustifyContent::SpaceAround,
+                        flex_direction: FlexDirection::ColumnReverse,
+                        padding: UiRect::all(Val::Percent(2_f32)),
+                        ..default()
+                    },
+                    color: UiColor::from(LOBBY_UI_COLOR),
+                    ..default()
+                })
+                .with_children(|commands| {
+                    screen.lobby.seats().iter().for_each(|seat| {
+                        let controlled = !seat.is_free() && session.controls(seat.team);
+                        seat_row(commands, &font, seat, controlled);
+                    });
+                    status_row(commands, &font, &screen.status, network_mode.can_start());
+                });
+        });
+}
+
+/// To spawn a text of the lobby.
+#[inline]
+fn seat_text(commands: &mut ChildBuilder, font: &Res<RegFontHandle>, text: &str, color: Color) {
+    commands.spawn_bundle(TextBundle::from_section(
+        text,
+        TextStyle {
+            font: font.get().clone(),
+            font_size: SEAT_TEXT_SIZE,
+            color,
+        },
+    ));
+}
+
+/// To spawn a row node of the lobby.
+#[inline]
+fn row_style() -> Style {
+    Style {
+        size: Size::new(Val::Percent(100_f32), Val::Percent(16_f32)),
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::SpaceBetween,
+        ..default()
+    }
+}
+
+/// To spawn the row of a seat with the team, the name of the player and its buttons.
+fn seat_row(commands: &mut ChildBuilder, font: &Res<RegFontHandle>, seat: &Seat, controlled: bool) {
+    commands
+        .spawn_bundle(NodeBundle {
+            style: row_style(),
+            color: UiColor::from(Color::NONE),
+            ..default()
+        })
+        .with_children(|commands| {
+            seat_text(
+                commands,
+                font,
+                Team::teamstr_from_team(seat.team),
+                color_from_team(seat.team),
+            );
+            seat_text(
+                commands,
+                font,
+                seat.name.as_deref().unwrap_or(FREE_SEAT_TEXT),
+                DEFAULT_FONT_CLR,
+            );
+            if !controlled {
+                return;
+            }
+            let role = match seat.defender {
+                true => "Defender",
+                false => "Attacker",
+            };
+            let ready = match seat.ready {
+                true => "Ready",
+                false => "Not Ready",
+            };
+            spawn_seat_btn(commands, font, "Colour", ButtonType::Colour(seat.team));
+            spawn_seat_btn(commands, font, role, ButtonType::Defender(seat.team));
+            spawn_seat_btn(commands, font, ready, ButtonType::Ready(seat.team));
+        });
+}
+
+/// To spawn the row with the start button and the status message.
+fn status_row(commands: &mut ChildBuilder, font: &Res<RegFontHandle>, status: &str, start: bool) {
+    commands
+        .spawn_bundle(NodeBundle {
+            style: row_style(),
+            color: UiColor::from(Color::NONE),
+            ..default()
+        })
+        .with_children(|commands| {
+            seat_text(commands, font, status, Color::RED);
+            if start {
+                spawn_seat_btn(commands, font, "Start", ButtonType::Start);
+            }
+        });
+}
+/*-----------------------------------------------------------------------------------------------*/
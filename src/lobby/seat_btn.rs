@@ -0,0 +1,205 @@
+//! seat button module.
+//!
+//! Module to handle the buttons of the seats and the start button of the lobby.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+mod style {
+    use bevy::prelude::Color;
+    /// Color when the button is clicked.
+    pub(crate) const SEAT_BTN_CLICK: Color = Color::DARK_GRAY;
+    /// Color when the button is hovered.
+    pub(crate) const SEAT_BTN_HOVER: Color = Color::GRAY;
+    /// Color when the button is idle.
+    pub(crate) const SEAT_BTN_NORML: Color = Color::SILVER;
+    /// Size of the font of buttons.
+    pub(crate) const SEAT_BTN_TEXT_SIZE: f32 = 28_f32;
+}
+
+use crate::{
+    font::{RegFontHandle, DEFAULT_FONT_CLR},
+    game::network::NetworkSession,
+    lobby::{describe_error, start_game, LobbyScreen},
+    startscreen::NameEntryValue,
+    state::FortChessState,
+};
+use bevy::prelude::{
+    default, AlignItems, App, BuildChildren, Button, ButtonBundle, Changed, ChildBuilder, Children,
+    Color, Commands, Component, Interaction, JustifyContent, Plugin, Query, Res, ResMut, Size,
+    State, Style, SystemSet, Text, TextBundle, TextStyle, UiColor, UiRect, Val, With,
+};
+use fort_builders::{lobby::LobbyRequest, player::Team};
+
+/// [`Component`] to identify a seat button.
+#[derive(Component, Debug)]
+struct ButtonComp {
+    btn_type: ButtonType,
+}
+/// To determine the button type and appropriate action.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum ButtonType {
+    /// To move to the next free seat.
+    Colour(Team),
+    /// To ask to play the defender, or to stop asking.
+    Defender(Team),
+    /// To mark the seat as ready, or not.
+    Ready(Team),
+    /// To start the game.
+    Start,
+}
+/// [`Component`] to identify seat button text.
+#[derive(Component)]
+struct ButtonText;
+/// [`Plugin`] to handle the seat buttons.
+pub(crate) struct SeatBtnPlugin;
+
+/// Type alias for seat button query.
+type SeatBtnQuery = (Changed<Interaction>, With<Button>, With<ButtonComp>);
+/// Type alias for seat button color query.
+type SeatBtnColorQuery<'a> = (
+    &'a Interaction,
+    &'a mut UiColor,
+    &'a Children,
+    &'a ButtonComp,
+);
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Plugin for SeatBtnPlugin████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Plugin for SeatBtnPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_update(FortChessState::Lobby).with_system(seat_btn_click));
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Spawn Seat Button████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To spawn a button UI node with the given text and button type.
+pub(crate) fn spawn_seat_btn(
+    commands: &mut ChildBuilder,
+    font: &Res<RegFontHandle>,
+    text: &str,
+    btn_type: ButtonType,
+) {
+    commands
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Percent(18_f32), Val::Percent(80_f32)),
+                padding: UiRect::all(Val::Percent(1_f32)),
+                justify_content: JustifyContent::SpaceAround,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: UiColor::from(style::SEAT_BTN_NORML),
+            ..default()
+        })
+        .with_children(|commands| {
+            commands
+                .spawn_bundle(TextBundle::from_section(
+                    text,
+                    TextStyle {
+                        font: font.get().clone(),
+                        font_size: style::SEAT_BTN_TEXT_SIZE,
+                        color: DEFAULT_FONT_CLR,
+                    },
+                ))
+                .insert(ButtonText);
+        })
+        .insert(ButtonComp { btn_type });
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Button Click████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To listen when the buttons of the lobby are clicked and handle them appropriately.
+fn seat_btn_click(
+    mut commands: Commands,
+    mut seat_btn_query: Query<SeatBtnColorQuery, SeatBtnQuery>,
+    mut text_query: Query<&mut Text, With<ButtonText>>,
+    mut screen: ResMut<LobbyScreen>,
+    session: Res<NetworkSession>,
+    name_entry_value: Res<NameEntryValue>,
+    mut state: ResMut<State<FortChessState>>,
+) {
+    seat_btn_query
+        .iter_mut()
+        .for_each(|(&interaction, mut color, children, btn_comp)| {
+            let text_color = &mut text_query
+                .get_mut(children[0])
+                .expect("button does not have text inside")
+                .sections[0]
+                .style
+                .color;
+            match interaction {
+                Interaction::Clicked => {
+                    *color = UiColor::from(style::SEAT_BTN_CLICK);
+                    *text_color = Color::WHITE;
+                    match btn_comp.btn_type {
+                        ButtonType::Start => start_game(
+                            &mut commands,
+                            &mut screen,
+                            &session,
+                            &name_entry_value,
+                            &mut state,
+                        ),
+                        btn_type => click(btn_type, &mut screen, &session),
+                    }
+                }
+                Interaction::Hovered => {
+                    *color = UiColor::from(style::SEAT_BTN_HOVER);
+                    *text_color = DEFAULT_FONT_CLR;
+                }
+                Interaction::None => {
+                    *color = UiColor::from(style::SEAT_BTN_NORML);
+                    *text_color = DEFAULT_FONT_CLR;
+                }
+            }
+        });
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Seat Button Click████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To turn the button of the seat into the request of its player.
+///
+/// The colour button claims the next free seat, while the other two toggle what the seat shows.
+#[inline]
+fn request_of(btn_type: ButtonType, screen: &LobbyScreen) -> Option<(Team, LobbyRequest)> {
+    match btn_type {
+        ButtonType::Colour(team) => screen
+            .lobby()
+            .next_free(team)
+            .map(|free| (team, LobbyRequest::Claim(free))),
+        ButtonType::Defender(team) => Some((
+            team,
+            LobbyRequest::Defender(!screen.lobby().seat(team).defender),
+        )),
+        ButtonType::Ready(team) => {
+            Some((team, LobbyRequest::Ready(!screen.lobby().seat(team).ready)))
+        }
+        ButtonType::Start => None,
+    }
+}
+
+/// To process when a button of a seat is clicked.
+///
+/// The seats of a local game are changed right away. In a network game the request is sent to the
+/// host, and the seats change once the host sends them back.
+fn click(btn_type: ButtonType, screen: &mut LobbyScreen, session: &NetworkSession) {
+    let (team, request) = match request_of(btn_type, screen) {
+        Some(request) => request,
+        None => return,
+    };
+    if !session.is_local() {
+        if let Err(err) = session.request(request) {
+            eprintln!("{err}");
+        }
+        return;
+    }
+    match screen.lobby_mut().request(team, &request) {
+        Ok(_) => screen.set_status(""),
+        Err(err) => screen.set_status(describe_error(&err)),
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
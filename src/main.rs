@@ -20,6 +20,7 @@ mod despawn_entity;
 mod font;
 mod game;
 mod listener;
+mod lobby;
 mod startscreen;
 mod state;
 mod tiles;
@@ -41,6 +42,7 @@ use fort_builders::{
 };
 use game::GamePlugin;
 use listener::ListenerPlugin;
+use lobby::LobbyPlugin;
 use startscreen::MainScreenPlugin;
 use state::FortChessState;
 use tiles::TilePlugin;
@@ -142,6 +144,9 @@ fn setup(mut commands: Commands) {
 
 /// To set a custom panic statement format.
 ///
+/// The latest snapshot of the game is flushed to the autosave before printing so that the game
+/// can be resumed from the start screen.
+///
 /// ## Format:
 /// ```text
 /// $ERROR: thread panicked at: [panic_location] called `error_source' on an 'error_type' with
@@ -149,6 +154,7 @@ fn setup(mut commands: Commands) {
 /// ```
 fn set_panic_hook_fmt() {
     std::panic::set_hook(Box::new(|info| {
+        game::save::flush_autosave();
         println!(
             "{RED}ERROR:{RST} [{}] {:?}",
             info.location()
@@ -162,7 +168,7 @@ fn tmp_state_change(mut state: ResMut<State<FortChessState>>, keyboard: Res<Inpu
     if keyboard.just_pressed(KeyCode::Return) {
         match state.current() {
             FortChessState::StartScreen => {
-                state.set(FortChessState::GameBuild).unwrap_or_default();
+                state.set(FortChessState::Lobby).unwrap_or_default();
             }
             FortChessState::GameBuild => {
                 state.set(FortChessState::BoardScreen).unwrap_or_default();
@@ -190,6 +196,7 @@ fn main() {
         .add_startup_system(setup)
         .add_plugins(DefaultPlugins)
         .add_plugin(MainScreenPlugin)
+        .add_plugin(LobbyPlugin)
         .add_plugin(TilePlugin)
         .add_plugin(ListenerPlugin)
         .add_plugin(GamePlugin)
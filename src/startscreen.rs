@@ -4,9 +4,11 @@
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
 //-----------//
+mod computer;
 mod expand;
 mod name_input;
 mod startbtn;
+mod variant;
 //-----------//
 
 use crate::{
@@ -21,12 +23,19 @@ use bevy::prelude::{
     Size, Style, SystemSet, Text, Text2dBundle, TextBundle, TextStyle, Transform, UiColor, UiImage,
     UiRect, Val, Visibility, With,
 };
+use computer::{spawn_computer_toggle, ComputerTogglePlugin};
 use expand::{
     style, ExpandBtnImage, ExpandTextInputButton, ExpandTextInputButtonPlugin, InputBoxNode,
     TextInputId,
 };
+use fort_builders::{
+    player::PlayerKind,
+    ruleset::{RuleSet, Variant},
+};
+use fort_engine::difficulty::Difficulty;
 use name_input::{NameInput, NameInputPlugin, NameInputText};
 use startbtn::{spawn_start_btn, StartBtnPlugin};
+use variant::VariantTogglePlugin;
 
 /// Player name UI color.
 const PLNAME_UI_COLOR: Color = Color::rgba(0.2_f32, 0.3_f32, 0.1_f32, 0.25_f32);
@@ -40,6 +49,8 @@ const MAIN_TITLE_SIZE: f32 = 96_f32;
 const TEXT_INPUT_NODE: (f32, f32) = (700_f32, 300_f32);
 /// Placeholder for name.
 pub(crate) const TEXT_INPUT_DEF_VAL: &str = "Enter your name";
+/// Name of the players played by the computer, followed by the number of the input box.
+const COMPUTER_NAME: &str = "Computer";
 
 pub(crate) trait FromBool {
     fn from_bool(value: bool) -> Self;
@@ -56,6 +67,10 @@ struct MainTitle;
 #[derive(Debug)]
 pub(crate) struct NameEntryValue {
     players: [String; 4_usize],
+    /// The difficulty of the computer, if the player of the input box is played by the computer.
+    computers: [Option<Difficulty>; 4_usize],
+    /// The variant of the rules the new game is played with.
+    variant: Variant,
 }
 
 /*████Functions██████████████████████████████████████████████████████████████████████████████████*/
@@ -76,7 +91,9 @@ impl Plugin for MainScreenPlugin {
         )
         .add_plugin(ExpandTextInputButtonPlugin)
         .add_plugin(StartBtnPlugin)
-        .add_plugin(NameInputPlugin);
+        .add_plugin(NameInputPlugin)
+        .add_plugin(ComputerTogglePlugin)
+        .add_plugin(VariantTogglePlugin);
     }
 }
 /*-----------------------------------------------------------------------------------------------*/
@@ -94,23 +111,99 @@ impl Default for NameEntryValue {
                 String::default(),
                 String::default(),
             ],
+            computers: [None; _],
+            variant: Variant::default(),
         }
     }
 }
 
 impl NameEntryValue {
-    /// Count of player names entered.
+    /// To get the names entered along with who plays them, counting the players played by the
+    /// computer. The empty input boxes are skipped.
+    #[inline]
+    pub(crate) fn entries(&self) -> impl Iterator<Item = (String, PlayerKind)> + '_ {
+        (usize::MIN..self.players.len())
+            .filter_map(|index| Some((self.as_string(index).ok()?, self.kind(index))))
+            .filter(|(name, _)| !name.is_empty())
+    }
+    /// To get the name of the first player that is not played by the computer, which is the one
+    /// played on this window in a network game.
     #[inline]
-    pub(crate) fn count(&self) -> usize {
-        self.players.iter().filter(|name| !name.is_empty()).count()
+    pub(crate) fn local_name(&self) -> String {
+        self.entries()
+            .find(|(_, kind)| *kind == PlayerKind::Human)
+            .map(|(name, _)| name)
+            .unwrap_or_default()
     }
     /// To get the string in the given position.
+    ///
+    /// A player played by the computer is named after the input box.
     #[inline]
     pub(crate) fn as_string(&self, index: usize) -> Result<String, usize> {
         if index >= self.players.len() {
             return Err(index);
         }
-        Ok(self.players[index].clone())
+        match self.computers[index].is_some() {
+            true => Ok(format!("{COMPUTER_NAME}{}", index + 1_usize)),
+            false => Ok(self.players[index].clone()),
+        }
+    }
+    /// To check if the player in the given position is played by the computer.
+    #[inline]
+    pub(crate) fn is_computer(&self, index: usize) -> bool {
+        self.difficulty(index).is_some()
+    }
+    /// To get the difficulty of the computer playing the player in the given position.
+    #[inline]
+    pub(crate) fn difficulty(&self, index: usize) -> Option<Difficulty> {
+        self.computers.get(index).copied().flatten()
+    }
+    /// To get the difficulty of the computer player with the given name.
+    #[inline]
+    pub(crate) fn difficulty_of(&self, name: &str) -> Option<Difficulty> {
+        (usize::MIN..self.players.len())
+            .filter(|&index| self.as_string(index).map_or(false, |entry| entry == name))
+            .find_map(|index| self.difficulty(index))
+    }
+    /// To get who plays the player in the given position.
+    #[inline]
+    pub(crate) fn kind(&self, index: usize) -> PlayerKind {
+        match self.is_computer(index) {
+            true => PlayerKind::Computer,
+            false => PlayerKind::Human,
+        }
+    }
+    /// To switch the player in the given position to the next difficulty of the computer.
+    ///
+    /// A human is switched to the easiest computer and the hardest computer back to a human. The
+    /// typed name is cleared either way.
+    #[inline]
+    pub(crate) fn toggle_computer(&mut self, index: usize) {
+        if let (Some(name), Some(computer)) =
+            (self.players.get_mut(index), self.computers.get_mut(index))
+        {
+            name.clear();
+            *computer = match *computer {
+                None => Some(Difficulty::Easy),
+                Some(Difficulty::Hard) => None,
+                Some(difficulty) => Some(difficulty.next()),
+            };
+        }
+    }
+    /// To get the variant of the rules picked for the new game.
+    #[inline]
+    pub(crate) fn variant(&self) -> Variant {
+        self.variant
+    }
+    /// To get the rules of the picked variant.
+    #[inline]
+    pub(crate) fn rules(&self) -> RuleSet {
+        self.variant.rules()
+    }
+    /// To switch to the next variant of the rules.
+    #[inline]
+    pub(crate) fn next_variant(&mut self) {
+        self.variant = self.variant.next();
     }
 }
 
@@ -191,6 +284,11 @@ fn text_box_sprite(
     if expandable {
         expand_btn(commands, asset_server, textinputid);
     }
+    let input_box = InputBoxNode {
+        expandable,
+        id: textinputid,
+    };
+    let index = input_box.as_usize();
     commands
         .spawn_bundle(NodeBundle {
             style: Style {
@@ -204,15 +302,12 @@ fn text_box_sprite(
             color: UiColor::from(Color::NONE),
             ..default()
         })
-        .insert(InputBoxNode {
-            expandable,
-            id: textinputid,
-        })
+        .insert(input_box)
         .with_children(|commands| {
             commands
                 .spawn_bundle(ButtonBundle {
                     style: Style {
-                        size: Size::new(Val::Percent(100_f32), Val::Percent(100_f32)),
+                        size: Size::new(Val::Percent(75_f32), Val::Percent(100_f32)),
                         align_items: AlignItems::Center,
                         ..default()
                     },
@@ -232,6 +327,7 @@ fn text_box_sprite(
                         ))
                         .insert(NameInputText);
                 });
+            spawn_computer_toggle(commands, font, index);
         });
 }
 
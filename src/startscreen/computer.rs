@@ -0,0 +1,146 @@
+//! computer module.
+//!
+//! Module to handle the toggle that lets the computer play a player instead of a name. Each click
+//! switches to the next difficulty of the computer, and the hardest one switches back to a human.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+mod style {
+    use bevy::prelude::Color;
+    /// Color when the toggle is hovered.
+    pub(crate) const TOGGLE_HOVER: Color = Color::GRAY;
+    /// Color when the player is played by a human.
+    pub(crate) const TOGGLE_HUMAN: Color = Color::SILVER;
+    /// Color when the player is played by the computer.
+    pub(crate) const TOGGLE_COMPUTER: Color = Color::DARK_GRAY;
+}
+
+use crate::{
+    font::{RegFontHandle, DEFAULT_FONT_CLR},
+    startscreen::NameEntryValue,
+    FortChessState,
+};
+use bevy::prelude::{
+    default, AlignItems, App, BuildChildren, Button, ButtonBundle, Changed, ChildBuilder,
+    Component, Interaction, JustifyContent, Plugin, Query, Res, ResMut, Size, Style, SystemSet,
+    Text, TextBundle, TextStyle, UiColor, Val, With,
+};
+
+/// The text of the toggle when the player is played by a human.
+const TOGGLE_TEXT: &str = "CPU";
+
+/// [`Component`] to identify the computer toggle of an input box.
+#[derive(Component)]
+pub(crate) struct ComputerToggle {
+    /// The index of the player in the [`NameEntryValue`].
+    index: usize,
+}
+/// [`Component`] to identify the text of a computer toggle.
+#[derive(Component)]
+pub(crate) struct ComputerToggleText {
+    /// The index of the player in the [`NameEntryValue`].
+    index: usize,
+}
+/// [`Plugin`] to handle the computer toggles.
+pub(crate) struct ComputerTogglePlugin;
+
+/// Type alias for computer toggle query.
+type ComputerToggleQuery = (Changed<Interaction>, With<Button>, With<ComputerToggle>);
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Plugin for ComputerTogglePlugin████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Plugin for ComputerTogglePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_update(FortChessState::StartScreen)
+                .with_system(computer_toggle_click)
+                .with_system(computer_toggle_color)
+                .with_system(computer_toggle_text),
+        );
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Spawn Computer Toggle████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To spawn the computer toggle next to the name input of the player at the given index.
+pub(crate) fn spawn_computer_toggle(
+    commands: &mut ChildBuilder,
+    font: &Res<RegFontHandle>,
+    index: usize,
+) {
+    commands
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Percent(25_f32), Val::Percent(100_f32)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            color: UiColor::from(style::TOGGLE_HUMAN),
+            ..default()
+        })
+        .insert(ComputerToggle { index })
+        .with_children(|commands| {
+            commands
+                .spawn_bundle(TextBundle::from_section(
+                    TOGGLE_TEXT,
+                    TextStyle {
+                        font: font.get().clone(),
+                        color: DEFAULT_FONT_CLR,
+                        ..default()
+                    },
+                ))
+                .insert(ComputerToggleText { index });
+        });
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Computer Toggle Click████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To switch the player to the next difficulty of the computer when the toggle is clicked.
+fn computer_toggle_click(
+    toggle_query: Query<(&Interaction, &ComputerToggle), ComputerToggleQuery>,
+    mut name_entry_value: ResMut<NameEntryValue>,
+) {
+    toggle_query.iter().for_each(|(interaction, toggle)| {
+        if interaction == &Interaction::Clicked {
+            name_entry_value.toggle_computer(toggle.index);
+        }
+    });
+}
+
+/// To color the toggle by who plays the player.
+fn computer_toggle_color(
+    mut toggle_query: Query<(&Interaction, &mut UiColor, &ComputerToggle), With<Button>>,
+    name_entry_value: Res<NameEntryValue>,
+) {
+    toggle_query
+        .iter_mut()
+        .for_each(|(interaction, mut color, toggle)| {
+            *color = UiColor::from(
+                match (interaction, name_entry_value.is_computer(toggle.index)) {
+                    (_, true) => style::TOGGLE_COMPUTER,
+                    (Interaction::Hovered, false) => style::TOGGLE_HOVER,
+                    (_, false) => style::TOGGLE_HUMAN,
+                },
+            );
+        });
+}
+
+/// To show the difficulty of the computer on the toggle.
+fn computer_toggle_text(
+    mut text_query: Query<(&mut Text, &ComputerToggleText)>,
+    name_entry_value: Res<NameEntryValue>,
+) {
+    text_query.iter_mut().for_each(|(mut text, toggle)| {
+        if let Some(section) = text.sections.first_mut() {
+            section.value = match name_entry_value.difficulty(toggle.index) {
+                Some(difficulty) => difficulty.to_string(),
+                None => String::from(TOGGLE_TEXT),
+            };
+        }
+    });
+}
+/*-----------------------------------------------------------------------------------------------*/
@@ -144,14 +144,21 @@ pub(crate) fn expand_btn_click(
 /// To reset the input string value.
 ///
 /// Takes [`TextInputId`] value and maps to the respective string buffer which is to be cleared.
+/// The player is given back to a human as well.
 #[inline]
 fn reset_input_str(id: &TextInputId, names: &mut ResMut<NameEntryValue>) {
-    (match id {
-        TextInputId::One => names.players.get_mut(2_usize),
-        TextInputId::Two => names.players.get_mut(3_usize),
-    })
-    .unwrap_or(&mut String::new())
-    .clear()
+    let index = match id {
+        TextInputId::One => 2_usize,
+        TextInputId::Two => 3_usize,
+    };
+    names
+        .players
+        .get_mut(index)
+        .unwrap_or(&mut String::new())
+        .clear();
+    if let Some(computer) = names.computers.get_mut(index) {
+        *computer = None;
+    }
 }
 
 /// Changes the expand button icon.
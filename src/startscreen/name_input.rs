@@ -199,6 +199,9 @@ fn text_typing(
         .filter(|(_, name)| **name)
         .collect::<Vec<(usize, &bool)>>()
         .get(0_usize) else { return };
+    if name_entry_value_res.is_computer(index) {
+        return;
+    }
     let Some(name) = name_entry_value_res.players.get_mut(index) else { return };
     if key_press.just_pressed(KeyCode::Back) {
         name.pop();
@@ -212,7 +215,8 @@ fn text_typing(
 
 /// To handle the display of the input text.
 ///
-/// Gets the text from [`NameEntryValue`] and displays it to the textbox node.
+/// Gets the text from [`NameEntryValue`] and displays it to the textbox node. A player played by
+/// the computer shows its name.
 fn display_text_to_input(
     name_entry_value: Res<NameEntryValue>,
     mut text_boxes: Query<(&mut Text, &Parent), With<NameInputText>>,
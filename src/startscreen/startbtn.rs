@@ -23,7 +23,8 @@ use crate::{
     close_window,
     despawn_entity::DespawnEntity,
     font::{BoldFontHandle, RegFontHandle, DEFAULT_FONT_CLR},
-    startscreen::NameEntryValue,
+    game::{network::NetworkMode, save::SaveSlot},
+    startscreen::{variant::spawn_variant_toggle, NameEntryValue},
     FortChessState, ZAxisLevel, RESOLUTION,
 };
 use bevy::prelude::{
@@ -32,7 +33,7 @@ use bevy::prelude::{
     Res, ResMut, Size, State, Style, SystemSet, Text, Text2dBundle, TextBundle, TextStyle,
     Transform, UiColor, UiRect, Val, Windows, With,
 };
-use fort_builders::player::{NAME_MAX_LEN, NAME_MIN_LEN};
+use fort_builders::player::{Player, NAME_MAX_LEN, NAME_MIN_LEN};
 
 /// [`Component`] to identify start button.
 #[derive(Component, Debug)]
@@ -43,6 +44,8 @@ struct ButtonComp {
 #[derive(Debug)]
 enum ButtonType {
     Start,
+    Continue,
+    Load,
     Exit,
 }
 /// [`Component`] to identify start button text.
@@ -81,7 +84,9 @@ impl Plugin for StartBtnPlugin {
 /*-----------------------------------------------------------------------------------------------*/
 /// To spwan the start button sprite.
 ///
-/// Takes font handle and spawns a UI node with insert and exit button as children.
+/// Takes font handle and spawns a UI node with start, load and exit buttons as children, followed
+/// by the toggle of the variant. The continue button is only spawned if there is an autosaved game
+/// to resume.
 pub(crate) fn spawn_start_btn(commands: &mut ChildBuilder, font: &Res<RegFontHandle>) {
     commands
         .spawn_bundle(NodeBundle {
@@ -96,51 +101,27 @@ pub(crate) fn spawn_start_btn(commands: &mut ChildBuilder, font: &Res<RegFontHan
             ..default()
         })
         .with_children(|commands| {
-            start_btn(commands, font);
-            exit_btn(commands, font);
-        });
-}
-
-/// To spawn a start button UI node.
-fn start_btn(commands: &mut ChildBuilder, font: &Res<RegFontHandle>) {
-    commands
-        .spawn_bundle(ButtonBundle {
-            style: Style {
-                size: Size::new(Val::Percent(20_f32), Val::Percent(66_f32)),
-                padding: UiRect::all(Val::Percent(1_f32)),
-                justify_content: JustifyContent::SpaceAround,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            color: UiColor::from(style::START_BTN_NORML),
-            ..default()
-        })
-        .with_children(|commands| {
-            commands
-                .spawn_bundle(TextBundle::from_section(
-                    "Start",
-                    TextStyle {
-                        font: font.get().clone(),
-                        font_size: style::START_BTN_TEXT_SIZE,
-                        color: DEFAULT_FONT_CLR,
-                    },
-                ))
-                .insert(ButtonText);
-        })
-        .insert(ButtonComp {
-            btn_type: ButtonType::Start,
+            menu_btn(commands, font, "Start", ButtonType::Start);
+            if SaveSlot::Autosave.exists() {
+                menu_btn(commands, font, "Continue", ButtonType::Continue);
+            }
+            menu_btn(commands, font, "Load", ButtonType::Load);
+            menu_btn(commands, font, "Exit", ButtonType::Exit);
+            spawn_variant_toggle(commands, font);
         });
 }
-/*-----------------------------------------------------------------------------------------------*/
 
-/*████Spawn Exit Button████*/
-/*-----------------------------------------------------------------------------------------------*/
-/// To spawn an exit button UI node.
-fn exit_btn(commands: &mut ChildBuilder, font: &Res<RegFontHandle>) {
+/// To spawn a button UI node with the given text and button type.
+fn menu_btn(
+    commands: &mut ChildBuilder,
+    font: &Res<RegFontHandle>,
+    text: &str,
+    btn_type: ButtonType,
+) {
     commands
         .spawn_bundle(ButtonBundle {
             style: Style {
-                size: Size::new(Val::Percent(20_f32), Val::Percent(66_f32)),
+                size: Size::new(Val::Percent(18_f32), Val::Percent(66_f32)),
                 padding: UiRect::all(Val::Percent(1_f32)),
                 justify_content: JustifyContent::SpaceAround,
                 align_items: AlignItems::Center,
@@ -152,7 +133,7 @@ fn exit_btn(commands: &mut ChildBuilder, font: &Res<RegFontHandle>) {
         .with_children(|commands| {
             commands
                 .spawn_bundle(TextBundle::from_section(
-                    "Exit",
+                    text,
                     TextStyle {
                         font: font.get().clone(),
                         font_size: style::START_BTN_TEXT_SIZE,
@@ -161,15 +142,14 @@ fn exit_btn(commands: &mut ChildBuilder, font: &Res<RegFontHandle>) {
                 ))
                 .insert(ButtonText);
         })
-        .insert(ButtonComp {
-            btn_type: ButtonType::Exit,
-        });
+        .insert(ButtonComp { btn_type });
 }
 /*-----------------------------------------------------------------------------------------------*/
 
 /*████Button Click████*/
 /*-----------------------------------------------------------------------------------------------*/
 /// To listen when the buttons on the start screen are clicked and handle them appropriately.
+#[allow(clippy::too_many_arguments)]
 fn start_btn_click(
     mut commands: Commands,
     mut start_btn_query: Query<StartBtnColorQuery, StartBtnQuery>,
@@ -179,6 +159,7 @@ fn start_btn_click(
     mut state: ResMut<State<FortChessState>>,
     font: Res<BoldFontHandle>,
     err_msg_query: Query<Entity, With<ErrDropDown>>,
+    network_mode: Res<NetworkMode>,
 ) {
     start_btn_query
         .iter_mut()
@@ -201,6 +182,7 @@ fn start_btn_click(
                         &mut state,
                         &font,
                         &err_msg_query,
+                        &network_mode,
                     );
                 }
                 Interaction::Hovered => {
@@ -219,6 +201,7 @@ fn start_btn_click(
 /*████Start Button Click████*/
 /*-----------------------------------------------------------------------------------------------*/
 /// To process when a button is clicked in the start screen.
+#[allow(clippy::too_many_arguments)]
 fn click(
     commands: &mut Commands,
     button_type: &ButtonType,
@@ -227,32 +210,46 @@ fn click(
     state: &mut ResMut<State<FortChessState>>,
     font: &Res<BoldFontHandle>,
     err_msg_query: &Query<Entity, With<ErrDropDown>>,
+    network_mode: &NetworkMode,
 ) {
     match button_type {
         ButtonType::Start => {
             commands.despawn_entity(err_msg_query);
-            validate_and_start_game(commands, name_entry_value, state, font);
+            validate_and_start_game(commands, name_entry_value, state, font, network_mode);
+        }
+        ButtonType::Continue => {
+            commands.despawn_entity(err_msg_query);
+            load_and_start_game(commands, SaveSlot::Autosave, state, font);
+        }
+        ButtonType::Load => {
+            commands.despawn_entity(err_msg_query);
+            load_and_start_game(commands, SaveSlot::Load, state, font);
         }
         ButtonType::Exit => close_window(windows),
     }
 }
 
 /// To check the name validations and start if all are clear else display an error message.
+///
+/// A spectator needs no name, so the names are not checked when the game is only watched.
 fn validate_and_start_game(
     commands: &mut Commands,
     name_entry_value: &Res<NameEntryValue>,
     state: &mut ResMut<State<FortChessState>>,
     font: &Res<BoldFontHandle>,
+    network_mode: &NetworkMode,
 ) {
+    if network_mode.is_watching() {
+        state.set(FortChessState::Lobby).unwrap_or_default();
+        return;
+    }
     if name_entry_value
-        .players
-        .get(0_usize)
-        .unwrap_or(&String::new())
+        .as_string(0_usize)
+        .unwrap_or_default()
         .is_empty()
         && name_entry_value
-            .players
-            .get(1_usize)
-            .unwrap_or(&String::new())
+            .as_string(1_usize)
+            .unwrap_or_default()
             .is_empty()
     {
         err_msg(commands, font, "There needs to be a minimum of 2 players");
@@ -274,6 +271,10 @@ fn validate_and_start_game(
             err_msg(commands, font, "Name cannot have whitespace");
             return;
         }
+        if !name.chars().all(Player::is_name_char) {
+            err_msg(commands, font, "Name cannot have separators like ':' or '/'");
+            return;
+        }
         if let Some(ch) = name.chars().next() {
             if !ch.is_alphabetic() {
                 err_msg(commands, font, "Name should start with an alphabet");
@@ -289,7 +290,23 @@ fn validate_and_start_game(
             }
         }
     }
-    state.set(FortChessState::GameBuild).unwrap_or_default();
+    state.set(FortChessState::Lobby).unwrap_or_default();
+}
+
+/// To load the game saved in the slot and start it else display an error message.
+fn load_and_start_game(
+    commands: &mut Commands,
+    slot: SaveSlot,
+    state: &mut ResMut<State<FortChessState>>,
+    font: &Res<BoldFontHandle>,
+) {
+    match slot.load() {
+        Ok(saved_game) => {
+            commands.insert_resource(saved_game);
+            state.set(FortChessState::GameBuild).unwrap_or_default();
+        }
+        Err(_) => err_msg(commands, font, "No saved game could be loaded"),
+    }
 }
 
 /// Display an error message in the given spot when called.
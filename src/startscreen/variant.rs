@@ -0,0 +1,135 @@
+//! variant module.
+//!
+//! Module to handle the toggle that picks the house variant of the rules the new game is played
+//! with. Each click switches to the next variant, and the last one switches back to the classic
+//! rules.
+/*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
+
+mod style {
+    use bevy::prelude::Color;
+    /// Color when the toggle is hovered.
+    pub(crate) const TOGGLE_HOVER: Color = Color::GRAY;
+    /// Color when the classic rules are picked.
+    pub(crate) const TOGGLE_CLASSIC: Color = Color::SILVER;
+    /// Color when a house variant is picked.
+    pub(crate) const TOGGLE_VARIANT: Color = Color::DARK_GRAY;
+    /// Size of the font of the toggle.
+    pub(crate) const TOGGLE_TEXT_SIZE: f32 = 36_f32;
+}
+
+use crate::{
+    font::{RegFontHandle, DEFAULT_FONT_CLR},
+    startscreen::NameEntryValue,
+    FortChessState,
+};
+use bevy::prelude::{
+    default, AlignItems, App, BuildChildren, Button, ButtonBundle, Changed, ChildBuilder,
+    Component, Interaction, JustifyContent, Plugin, Query, Res, ResMut, Size, Style, SystemSet,
+    Text, TextBundle, TextStyle, UiColor, UiRect, Val, With,
+};
+use fort_builders::ruleset::Variant;
+
+/// [`Component`] to identify the variant toggle.
+#[derive(Component)]
+pub(crate) struct VariantToggle;
+/// [`Component`] to identify the text of the variant toggle.
+#[derive(Component)]
+pub(crate) struct VariantToggleText;
+/// [`Plugin`] to handle the variant toggle.
+pub(crate) struct VariantTogglePlugin;
+
+/// Type alias for variant toggle query.
+type VariantToggleQuery = (Changed<Interaction>, With<Button>, With<VariantToggle>);
+
+/*████Functions██████████████████████████████████████████████████████████████████████████████████*/
+
+/*████Plugin for VariantTogglePlugin████*/
+/*-----------------------------------------------------------------------------------------------*/
+impl Plugin for VariantTogglePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_update(FortChessState::StartScreen)
+                .with_system(variant_toggle_click)
+                .with_system(variant_toggle_color)
+                .with_system(variant_toggle_text),
+        );
+    }
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Spawn Variant Toggle████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To spawn the variant toggle next to the menu buttons.
+pub(crate) fn spawn_variant_toggle(commands: &mut ChildBuilder, font: &Res<RegFontHandle>) {
+    commands
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Percent(18_f32), Val::Percent(66_f32)),
+                padding: UiRect::all(Val::Percent(1_f32)),
+                justify_content: JustifyContent::SpaceAround,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            color: UiColor::from(style::TOGGLE_CLASSIC),
+            ..default()
+        })
+        .insert(VariantToggle)
+        .with_children(|commands| {
+            commands
+                .spawn_bundle(TextBundle::from_section(
+                    Variant::default().to_string(),
+                    TextStyle {
+                        font: font.get().clone(),
+                        font_size: style::TOGGLE_TEXT_SIZE,
+                        color: DEFAULT_FONT_CLR,
+                    },
+                ))
+                .insert(VariantToggleText);
+        });
+}
+/*-----------------------------------------------------------------------------------------------*/
+
+/*████Variant Toggle Click████*/
+/*-----------------------------------------------------------------------------------------------*/
+/// To switch to the next variant when the toggle is clicked.
+fn variant_toggle_click(
+    toggle_query: Query<&Interaction, VariantToggleQuery>,
+    mut name_entry_value: ResMut<NameEntryValue>,
+) {
+    toggle_query.iter().for_each(|interaction| {
+        if interaction == &Interaction::Clicked {
+            name_entry_value.next_variant();
+        }
+    });
+}
+
+/// To color the toggle by the picked variant.
+fn variant_toggle_color(
+    mut toggle_query: Query<(&Interaction, &mut UiColor), (With<Button>, With<VariantToggle>)>,
+    name_entry_value: Res<NameEntryValue>,
+) {
+    toggle_query
+        .iter_mut()
+        .for_each(|(interaction, mut color)| {
+            *color = UiColor::from(
+                match (interaction, name_entry_value.variant() == Variant::Classic) {
+                    (_, false) => style::TOGGLE_VARIANT,
+                    (Interaction::Hovered, true) => style::TOGGLE_HOVER,
+                    (_, true) => style::TOGGLE_CLASSIC,
+                },
+            );
+        });
+}
+
+/// To show the name of the picked variant on the toggle.
+fn variant_toggle_text(
+    mut text_query: Query<&mut Text, With<VariantToggleText>>,
+    name_entry_value: Res<NameEntryValue>,
+) {
+    text_query.iter_mut().for_each(|mut text| {
+        if let Some(section) = text.sections.first_mut() {
+            section.value = name_entry_value.variant().to_string();
+        }
+    });
+}
+/*-----------------------------------------------------------------------------------------------*/
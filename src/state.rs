@@ -6,6 +6,7 @@
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub(crate) enum FortChessState {
     StartScreen,
+    Lobby,
     GameBuild,
     BoardScreen,
     ResultScreen,
@@ -15,10 +15,7 @@ use bevy::prelude::{
     Vec3,
 };
 use block::FortBlockPlugin;
-use fort_builders::{
-    board::{X_MAX, X_MIN, Y_MAX, Y_MIN},
-    decrement_if_positive, BREADTH,
-};
+use fort_builders::board::{layout::Area, position_in_board_bounds, BOARD_LAYOUT};
 
 /// To hold the row size of the tile pieces.
 const TILE_TYPE_ROW: usize = 5_usize;
@@ -30,7 +27,7 @@ struct TileSheet(Handle<TextureAtlas>);
 /// Plugin to handle board drawing systems.
 pub(crate) struct TilePlugin;
 /// To denote the type of tile.
-#[derive(PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 enum TileSpriteSheetIndex {
     /// Light color tile inside the board.
     Light,
@@ -101,7 +98,14 @@ impl TileSpriteSheetIndex {
 /// Returns [`TileSpriteSheetIndex::Dark`] or [`TileSpriteSheetIndex::Light`] tile depending on the
 /// tiles. Returns Dark for even tiles and Light for odd tiles until the x value is less than zero.
 /// After `x > 0`, the tiles are then switched with Light for even and Dark for odd tiles.
+///
+/// The x value is counted the way the board used to count its columns, with the columns from zero
+/// on being one further as the board skipped the *Zeroeth* axis.
 fn dark_or_light_tile_index(x: i32, y: i32) -> TileSpriteSheetIndex {
+    let x = match x >= 0_i32 {
+        true => x + 1_i32,
+        false => x,
+    };
     TileSpriteSheetIndex::from_usize({
         (match x > 0_i32 {
             true => TileSpriteSheetIndex::Light,
@@ -116,102 +120,94 @@ fn dark_or_light_tile_index(x: i32, y: i32) -> TileSpriteSheetIndex {
     })
 }
 
+/// To check if the position is on the board or in the fort.
+#[inline]
+fn position_in_board_or_fort(x: i32, y: i32) -> bool {
+    position_in_board_bounds(x, y) || BOARD_LAYOUT.fort.contains(x, y)
+}
+
 /*████Drawing the Board████*/
 /*-----------------------------------------------------------------------------------------------*/
 
 /// To Draw the board.
 ///
-/// Iterates from [`X_MIN`] to [`X_MAX`] and from [`Y_MIN`] to [`Y_MAX`] as it generates the board
-///
-/// with the power of mathematics. The one issue I do have is with the *Zeroeth* axis as that
-/// produced odd number of tiles at each axis.
+/// Draws a tile on each square of the quadrants of the [`BOARD_LAYOUT`].
 fn draw_board(mut commands: Commands, tile: Res<TileSheet>) {
-    (X_MIN..=X_MAX).for_each(|x| {
-        (Y_MIN..=Y_MAX).for_each(|mut y| {
-            if !{
-                y == 0_i32
-                    || (x.abs() <= BREADTH && y.abs() <= BREADTH)
-                    || (x.abs() > BREADTH && y.abs() > BREADTH)
-            } {
-                if y > 0_i32 {
-                    y -= 1_i32
-                }
-                let tile = spawn_tile(
-                    &mut commands,
-                    &tile,
-                    dark_or_light_tile_index(x, y),
-                    Vec3::new(
-                        decrement_if_positive(x) as f32 * RESOLUTION,
-                        y as f32 * RESOLUTION,
-                        ZAxisLevel::Second.as_f32(),
-                    ),
-                );
-                commands.entity(tile).insert(TileComponent);
-            }
-        })
-    });
+    BOARD_LAYOUT
+        .quadrants
+        .iter()
+        .flat_map(|layout| layout.area.positions())
+        .for_each(|(x, y)| {
+            let tile = spawn_tile(
+                &mut commands,
+                &tile,
+                dark_or_light_tile_index(x, y),
+                Vec3::new(
+                    x as f32 * RESOLUTION,
+                    y as f32 * RESOLUTION,
+                    ZAxisLevel::Second.as_f32(),
+                ),
+            );
+            commands.entity(tile).insert(TileComponent);
+        });
 }
 
 /// To Draw the border of the board.
 ///
-/// This is essentially just the same as *draw_board* but with bounds incremented to form a border.
+/// Draws a tile on each square next to the quadrants or the fort that is not part of them.
 fn draw_border(mut commands: Commands, tile: Res<TileSheet>) {
-    ((X_MIN - 1)..=(X_MAX + 1)).for_each(|x| {
-        ((Y_MIN - 1)..=(Y_MAX + 1)).for_each(|mut y| {
-            if !{
-                y == 0_i32
-                    || (x.abs() <= BREADTH && y.abs() <= BREADTH)
-                    || (x.abs() > BREADTH + 1_i32 && y.abs() > BREADTH + 1_i32)
-            } {
-                if y > 0_i32 {
-                    y -= 1_i32
-                }
-                let tile = spawn_tile(
-                    &mut commands,
-                    &tile,
-                    TileSpriteSheetIndex::Border,
-                    Vec3::new(
-                        decrement_if_positive(x) as f32 * RESOLUTION,
-                        y as f32 * RESOLUTION,
-                        ZAxisLevel::First.as_f32(),
-                    ),
-                );
-                commands.entity(tile).insert(TileComponent);
-            }
+    let areas = BOARD_LAYOUT
+        .quadrants
+        .iter()
+        .map(|layout| layout.area.grow(1_i32))
+        .chain(std::iter::once(BOARD_LAYOUT.fort.grow(1_i32)))
+        .collect::<Vec<Area>>();
+    BOARD_LAYOUT
+        .bounds()
+        .grow(1_i32)
+        .positions()
+        .filter(|&(x, y)| {
+            !position_in_board_or_fort(x, y) && areas.iter().any(|area| area.contains(x, y))
         })
-    });
-}
-
-/// To draw the fort.
-///
-/// Follows the similar logic as other "drawing" functions.
-fn draw_fort(mut commands: Commands, tile_sheet: Res<TileSheet>) {
-    (-BREADTH..=BREADTH).for_each(|x| {
-        (-BREADTH..BREADTH).for_each(|y| {
+        .for_each(|(x, y)| {
             let tile = spawn_tile(
                 &mut commands,
-                &tile_sheet,
-                TileSpriteSheetIndex::FortOuter,
+                &tile,
+                TileSpriteSheetIndex::Border,
                 Vec3::new(
-                    decrement_if_positive(x) as f32 * RESOLUTION,
+                    x as f32 * RESOLUTION,
                     y as f32 * RESOLUTION,
-                    ZAxisLevel::Third.as_f32(),
+                    ZAxisLevel::First.as_f32(),
                 ),
             );
             commands.entity(tile).insert(TileComponent);
-        })
-    });
-    ((-BREADTH + 1_i32)..=(BREADTH - 1_i32)).for_each(|x| {
-        ((-BREADTH + 1_i32)..(BREADTH - 1_i32)).for_each(|y| {
+        });
+}
+
+/// To draw the fort.
+///
+/// Draws the outer shell on the fort of the [`BOARD_LAYOUT`] and the inner part one square in.
+fn draw_fort(mut commands: Commands, tile_sheet: Res<TileSheet>) {
+    [
+        (
+            BOARD_LAYOUT.fort,
+            TileSpriteSheetIndex::FortOuter,
+            ZAxisLevel::Third,
+        ),
+        (
+            BOARD_LAYOUT.fort.grow(-1_i32),
+            TileSpriteSheetIndex::FortInner,
+            ZAxisLevel::Fourth,
+        ),
+    ]
+    .into_iter()
+    .for_each(|(area, index, level)| {
+        area.positions().for_each(|(x, y)| {
             let tile = spawn_tile(
                 &mut commands,
                 &tile_sheet,
-                TileSpriteSheetIndex::FortInner,
-                Vec3::new(
-                    decrement_if_positive(x) as f32 * RESOLUTION,
-                    y as f32 * RESOLUTION,
-                    ZAxisLevel::Fourth.as_f32(),
-                ),
+                index,
+                Vec3::new(x as f32 * RESOLUTION, y as f32 * RESOLUTION, level.as_f32()),
             );
             commands.entity(tile).insert(TileComponent);
         })
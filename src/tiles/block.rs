@@ -1,14 +1,11 @@
 /*████Constants and Declarations█████████████████████████████████████████████████████████████████*/
 
-use crate::{startscreen::NameEntryValue, state::FortChessState, ZAxisLevel, RESOLUTION, TILESIZE};
+use crate::{game::GameAsset, state::FortChessState, ZAxisLevel, RESOLUTION, TILESIZE};
 use bevy::prelude::{
     default, App, Color, Commands, Component, Entity, Plugin, Res, Sprite, SpriteBundle, SystemSet,
     Transform, Vec2, Vec3,
 };
-use fort_builders::{
-    board::{X_MAX, Y_MAX},
-    BREADTH,
-};
+use fort_builders::{board::BOARD_LAYOUT, decrement_if_positive};
 
 /// To hold the quadrant block color,
 const BLOCK_COLOR: Color = Color::rgba(0.1_f32, 0.1_f32, 0.1_f32, 0.95_f32);
@@ -26,9 +23,7 @@ pub(crate) struct FortBlockPlugin;
 impl Plugin for FortBlockPlugin {
     fn build(&self, app: &mut App) {
         app.add_system_set(
-            SystemSet::on_enter(FortChessState::BoardScreen)
-                .with_system(muteblockq2)
-                .with_system(muteblockq3),
+            SystemSet::on_enter(FortChessState::BoardScreen).with_system(muteblock_quadrants),
         );
     }
 }
@@ -36,38 +31,18 @@ impl Plugin for FortBlockPlugin {
 
 /*████Quadrant Blocking████*/
 /*-----------------------------------------------------------------------------------------------*/
-/// To block the [`Q2`] if the `PLAYER_COUNT` is less than `3`.
+/// To block the quadrants of the [`BOARD_LAYOUT`] that no attacker starts from. Like [`Q2`] if
+/// the `PLAYER_COUNT` is less than `3` and [`Q3`] if it is less than `4`.
 ///
 /// [`Q2`]: [`fort_builder::board::Quadrant::Q2`]
-fn muteblockq2(mut commands: Commands, name_entry_value_res: Res<NameEntryValue>) {
-    if name_entry_value_res.count() >= 3_usize {
-        return;
-    }
-    (-BREADTH..BREADTH).into_iter().for_each(|x| {
-        (BREADTH..Y_MAX).into_iter().for_each(|y| {
-            let tile = spawn_block_sprite(
-                &mut commands,
-                BLOCK_COLOR,
-                Vec3::new(
-                    x as f32 * RESOLUTION,
-                    y as f32 * RESOLUTION,
-                    ZAxisLevel::Fourteenth.as_f32(),
-                ),
-            );
-            commands.entity(tile).insert(Blocker);
-        })
-    })
-}
-
-/// To block the [`Q3`] if the `PLAYER_COUNT` is less than `4`.
-///
 /// [`Q3`]: [`fort_builder::board::Quadrant::Q3`]
-fn muteblockq3(mut commands: Commands, name_entry_value_res: Res<NameEntryValue>) {
-    if name_entry_value_res.count() >= 4_usize {
-        return;
-    }
-    (BREADTH..X_MAX).into_iter().for_each(|x| {
-        (-BREADTH..BREADTH).into_iter().for_each(|y| {
+fn muteblock_quadrants(mut commands: Commands, game: Res<GameAsset>) {
+    BOARD_LAYOUT
+        .quadrants
+        .iter()
+        .skip(decrement_if_positive(game.get().quadrant_active))
+        .flat_map(|layout| layout.area.positions())
+        .for_each(|(x, y)| {
             let tile = spawn_block_sprite(
                 &mut commands,
                 BLOCK_COLOR,
@@ -79,7 +54,6 @@ fn muteblockq3(mut commands: Commands, name_entry_value_res: Res<NameEntryValue>
             );
             commands.entity(tile).insert(Blocker);
         })
-    })
 }
 /*-----------------------------------------------------------------------------------------------*/
 